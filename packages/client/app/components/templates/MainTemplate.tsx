@@ -26,6 +26,7 @@ import CreateNovelDialog from "~/components/modals/CreateNovelDialog"
 import { Tooltip } from "~/components/ui/tooltip"
 import Footer from "~/components/organisms/Footer"
 import { useTauriFileOpen } from "~/hooks/useTauriFileOpen"
+import { useTempFileRecovery } from "~/hooks/useTempFileRecovery"
 
 const BuyMeACoffeText = "Buy me a coffee!"
 
@@ -84,6 +85,7 @@ const MainTemplate: React.FC<{
   const createNovelDialog = useDialog()
 
   useTauriFileOpen()
+  useTempFileRecovery()
 
   return (
     <Stack p={0}>
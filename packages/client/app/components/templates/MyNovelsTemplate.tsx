@@ -20,6 +20,8 @@ import NovelEmptyState from "~/components/molecules/NovelEmptyState"
 import LoadingOverlay from "~/components/templates/LoadingOverlay"
 import { usePlatform } from "~/hooks/usePlatform"
 import { TbDownload } from "react-icons/tb"
+import { useRevalidator } from "react-router"
+import { useExternalChanges } from "~/hooks/useExternalChanges"
 
 const MyNovelsTemplate: React.FC<{
   novels: Novel[]
@@ -27,6 +29,12 @@ const MyNovelsTemplate: React.FC<{
 }> = ({ novels, localNovels }) => {
   const user = useUser()
   const { isTauri } = usePlatform()
+  const { revalidate } = useRevalidator()
+
+  // 앱 밖에서 소설 폴더가 추가/삭제되거나 소설 정보가 바뀌면 목록을 새로 고침
+  useExternalChanges((target) => {
+    if (target === "novel") void revalidate()
+  })
 
   if (!user && !isTauri) {
     // 타우리 환경에서는 로그인 페이지로 리다이렉트 하지 않음 (오프라인 가능)
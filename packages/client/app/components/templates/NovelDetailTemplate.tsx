@@ -34,6 +34,8 @@ import { createNovelEpisode } from "~/services/episodeService"
 import CreateEpisodeMenu from "~/features/novel-editor/components/menus/CreateEpisodeMenu"
 import { getKimuageUrl } from "~/utils/getKimuageUrl"
 import SortableEpisodeList from "../organisms/SortableEpisodeList"
+import { useExternalChanges } from "~/hooks/useExternalChanges"
+import { useSyncConflicts } from "~/hooks/useSyncConflicts"
 
 const NovelDetailTemplate: React.FC<{
   novel: GetNovelResponseDto
@@ -42,6 +44,22 @@ const NovelDetailTemplate: React.FC<{
   const navigate = useNavigate()
   const [isEpisodesLoading, setIsEpisodesLoading] = React.useState(false)
 
+  // 앱 밖에서 이 소설이나 에피소드, 위키 페이지 파일이 바뀌면 목록을 새로 고침
+  useExternalChanges((target, event) => {
+    if (event.novelId !== novel.id) return
+    if (target === "novel" && event.change === "removed") {
+      toaster.warning({
+        title: "소설 파일이 삭제되었습니다",
+        description: "앱 밖에서 이 소설의 메타데이터 파일이 삭제되었습니다.",
+      })
+      return
+    }
+    void revalidate()
+  })
+
+  // 동기화 충돌 사본을 알리고, 병합하면 목록을 새로 고침
+  useSyncConflicts(novel.id, () => void revalidate())
+
   // TODO: 로컬판이 구현되면 ? 빼기
   const characters =
     novel.wikiPages?.filter((w) => w.category === WikiPageCategory.Character) ||
@@ -8,6 +8,8 @@ import { useEpisodeMetadataSync } from "~/features/novel-editor/hooks/useEpisode
 import { combineSyncStates } from "~/utils/combineSyncStates"
 import { useEpisodeBlocksSync } from "~/features/novel-editor/hooks/useEpisodeBlocksSync"
 import { EpisodeProvider } from "~/providers/EpisodeProvider"
+import { useExternalChanges } from "~/hooks/useExternalChanges"
+import { toaster } from "~/components/ui/toaster"
 
 const EditorPage: React.FC<{ episode: GetEpisodeResponseDto }> = ({
   episode: initialEpisode,
@@ -25,11 +27,39 @@ const EditorPage: React.FC<{ episode: GetEpisodeResponseDto }> = ({
     initialBlocks,
     isLoadingBlocks,
     handleDocUpdate,
+    stepPersistentHistory,
+    reloadBlocks,
   } = useEpisodeBlocksSync({
     episodeContext: initialEpisode,
     canEdit: !!initialEpisode.permissions.edit,
   })
 
+  // 앱 밖(동기화 서비스 등)에서 열려 있는 에피소드 파일이 바뀐 경우
+  useExternalChanges((target, event) => {
+    if (target !== "episode" || event.itemId !== initialEpisode.id) return
+    if (event.change === "removed") {
+      toaster.warning({
+        title: "에피소드 파일이 삭제되었습니다",
+        description:
+          "앱 밖에서 이 에피소드 파일이 삭제되어, 이후 변경 사항은 저장되지 않을 수 있습니다.",
+      })
+      return
+    }
+    if (blockSyncState === SyncState.Synced) {
+      toaster.info({
+        title: "에피소드가 앱 밖에서 수정되었습니다",
+        description: "수정된 내용을 다시 불러옵니다.",
+      })
+      void reloadBlocks()
+    } else {
+      toaster.warning({
+        title: "에피소드가 앱 밖에서 수정되었습니다",
+        description:
+          "저장되지 않은 변경 사항이 있어 다시 불러오지 않았습니다. 겹치는 블록의 변경은 저장되지 않을 수 있습니다.",
+      })
+    }
+  })
+
   const combinedSyncState = useMemo(() => {
     return combineSyncStates(episodeSyncState, blockSyncState)
   }, [episodeSyncState, blockSyncState])
@@ -51,7 +81,10 @@ const EditorPage: React.FC<{ episode: GetEpisodeResponseDto }> = ({
   }
 
   return (
-    <EditorProvider onDocUpdate={handleDocUpdate}>
+    <EditorProvider
+      onDocUpdate={handleDocUpdate}
+      onHistoryFallback={stepPersistentHistory}
+    >
       <EpisodeProvider
         episode={episodeData}
         setEpisode={setEpisodeData}
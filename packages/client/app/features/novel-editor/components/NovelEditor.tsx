@@ -20,7 +20,8 @@ const NovelEditor: React.FC<NovelEditorProps> = ({
 }) => {
   const containerRef = useRef<HTMLDivElement>(null!)
   const [editorStyle] = useEditorStyleOptions()
-  const { onDocUpdate, setEditorState } = useEditorContext()
+  const { onDocUpdate, onHistoryFallback, setEditorState } =
+    useEditorContext()
   const pasteAlertFlagRef = useRef(false)
 
   useEffect(() => {
@@ -49,6 +50,7 @@ const NovelEditor: React.FC<NovelEditorProps> = ({
     episodeId,
     editable,
     onDocUpdate,
+    onHistoryFallback,
     onStateChange: setEditorState,
   })
 
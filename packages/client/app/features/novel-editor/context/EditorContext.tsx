@@ -20,6 +20,7 @@ interface Match {
 interface EditorProviderProps {
   children: React.ReactNode
   onDocUpdate: (doc: PMNode) => void
+  onHistoryFallback?: (direction: "undo" | "redo") => void
 }
 
 interface EditorContextValue {
@@ -29,6 +30,8 @@ interface EditorContextValue {
   editorState: EditorState | null
   setEditorState: (state: EditorState | null) => void
   onDocUpdate: (doc: PMNode) => void // 문서 업데이트 핸들러
+  // 에디터의 실행 취소 기록이 비었을 때 호출 (저장된 편집 기록 사용)
+  onHistoryFallback?: (direction: "undo" | "redo") => void
 
   // Prosemirror 유틸
   setHighlightDecorations: (matches: Match[], currentIndex: number) => void
@@ -47,6 +50,7 @@ export const useEditorContext = () => {
 export const EditorProvider: React.FC<EditorProviderProps> = ({
   children,
   onDocUpdate,
+  onHistoryFallback,
 }) => {
   const [view, setView] = useState<EditorView | null>(null)
   const [editorState, setEditorState] = useState<EditorState | null>(null)
@@ -73,9 +77,16 @@ export const EditorProvider: React.FC<EditorProviderProps> = ({
       editorState,
       setEditorState,
       onDocUpdate,
+      onHistoryFallback,
       setHighlightDecorations,
     }),
-    [view, editorState, onDocUpdate, setHighlightDecorations],
+    [
+      view,
+      editorState,
+      onDocUpdate,
+      onHistoryFallback,
+      setHighlightDecorations,
+    ],
   )
 
   return (
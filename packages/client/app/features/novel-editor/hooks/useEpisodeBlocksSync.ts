@@ -5,10 +5,13 @@ import {
   type GetEpisodeResponseDto,
   ShareType,
   SnapshotReason,
+  type SyncResult,
 } from "muvel-api-types"
 import {
+  compactEpisodeJournal,
   type EpisodeContext,
-  getEpisodeBlocks,
+  getEpisodeBlocksWithRevision,
+  stepEpisodeEditHistory,
   syncDeltaBlocks as syncEpisodeDeltaBlocksService,
 } from "~/services/episodeService"
 import { Node as PMNode } from "prosemirror-model"
@@ -16,9 +19,10 @@ import { SyncState } from "~/features/novel-editor/components/SyncIndicator"
 import { useInternalBlocksSyncLogic } from "~/hooks/useInternalBlocksSyncLogic"
 import { useDebouncedCallback } from "use-debounce"
 import { saveEpisodeSnapshot } from "~/services/episodeSnapshotService"
-import { useEffect } from "react"
+import { useCallback, useEffect, useRef } from "react"
 import { backupCloudEpisodeToLocal } from "~/services/tauri/episodeStorage"
 import { fillPartialBlocks } from "~/features/novel-editor/utils/fillPartialBlock"
+import { toaster } from "~/components/ui/toaster"
 
 // useEpisodeBlocksSync 훅의 Props 타입 정의
 interface UseEpisodeBlocksSyncProps {
@@ -32,6 +36,31 @@ interface UseEpisodeBlocksSyncReturn {
   isLoadingBlocks: boolean
   syncState: SyncState
   handleDocUpdate: (doc: PMNode) => void
+  // 에디터의 실행 취소 기록이 비었을 때, 저장된 편집 기록으로 되돌리거나 다시 적용
+  stepPersistentHistory: (direction: "undo" | "redo") => Promise<void>
+  reloadBlocks: () => Promise<void>
+}
+
+/**
+ * 로컬 동기화 결과를 확인해 다시 불러와야 하는지 판단하고, 필요하면 사용자에게 알립니다.
+ */
+const needsReloadAfterSync = (result: SyncResult): boolean => {
+  if (result.rejected.length > 0) {
+    toaster.warning({
+      title: "일부 변경 사항을 저장하지 못했습니다",
+      description: `다른 곳에서 먼저 수정된 블록 ${result.rejected.length}개와 충돌하여, 저장된 최신 내용으로 다시 불러옵니다.`,
+    })
+    return true
+  }
+  if (result.staleBase) {
+    toaster.info({
+      title: "에피소드가 다른 곳에서 수정되었습니다",
+      description:
+        "변경 사항은 저장되었으며, 다른 곳에서 수정된 내용을 반영하기 위해 다시 불러옵니다.",
+    })
+    return true
+  }
+  return result.rebalanced
 }
 
 /**
@@ -42,30 +71,39 @@ export function useEpisodeBlocksSync({
   episodeContext,
   canEdit,
 }: UseEpisodeBlocksSyncProps): UseEpisodeBlocksSyncReturn {
-  const { handleDocUpdate, ...internalSyncResult } = useInternalBlocksSyncLogic<
-    GetEpisodeResponseDto,
-    EpisodeBlockType
-  >({
-    documentContext: episodeContext,
-    canEdit,
-    fetchBlocksFn: (context) => getEpisodeBlocks(context),
-    syncDeltaBlocksFn: async (context, deltas, blocks) => {
-      if (blocks && episodeContext.novel.share !== ShareType.Local) {
-        // 클라우드 소설일 경우 로컬에 백업
-        void backupCloudEpisodeToLocal({
-          ...episodeContext,
-          blocks: fillPartialBlocks<EpisodeBlockType>(blocks),
-        })
-      }
-      await syncEpisodeDeltaBlocksService(
-        context,
-        deltas as DeltaBlock<EpisodeBlockType>[],
-      )
-    },
-    onBeforeBackupMerge: async () => {
-      await saveEpisodeSnapshot(episodeContext, SnapshotReason.Merge)
-    },
-  })
+  // 에디터가 불러온 블록의 리비전 (로컬 에피소드만 해당). 동기화할 때 baseRevision으로 전달합니다.
+  const revisionRef = useRef<number | undefined>(undefined)
+
+  const { handleDocUpdate, replaceBlocks, ...internalSyncResult } =
+    useInternalBlocksSyncLogic<GetEpisodeResponseDto, EpisodeBlockType>({
+      documentContext: episodeContext,
+      canEdit,
+      fetchBlocksFn: async (context) => {
+        const { blocks, revision } = await getEpisodeBlocksWithRevision(context)
+        revisionRef.current = revision
+        return blocks
+      },
+      syncDeltaBlocksFn: async (context, deltas, blocks) => {
+        if (blocks && episodeContext.novel.share !== ShareType.Local) {
+          // 클라우드 소설일 경우 로컬에 백업
+          void backupCloudEpisodeToLocal({
+            ...episodeContext,
+            blocks: fillPartialBlocks<EpisodeBlockType>(blocks),
+          })
+        }
+        const result = await syncEpisodeDeltaBlocksService(
+          context,
+          deltas as DeltaBlock<EpisodeBlockType>[],
+          revisionRef.current,
+        )
+        if (!result) return
+        revisionRef.current = result.revision
+        return { reloadRequired: needsReloadAfterSync(result) }
+      },
+      onBeforeBackupMerge: async () => {
+        await saveEpisodeSnapshot(episodeContext, SnapshotReason.Merge)
+      },
+    })
 
   const debouncedAutoSave = useDebouncedCallback(
     async (episodeContext: EpisodeContext) => {
@@ -80,6 +118,8 @@ export function useEpisodeBlocksSync({
     console.log(episodeContext.id + "로그")
     return () => {
       debouncedAutoSave.flush()
+      // 에디터를 닫을 때 편집 저널을 에피소드 파일에 합침
+      void compactEpisodeJournal(episodeContext).catch(() => {})
     }
   }, [episodeContext.id])
 
@@ -88,9 +128,38 @@ export function useEpisodeBlocksSync({
     handleDocUpdate(doc)
   }
 
+  const { syncState } = internalSyncResult
+  const stepPersistentHistory = useCallback(
+    async (direction: "undo" | "redo") => {
+      if (episodeContext.novel.share !== ShareType.Local || !canEdit) return
+      // 저장되지 않은 변경이 있으면 저장된 기록과 어긋나므로 저장 후에 다시 시도하도록 함
+      if (syncState !== SyncState.Synced) {
+        toaster.info({
+          title: "저장 중입니다",
+          description: "변경 사항이 저장된 뒤에 다시 시도해 주세요.",
+        })
+        return
+      }
+      try {
+        const result = await stepEpisodeEditHistory(episodeContext, direction)
+        if (!result?.applied) return
+        revisionRef.current = result.revision
+        replaceBlocks(result.blocks)
+      } catch (e) {
+        console.error(`[${episodeContext.id}] Failed to ${direction}:`, e)
+        toaster.error({
+          title: direction === "undo" ? "실행 취소 실패" : "다시 실행 실패",
+          description: "저장된 편집 기록을 적용하지 못했습니다.",
+        })
+      }
+    },
+    [episodeContext, canEdit, syncState, replaceBlocks],
+  )
+
   return {
     ...internalSyncResult,
     handleDocUpdate: handleDocUpdate_,
+    stepPersistentHistory,
     initialBlocks: internalSyncResult.initialBlocks as
       | BaseBlock<EpisodeBlockType>[]
       | null,
@@ -1,5 +1,5 @@
 import React, { useEffect, useRef } from "react"
-import { EditorState } from "prosemirror-state"
+import { type Command, EditorState } from "prosemirror-state"
 import { EditorView } from "prosemirror-view"
 import { keymap } from "prosemirror-keymap"
 import { baseKeymap, toggleMark } from "prosemirror-commands"
@@ -25,6 +25,8 @@ interface UseEpisodeEditorProps {
   editable?: boolean
   onDocUpdate?: (doc: PMNode) => void
   onStateChange?: (state: EditorState) => void
+  // 에디터의 실행 취소 기록이 비었을 때 호출 (앱을 다시 시작해도 유지되는 편집 기록 사용)
+  onHistoryFallback?: (direction: "undo" | "redo") => void
 }
 
 export const useEpisodeEditor = ({
@@ -34,9 +36,25 @@ export const useEpisodeEditor = ({
   editable = true,
   onDocUpdate,
   onStateChange,
+  onHistoryFallback,
 }: UseEpisodeEditorProps) => {
   const { setView } = useEditorContext()
   const viewRef = useRef<EditorView | null>(null)
+  // 에디터는 한 번만 만들어지므로 최신 콜백을 ref로 참조
+  const historyFallbackRef = useRef(onHistoryFallback)
+  historyFallbackRef.current = onHistoryFallback
+
+  const withHistoryFallback =
+    (command: Command, direction: "undo" | "redo"): Command =>
+    (state, dispatch) => {
+      if (command(state, dispatch)) return true
+      const fallback = historyFallbackRef.current
+      if (!fallback || !dispatch) return false
+      fallback(direction)
+      return true
+    }
+  const undoCommand = withHistoryFallback(undo, "undo")
+  const redoCommand = withHistoryFallback(redo, "redo")
 
   useEffect(() => {
     if (!containerRef.current) return
@@ -71,9 +89,9 @@ export const useEpisodeEditor = ({
         highlightPlugin(),
         placeholderPlugin,
         keymap({
-          "Mod-z": undo,
-          "Mod-y": redo,
-          "Shift-Mod-z": redo, // mac 호환
+          "Mod-z": undoCommand,
+          "Mod-y": redoCommand,
+          "Shift-Mod-z": redoCommand, // mac 호환
           "Mod-b": toggleMark(baseSchema.marks.strong), // Bold
           "Mod-i": toggleMark(baseSchema.marks.em), // Italic
           "Mod-u": toggleMark(baseSchema.marks.underline), // Underline (스키마에 있다면)
@@ -106,7 +124,7 @@ export const useEpisodeEditor = ({
       handleDOMEvents: {
         keydown(view, event) {
           if ((event.ctrlKey || event.metaKey) && event.key === "z") {
-            undo(view.state, view.dispatch)
+            undoCommand(view.state, view.dispatch)
             return true
           }
           return false
@@ -1,5 +1,6 @@
 import { Schema } from "prosemirror-model"
 import { EpisodeBlockType } from "muvel-api-types"
+import { resolveResourceUrl, toResourceUri } from "~/utils/resourceUrl"
 
 export const baseSchema = new Schema({
   nodes: {
@@ -77,7 +78,7 @@ export const baseSchema = new Schema({
           tag: "img",
           getAttrs: (dom) => ({
             id: dom.getAttribute("data-id"),
-            src: dom.getAttribute("src"),
+            src: toResourceUri(dom.getAttribute("src") ?? ""),
             alt: dom.getAttribute("alt"),
           }),
         },
@@ -86,7 +87,7 @@ export const baseSchema = new Schema({
         "img",
         {
           "data-id": node.attrs.id,
-          src: node.attrs.src,
+          src: resolveResourceUrl(node.attrs.src),
           alt: node.attrs.alt,
         },
       ],
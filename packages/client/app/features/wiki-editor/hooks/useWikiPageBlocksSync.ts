@@ -2,35 +2,76 @@ import {
   type DeltaBlock,
   type GetWikiPageResponse,
   type PartialWikiBlock,
+  ShareType,
   type WikiBlock,
   type WikiBlockType,
+  type WikiPageSyncResult,
 } from "muvel-api-types" // 실제 경로에 맞게 수정
 import { Node as PMNode } from "prosemirror-model"
 import { SyncState } from "~/features/novel-editor/components/SyncIndicator"
-import { useInternalBlocksSyncLogic } from "~/hooks/useInternalBlocksSyncLogic"
+import {
+  type BlocksSyncOutcome,
+  useInternalBlocksSyncLogic,
+} from "~/hooks/useInternalBlocksSyncLogic"
 import { getCloudWikiPageBlocks } from "~/services/api/api.wiki" // SyncState 임포트
+import {
+  getWikiPageBlocks as getWikiPageBlocksService,
+  syncWikiPageBlocks,
+} from "~/services/wikiPageService"
+import { toaster } from "~/components/ui/toaster"
+
+const isLocalWikiPage = (context: GetWikiPageResponse) =>
+  context.novel.share === ShareType.Local
 
-// 위키 페이지 블록을 가져오는 서비스 함수 (플레이스홀더)
-// 실제 구현에서는 wikiPageService.ts 등에 정의되어야 합니다.
+const wikiPageInputOf = (context: GetWikiPageResponse) => ({
+  id: context.id,
+  novelId: context.novel.id,
+  novelShareType: context.novel.share,
+})
+
+// 위키 페이지 블록을 가져오는 서비스 함수
+// 클라우드 위키 페이지는 아직 플레이스홀더입니다.
 async function getWikiPageBlocks(
   context: GetWikiPageResponse,
 ): Promise<PartialWikiBlock[]> {
+  if (isLocalWikiPage(context)) {
+    return getWikiPageBlocksService(wikiPageInputOf(context))
+  }
   return getCloudWikiPageBlocks(context.id)
 }
 
-// 위키 페이지 델타 블록을 동기화하는 서비스 함수 (플레이스홀더)
-// 실제 구현에서는 wikiPageService.ts 등에 정의되어야 합니다.
+/**
+ * 로컬 동기화 결과를 확인해 다시 불러와야 하는지 판단하고, 필요하면 사용자에게 알립니다.
+ */
+const needsReloadAfterSync = (result: WikiPageSyncResult): boolean => {
+  if (result.rejected.length > 0) {
+    toaster.warning({
+      title: "일부 변경 사항을 저장하지 못했습니다",
+      description: `다른 곳에서 먼저 수정된 블록 ${result.rejected.length}개와 충돌하여, 저장된 최신 내용으로 다시 불러옵니다.`,
+    })
+    return true
+  }
+  return result.rebalanced
+}
+
+// 위키 페이지 델타 블록을 동기화하는 서비스 함수
+// 클라우드 위키 페이지는 아직 플레이스홀더입니다.
 async function syncWikiPageDeltaBlocks(
   context: GetWikiPageResponse,
   deltas: DeltaBlock<WikiBlockType>[],
-): Promise<void | PartialWikiBlock[]> {
+): Promise<BlocksSyncOutcome | void> {
+  if (isLocalWikiPage(context)) {
+    const result = await syncWikiPageBlocks(wikiPageInputOf(context), deltas)
+    if (!result) return
+    return { reloadRequired: needsReloadAfterSync(result) }
+  }
   console.warn(
     `syncWikiPageDeltaBlocks for ${context.id} is not implemented. Simulating success.`,
     deltas,
   )
   // 실제 API 호출 로직:
   // await api.patch(`/wiki-pages/${context.id}/blocks/sync`, { deltaBlocks: deltas });
-  return Promise.resolve() // 임시 반환 (또는 업데이트된 블록 목록)
+  return Promise.resolve() // 임시 반환
 }
 
 // --- useWikiPageBlocksSync 훅 ---
@@ -0,0 +1,38 @@
+import { useEffect, useRef } from "react"
+import {
+  type ExternalChangeEvent,
+  type ExternalChangeTarget,
+  listenExternalChanges,
+} from "~/services/tauri/externalChanges"
+import { usePlatform } from "~/hooks/usePlatform"
+
+/**
+ * 동기화 서비스 등 앱 밖에서 일어난 소설/에피소드/위키 페이지 파일 변경을 구독합니다.
+ * Tauri 앱에서만 동작하며, 컴포넌트가 사라지면 구독을 해제합니다.
+ */
+export const useExternalChanges = (
+  handler: (target: ExternalChangeTarget, event: ExternalChangeEvent) => void,
+) => {
+  const { isTauri } = usePlatform()
+  // 구독은 한 번만 하므로 최신 핸들러를 ref로 참조
+  const handlerRef = useRef(handler)
+  handlerRef.current = handler
+
+  useEffect(() => {
+    if (!isTauri) return
+
+    let unlisten: (() => void) | undefined
+    let disposed = false
+    void listenExternalChanges((target, event) =>
+      handlerRef.current(target, event),
+    ).then((fn) => {
+      if (disposed) fn()
+      else unlisten = fn
+    })
+
+    return () => {
+      disposed = true
+      unlisten?.()
+    }
+  }, [isTauri])
+}
@@ -41,7 +41,7 @@ export const useImageUpload = ({
         try {
           // getCoreApi를 사용하여 Tauri 핵심 API 함수들을 가져옵니다.
           const coreApi = await getCoreApi()
-          const { invoke, convertFileSrc } = coreApi
+          const { invoke } = coreApi
 
           const reader = new FileReader()
           reader.onload = async (event) => {
@@ -51,16 +51,14 @@ export const useImageUpload = ({
             ) {
               const fileBytes = Array.from(new Uint8Array(event.target.result))
 
-              // Rust 커맨드 호출하여 이미지 저장 및 절대 경로 받기
-              const absolutePath = await invoke<string>(CMD_SAVE_NOVEL_IMAGE, {
+              // Rust 커맨드 호출하여 이미지 저장 및 리소스 URI 받기
+              // (소설 폴더 기준 URI이므로 폴더를 옮겨도 유효하며, 표시할 때 resolveResourceUrl로 변환)
+              const resourceUri = await invoke<string>(CMD_SAVE_NOVEL_IMAGE, {
                 novelId: storageNovelId, // Rust 커맨드에는 novelId로 전달
                 originalFileName: file.name,
                 fileBytes,
               })
-
-              // 절대 경로를 웹뷰에서 사용 가능한 URL로 변환
-              const assetUrl = convertFileSrc(absolutePath)
-              onUploaded(assetUrl) // 변환된 URL 콜백
+              onUploaded(resourceUri)
               toaster.success({
                 title: "로컬 저장 완료",
                 description: `이미지가 저장되었습니다!`,
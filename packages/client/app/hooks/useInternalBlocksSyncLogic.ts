@@ -30,6 +30,12 @@ export interface DocumentContextForSync {
   [key: string]: any // GetEpisodeResponseDto 또는 GetWikiPageResponseDto 등의 전체 타입을 허용
 }
 
+// syncDeltaBlocksFn이 반환할 수 있는 동기화 결과
+export interface BlocksSyncOutcome {
+  // 일부 변경이 반영되지 않았거나 저장된 블록이 바뀌어, 블록을 다시 불러와야 하는 경우 true
+  reloadRequired?: boolean
+}
+
 // useInternalBlocksSyncLogic 훅의 Props 타입 정의
 interface UseInternalBlocksSyncLogicProps<
   DataType extends DocumentContextForSync,
@@ -43,7 +49,7 @@ interface UseInternalBlocksSyncLogicProps<
     context: DataType,
     deltas: DeltaBlock<BlockType>[],
     blocks: PartialBlock<BlockType>[] | null,
-  ) => Promise<unknown>
+  ) => Promise<BlocksSyncOutcome | void>
   /**
    * IndexedDB에 저장된 백업 데이터를 병합하기 직전에 호출되는 선택적 콜백 함수.
    * 예를 들어, 에피소드의 경우 이 시점에서 스냅샷을 생성할 수 있습니다.
@@ -57,6 +63,10 @@ interface UseInternalBlocksSyncLogicReturn<BlockType extends MuvelBlockType> {
   isLoadingBlocks: boolean
   syncState: SyncState
   handleDocUpdate: (doc: PMNode) => void
+  // 저장된 블록이 바뀐 경우(실행 취소 등) 에디터 내용을 새 블록으로 교체
+  replaceBlocks: (blocks: PartialBlock<BlockType>[]) => void
+  // 저장된 블록을 다시 불러와 에디터 내용을 교체 (앱 밖에서 파일이 바뀐 경우 등)
+  reloadBlocks: () => Promise<void>
 }
 
 export function useInternalBlocksSyncLogic<
@@ -209,7 +219,16 @@ export function useInternalBlocksSyncLogic<
 
       try {
         if (shareType === ShareType.Local) {
-          await syncDeltaBlocksFn(documentContext, changes, newBlocks)
+          const outcome = await syncDeltaBlocksFn(
+            documentContext,
+            changes,
+            newBlocks,
+          )
+          if (outcome?.reloadRequired) {
+            // 에디터 내용과 저장된 블록이 어긋났으므로 저장된 블록을 다시 불러옴
+            void init()
+            return
+          }
         } else {
           if (changes.length > 1000) {
             toaster.warning({
@@ -240,7 +259,7 @@ export function useInternalBlocksSyncLogic<
         }
       }
     },
-    [canEdit, documentContext, isOffline, syncState, syncDeltaBlocksFn],
+    [canEdit, documentContext, isOffline, syncState, syncDeltaBlocksFn, init],
   )
 
   const debouncedSaveBlocks = useDebouncedCallback(actualSaveBlocks, 500, {
@@ -268,10 +287,22 @@ export function useInternalBlocksSyncLogic<
     }
   }, [documentContext.id, debouncedSaveBlocks])
 
+  const replaceBlocks = useCallback(
+    (blocks: PartialBlock<BlockType>[]) => {
+      debouncedSaveBlocks.cancel()
+      originalBlocksRef.current = blocks
+      setInitialBlocks(blocks)
+      setSyncState(SyncState.Synced)
+    },
+    [debouncedSaveBlocks],
+  )
+
   return {
     initialBlocks,
     isLoadingBlocks,
     syncState,
     handleDocUpdate,
+    replaceBlocks,
+    reloadBlocks: init,
   }
 }
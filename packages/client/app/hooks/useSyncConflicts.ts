@@ -0,0 +1,75 @@
+import { useEffect, useRef } from "react"
+import {
+  listenSyncConflicts,
+  listSyncConflicts,
+  mergeSyncConflict,
+  type SyncConflictCopy,
+} from "~/services/tauri/syncConflictStorage"
+import { getErrorMessage } from "~/services/tauri/errors"
+import { toaster } from "~/components/ui/toaster"
+import { usePlatform } from "~/hooks/usePlatform"
+
+/**
+ * 소설 폴더에 동기화 충돌 사본이 있으면 알리고, 사용자가 원하면 원본에 병합합니다.
+ * 화면을 열 때 이미 남아 있던 사본과, 열어 둔 동안 새로 생긴 사본을 모두 알립니다.
+ */
+export const useSyncConflicts = (novelId: string, onMerged: () => void) => {
+  const { isTauri } = usePlatform()
+  // 구독은 소설마다 한 번만 하므로 최신 핸들러를 ref로 참조
+  const onMergedRef = useRef(onMerged)
+  onMergedRef.current = onMerged
+
+  useEffect(() => {
+    if (!isTauri) return
+
+    const merge = async (conflict: SyncConflictCopy) => {
+      try {
+        const result = await mergeSyncConflict(novelId, conflict.conflictPath)
+        toaster.success({
+          title: "충돌 사본 병합 완료",
+          description:
+            `블록 ${result.mergedBlockCount}개를 가져왔습니다. ` +
+            `원본이 더 최신인 블록 ${result.keptBlockCount}개와 ` +
+            `원본에서 삭제된 블록 ${result.skippedBlockCount}개는 ` +
+            "가져오지 않았습니다.",
+        })
+        onMergedRef.current()
+      } catch (e) {
+        toaster.error({
+          title: "충돌 사본 병합 오류",
+          description: getErrorMessage(e),
+        })
+      }
+    }
+
+    const notify = (conflict: SyncConflictCopy) => {
+      toaster.warning({
+        title: "동기화 충돌 사본이 있습니다",
+        description: conflict.conflictPath,
+        action: {
+          label: "병합",
+          onClick: () => void merge(conflict),
+        },
+      })
+    }
+
+    let unlisten: (() => void) | undefined
+    let disposed = false
+    void listenSyncConflicts(({ novelId: eventNovelId, conflict }) => {
+      if (eventNovelId === novelId) notify(conflict)
+    }).then((fn) => {
+      if (disposed) fn()
+      else unlisten = fn
+    })
+    void listSyncConflicts(novelId)
+      .then((conflicts) => {
+        if (!disposed) conflicts.forEach(notify)
+      })
+      .catch(() => {})
+
+    return () => {
+      disposed = true
+      unlisten?.()
+    }
+  }, [isTauri, novelId])
+}
@@ -0,0 +1,91 @@
+import { useEffect } from "react"
+import {
+  discardTempFile,
+  type LeftoverTempFiles,
+  listenLeftoverTempFilesFound,
+  recoverTempFile,
+  takeRecoverableTempFiles,
+} from "~/services/tauri/recoveryStorage"
+import { getErrorMessage } from "~/services/tauri/errors"
+import { getDialogApi } from "~/services/tauri/tauriApiProvider"
+import { toaster } from "~/components/ui/toaster"
+import { usePlatform } from "~/hooks/usePlatform"
+
+/**
+ * 이전 실행에서 저장 도중 남은 임시 파일이 있으면 복구 여부를 묻고,
+ * 복구할 필요가 없는 임시 파일은 삭제할지 묻습니다.
+ * 검사는 앱 시작 후 백그라운드에서 진행되므로, 검사 완료 이벤트를 받았을 때도 확인합니다.
+ */
+export const useTempFileRecovery = () => {
+  const { isTauri } = usePlatform()
+
+  useEffect(() => {
+    if (!isTauri) return
+
+    const handleLeftovers = async ({ recoverable, stale }: LeftoverTempFiles) => {
+      const { ask } = await getDialogApi()
+      for (const file of recoverable) {
+        const recover = await ask(
+          `${file.originalPath}\n\n` +
+            (file.originalExists
+              ? "파일이 손상되어 있습니다. "
+              : "파일이 존재하지 않습니다. ") +
+            "저장 도중 남은 임시 파일로 복구할까요?",
+          { title: "파일 복구", kind: "warning" },
+        )
+        try {
+          if (recover) {
+            await recoverTempFile(file.tempPath)
+            toaster.success({
+              title: "파일 복구 완료",
+              description: file.originalPath,
+            })
+          } else {
+            await discardTempFile(file.tempPath)
+          }
+        } catch (e) {
+          toaster.error({
+            title: "파일 복구 오류",
+            description: getErrorMessage(e),
+          })
+        }
+      }
+
+      if (!stale.length) return
+      const discard = await ask(
+        `${stale.join("\n")}\n\n` +
+          "저장 도중 남은 임시 파일이지만, 원본 파일이 온전하거나 임시 파일이 손상되어 복구할 필요가 없습니다. 삭제할까요?",
+        { title: "남은 임시 파일 정리", kind: "info" },
+      )
+      if (!discard) return
+      for (const tempPath of stale) {
+        try {
+          await discardTempFile(tempPath)
+        } catch (e) {
+          toaster.error({
+            title: "임시 파일 삭제 오류",
+            description: getErrorMessage(e),
+          })
+        }
+      }
+    }
+
+    const takeAndHandle = async () => {
+      await handleLeftovers(await takeRecoverableTempFiles())
+    }
+
+    let unlisten: (() => void) | undefined
+    let disposed = false
+    void listenLeftoverTempFilesFound(() => void takeAndHandle()).then((fn) => {
+      if (disposed) fn()
+      else unlisten = fn
+    })
+    // 이벤트를 구독하기 전에 검사가 끝났을 수 있으므로 한 번 확인
+    void takeAndHandle()
+
+    return () => {
+      disposed = true
+      unlisten?.()
+    }
+  }, [])
+}
@@ -1,10 +1,12 @@
 import {
   type CreateEpisodeBodyDto,
   type DeltaBlock,
+  type EditHistoryResult,
   type Episode as ApiEpisode,
   type GetEpisodeBlocksResponse,
   type GetEpisodeResponseDto,
   ShareType as ApiShareType,
+  type SyncResult,
   type UpdateEpisodeBodyDto,
 } from "muvel-api-types"
 
@@ -18,10 +20,13 @@ import {
 import { createCloudNovelEpisode } from "./api/api.novel"
 
 import {
+  compactLocalEpisodeJournal,
   createLocalNovelEpisode as createTauriLocalEpisode,
   deleteLocalEpisode as deleteTauriLocalEpisode,
   getLocalEpisodeById,
+  redoLocalEpisodeEdit,
   syncLocalDeltaBlocks,
+  undoLocalEpisodeEdit,
   updateLocalEpisodeMetadata as updateTauriLocalEpisodeMetadata,
 } from "./tauri/episodeStorage"
 import { getNovel, type NovelInput } from "./novelService"
@@ -182,6 +187,17 @@ export const deleteEpisode = async (
 export const getEpisodeBlocks = async (
   episodeInput: EpisodeInput,
 ): Promise<GetEpisodeBlocksResponse> => {
+  const { blocks } = await getEpisodeBlocksWithRevision(episodeInput)
+  return blocks
+}
+
+/**
+ * 에피소드 블록과 그 블록을 읽은 시점의 리비전을 함께 가져옵니다.
+ * 리비전은 로컬 에피소드에만 있으며, 블록 동기화 시 baseRevision으로 전달합니다.
+ */
+export const getEpisodeBlocksWithRevision = async (
+  episodeInput: EpisodeInput,
+): Promise<{ blocks: GetEpisodeBlocksResponse; revision?: number }> => {
   const { episodeId, novelShareType } =
     await resolveEpisodeContext(episodeInput)
 
@@ -191,16 +207,24 @@ export const getEpisodeBlocks = async (
         "로컬 에피소드 블록 조회는 Tauri 앱 환경에서만 가능합니다.",
       )
     const localEpisodeData = await getLocalEpisodeById(episodeId) // novelId 필요시 전달
-    return localEpisodeData.blocks
+    return {
+      blocks: localEpisodeData.blocks,
+      revision: localEpisodeData.revision,
+    }
   } else {
-    return getCloudEpisodeBlocks(episodeId)
+    return { blocks: await getCloudEpisodeBlocks(episodeId) }
   }
 }
 
+/**
+ * 변경된 블록을 동기화합니다.
+ * 로컬 에피소드는 동기화 결과(새 리비전, 거부된 변경 등)를 반환하고, 클라우드 에피소드는 null을 반환합니다.
+ */
 export const syncDeltaBlocks = async (
   episodeInput: EpisodeInput,
   deltaBlocks: DeltaBlock[] = [],
-): Promise<void> => {
+  baseRevision?: number,
+): Promise<SyncResult | null> => {
   const { episodeId, novelShareType } =
     await resolveEpisodeContext(episodeInput)
 
@@ -211,9 +235,40 @@ export const syncDeltaBlocks = async (
       )
     }
 
-    await syncLocalDeltaBlocks(episodeId, deltaBlocks)
-    return
+    return syncLocalDeltaBlocks(episodeId, deltaBlocks, baseRevision)
   } else {
-    return syncCloudDeltaBlocks(episodeId, deltaBlocks)
+    await syncCloudDeltaBlocks(episodeId, deltaBlocks)
+    return null
   }
 }
+
+/**
+ * 저장된 편집 기록으로 마지막 편집을 되돌리거나(undo) 되돌린 편집을 다시 적용합니다(redo).
+ * 편집 기록은 로컬 에피소드에만 있으므로, 클라우드 에피소드는 null을 반환합니다.
+ */
+export const stepEpisodeEditHistory = async (
+  episodeInput: EpisodeInput,
+  direction: "undo" | "redo",
+): Promise<EditHistoryResult | null> => {
+  const { episodeId, novelShareType } =
+    await resolveEpisodeContext(episodeInput)
+
+  if (novelShareType !== ApiShareType.Local || !IS_TAURI_APP) return null
+  return direction === "undo"
+    ? undoLocalEpisodeEdit(episodeId)
+    : redoLocalEpisodeEdit(episodeId)
+}
+
+/**
+ * 로컬 에피소드의 편집 저널을 에피소드 파일에 합칩니다.
+ * 클라우드 에피소드는 저널이 없으므로 아무것도 하지 않습니다.
+ */
+export const compactEpisodeJournal = async (
+  episodeInput: EpisodeInput,
+): Promise<void> => {
+  const { episodeId, novelShareType } =
+    await resolveEpisodeContext(episodeInput)
+
+  if (novelShareType !== ApiShareType.Local || !IS_TAURI_APP) return
+  await compactLocalEpisodeJournal(episodeId)
+}
@@ -11,6 +11,7 @@ import { pmNodeToMarkdown } from "~/services/io/markdown"
 import { pmNodeToHtml } from "~/services/io/html/pmNodeToHtml"
 import { docToBlocks } from "~/features/novel-editor/utils/blockConverter"
 import { getDialogApi, getFsPlugin, getPathApi } from "./tauri/tauriApiProvider"
+import { getErrorMessage } from "./tauri/errors"
 import { textToHwpx } from "~/services/io/hwpx/textToHwpx"
 import { pmNodeToDocx, textToDocx } from "~/services/io/docx"
 
@@ -81,8 +82,11 @@ export async function importEpisodes(
 
       if (deltaBlocks.length > 0) {
         const chunks = chunk(deltaBlocks, SYNC_CHUNK_SIZE)
+        // 방금 만든 에피소드이므로 첫 묶음은 리비전 확인 없이 보내고, 이후 묶음은 직전 결과의 리비전을 사용
+        let revision: number | undefined
         for (const blockChunk of chunks) {
-          await syncDeltaBlocks(episodeId, blockChunk)
+          const result = await syncDeltaBlocks(episodeId, blockChunk, revision)
+          revision = result?.revision
         }
       }
 
@@ -95,7 +99,7 @@ export async function importEpisodes(
       results.push({
         fileName: file.name,
         success: false,
-        error: error instanceof Error ? error.message : String(error),
+        error: getErrorMessage(error),
       })
     }
   }
@@ -0,0 +1,136 @@
+// app/services/tauri/attachmentStorage.ts
+import { getCoreApi } from "./tauriApiProvider"
+import {
+  CMD_ADD_ATTACHMENT,
+  CMD_DELETE_ATTACHMENT,
+  CMD_LIST_ATTACHMENTS,
+  CMD_RENAME_ATTACHMENT,
+  CMD_UPDATE_ATTACHMENT,
+} from "~/services/tauri/constants"
+
+/** 소설에 첨부한 자료 (참고 PDF, 지도, 배경 음악, 자료 스크랩 등) */
+export interface Attachment {
+  id: string
+  title: string
+  /** 첨부 폴더 안의 실제 파일 이름 */
+  fileName: string
+  mimeType: string
+  /** 파일 크기 (바이트) */
+  size: number
+  tags: string[]
+  linkedWikiPageIds: string[]
+  linkedEpisodeIds: string[]
+  createdAt: string
+  updatedAt: string
+  /** 파일을 불러올 리소스 URI (resolveResourceUrl로 웹뷰용 URL로 변환) */
+  uri: string
+}
+
+export interface CreateAttachmentOptions {
+  /** 첨부 폴더에 저장할 파일 이름. 지정하지 않으면 원본 파일 이름을 사용 */
+  originalFileName?: string
+  /** 지정하지 않으면 확장자를 뺀 파일 이름을 사용 */
+  title?: string
+  tags?: string[]
+  linkedWikiPageIds?: string[]
+  linkedEpisodeIds?: string[]
+}
+
+export type UpdateAttachmentData = Partial<
+  Pick<Attachment, "tags" | "linkedWikiPageIds" | "linkedEpisodeIds">
+>
+
+/**
+ * 파일을 소설에 첨부합니다. 파일은 Rust 쪽에서 첨부 폴더로 복사합니다.
+ * @param novelId 소설의 UUID
+ * @param sourcePath 첨부할 파일의 경로 (파일 선택 대화상자에서 고른 경로)
+ * @param options 저장할 파일 이름, 제목, 태그, 연결할 위키 페이지/에피소드
+ */
+export const addAttachment = async (
+  novelId: string,
+  sourcePath: string,
+  options?: CreateAttachmentOptions,
+): Promise<Attachment> => {
+  const { invoke } = await getCoreApi()
+  try {
+    return await invoke<Attachment>(CMD_ADD_ATTACHMENT, {
+      novelId,
+      sourcePath,
+      options,
+    })
+  } catch (error) {
+    console.error(`Error adding attachment to novel ${novelId}:`, error)
+    throw error
+  }
+}
+
+/**
+ * 소설의 첨부 파일 목록을 가져옵니다.
+ * @param linkedItemId 지정하면 이 에피소드/위키 페이지에 연결된 첨부 파일만 반환
+ */
+export const listAttachments = async (
+  novelId: string,
+  linkedItemId?: string,
+): Promise<Attachment[]> => {
+  const { invoke } = await getCoreApi()
+  try {
+    return await invoke<Attachment[]>(CMD_LIST_ATTACHMENTS, {
+      novelId,
+      linkedItemId,
+    })
+  } catch (error) {
+    console.error(`Error listing attachments for novel ${novelId}:`, error)
+    throw error
+  }
+}
+
+/** 첨부 파일의 제목과 실제 파일 이름을 바꿉니다. (확장자 유지) */
+export const renameAttachment = async (
+  novelId: string,
+  attachmentId: string,
+  newTitle: string,
+): Promise<Attachment> => {
+  const { invoke } = await getCoreApi()
+  try {
+    return await invoke<Attachment>(CMD_RENAME_ATTACHMENT, {
+      novelId,
+      attachmentId,
+      newTitle,
+    })
+  } catch (error) {
+    console.error(`Error renaming attachment ${attachmentId}:`, error)
+    throw error
+  }
+}
+
+/** 첨부 파일의 태그와 연결된 위키 페이지/에피소드를 바꿉니다. */
+export const updateAttachment = async (
+  novelId: string,
+  attachmentId: string,
+  data: UpdateAttachmentData,
+): Promise<Attachment> => {
+  const { invoke } = await getCoreApi()
+  try {
+    return await invoke<Attachment>(CMD_UPDATE_ATTACHMENT, {
+      novelId,
+      attachmentId,
+      data,
+    })
+  } catch (error) {
+    console.error(`Error updating attachment ${attachmentId}:`, error)
+    throw error
+  }
+}
+
+export const deleteAttachment = async (
+  novelId: string,
+  attachmentId: string,
+): Promise<void> => {
+  const { invoke } = await getCoreApi()
+  try {
+    await invoke<void>(CMD_DELETE_ATTACHMENT, { novelId, attachmentId })
+  } catch (error) {
+    console.error(`Error deleting attachment ${attachmentId}:`, error)
+    throw error
+  }
+}
@@ -21,8 +21,20 @@ export const CMD_UPDATE_LOCAL_NOVEL_METADATA = `update_local_novel_metadata_comm
 export const CMD_REMOVE_NOVEL_PROJECT = `remove_novel_project_command` // 인덱스 및 파일 모두 삭제
 export const CMD_OPEN_NOVEL_PROJECT_FOLDER = `open_novel_project_folder_command`
 export const CMD_SAVE_NOVEL_IMAGE = `save_novel_image_command`
+export const CMD_EXPORT_NOVEL_ARCHIVE = `export_novel_archive_command`
+export const CMD_IMPORT_NOVEL_ARCHIVE = `import_novel_archive_command`
 export const CMD_UPDATE_LOCAL_NOVEL_EPISODES_METADATA = `update_local_novel_episodes_metadata_command`
 
+// RESOURCE
+export const CMD_CLEAN_UNUSED_RESOURCES = `clean_unused_resources_command`
+
+// ATTACHMENT
+export const CMD_ADD_ATTACHMENT = `add_attachment_command`
+export const CMD_LIST_ATTACHMENTS = `list_attachments_command`
+export const CMD_RENAME_ATTACHMENT = `rename_attachment_command`
+export const CMD_UPDATE_ATTACHMENT = `update_attachment_command`
+export const CMD_DELETE_ATTACHMENT = `delete_attachment_command`
+
 // NOVEL SEARCH
 export const CMD_SEARCH_IN_NOVEL = "search_in_novel_command"
 
@@ -33,10 +45,37 @@ export const CMD_UPDATE_LOCAL_EPISODE_METADATA = `update_local_episode_metadata_
 export const CMD_DELETE_LOCAL_EPISODE = `delete_local_episode_command`
 export const CMD_LIST_LOCAL_EPISODE_SUMMARIES = `list_local_episode_summaries_command`
 export const CMD_SYNC_LOCAL_DELTA_BLOCKS = `sync_local_delta_blocks_command`
+export const CMD_COMPACT_LOCAL_EPISODE_JOURNAL = `compact_local_episode_journal_command`
+export const CMD_GET_LOCAL_EPISODE_EDIT_HISTORY = `get_local_episode_edit_history_command`
+export const CMD_UNDO_LOCAL_EPISODE_EDIT = `undo_local_episode_edit_command`
+export const CMD_REDO_LOCAL_EPISODE_EDIT = `redo_local_episode_edit_command`
 
 // SNAPSHOT
 export const CMD_CREATE_LOCAL_EPISODE_SNAPSHOT = `create_episode_snapshot_command`
 export const CMD_GET_LOCAL_EPISODE_SNAPSHOT = `get_episode_snapshots_command`
+export const CMD_RESTORE_LOCAL_EPISODE_SNAPSHOT = `restore_episode_snapshot_command`
+export const CMD_UPDATE_LOCAL_EPISODE_SNAPSHOT = `update_episode_snapshot_command`
+export const CMD_DELETE_LOCAL_EPISODE_SNAPSHOT = `delete_episode_snapshot_command`
+export const CMD_DIFF_LOCAL_EPISODE_SNAPSHOT = `diff_episode_snapshot_command`
+export const CMD_CREATE_LOCAL_NOVEL_SNAPSHOT = `create_novel_snapshot_command`
+export const CMD_GET_LOCAL_NOVEL_SNAPSHOTS = `get_novel_snapshots_command`
+export const CMD_GET_LOCAL_NOVEL_SNAPSHOT = `get_novel_snapshot_command`
+export const CMD_RESTORE_LOCAL_NOVEL_SNAPSHOT = `restore_novel_snapshot_command`
+export const CMD_UPDATE_LOCAL_NOVEL_SNAPSHOT = `update_novel_snapshot_command`
+export const CMD_DELETE_LOCAL_NOVEL_SNAPSHOT = `delete_novel_snapshot_command`
+export const CMD_CREATE_LOCAL_WIKI_PAGE_SNAPSHOT = `create_wiki_page_snapshot_command`
+export const CMD_GET_LOCAL_WIKI_PAGE_SNAPSHOTS = `get_wiki_page_snapshots_command`
+export const CMD_RESTORE_LOCAL_WIKI_PAGE_SNAPSHOT = `restore_wiki_page_snapshot_command`
+export const CMD_UPDATE_LOCAL_WIKI_PAGE_SNAPSHOT = `update_wiki_page_snapshot_command`
+export const CMD_DELETE_LOCAL_WIKI_PAGE_SNAPSHOT = `delete_wiki_page_snapshot_command`
+export const CMD_GET_SNAPSHOT_RETENTION_POLICY = `get_snapshot_retention_policy_command`
+export const CMD_UPDATE_SNAPSHOT_RETENTION_POLICY = `update_snapshot_retention_policy_command`
+
+// SETTINGS
+export const CMD_GET_APP_LOCALE = `get_app_locale_command`
+export const CMD_UPDATE_APP_LOCALE = `update_app_locale_command`
+export const CMD_GET_IMAGE_RESOURCE_SETTINGS = `get_image_resource_settings_command`
+export const CMD_UPDATE_IMAGE_RESOURCE_SETTINGS = `update_image_resource_settings_command`
 
 // WIKI
 export const CMD_CREATE_LOCAL_WIKI_PAGE_CMD = "create_wiki_page_command"
@@ -50,5 +89,28 @@ export const CMD_SYNC_LOCAL_WIKI_PAGE_BLOCKS_CMD = "sync_local_wiki_page_blocks"
 // OPEN FILE
 export const CMD_TAKE_INITIAL_OPEN = `take_initial_open`
 
+// TEMP FILE RECOVERY
+export const CMD_TAKE_RECOVERABLE_TEMP_FILES = `take_recoverable_temp_files_command`
+export const CMD_RECOVER_TEMP_FILE = `recover_temp_file_command`
+export const CMD_DISCARD_TEMP_FILE = `discard_temp_file_command`
+
+// SYNC CONFLICT COPIES
+export const CMD_LIST_SYNC_CONFLICTS = `list_sync_conflicts_command`
+export const CMD_MERGE_SYNC_CONFLICT = `merge_sync_conflict_command`
+export const CMD_DISCARD_SYNC_CONFLICT = `discard_sync_conflict_command`
+
 // LOCAL BACKUP
 export const CMD_BACKUP_CLOUD_EPISODE = `backup_cloud_episode_command`
+
+/** Tauri Event Name
+ * storage/novel_watcher.rs에 적힌 것과 동일하게 관리해 주세요
+ */
+
+// EXTERNAL CHANGES
+export const EVENT_NOVEL_CHANGED_EXTERNALLY = `novel-changed-externally`
+export const EVENT_EPISODE_CHANGED_EXTERNALLY = `episode-changed-externally`
+export const EVENT_WIKI_PAGE_CHANGED_EXTERNALLY = `wiki-page-changed-externally`
+export const EVENT_SYNC_CONFLICT_DETECTED = `sync-conflict-detected`
+
+// RECOVERY (storage/temp_file_recovery.rs)
+export const EVENT_LEFTOVER_TEMP_FILES_FOUND = `leftover-temp-files-found`
@@ -3,20 +3,27 @@ import { getCoreApi } from "./tauriApiProvider"
 import {
   type CreateEpisodeBodyDto,
   type DeltaBlock,
+  type EditHistoryResult,
+  type EditHistoryState,
   type Episode as ApiEpisode,
   type EpisodeBlock,
   type GetEpisodeResponseDto,
   type LocalEpisode,
   masterPermission,
+  type SyncResult,
   type UpdateEpisodeBodyDto,
 } from "muvel-api-types"
 import {
   CMD_BACKUP_CLOUD_EPISODE,
+  CMD_COMPACT_LOCAL_EPISODE_JOURNAL,
   CMD_CREATE_LOCAL_EPISODE,
   CMD_DELETE_LOCAL_EPISODE,
   CMD_GET_LOCAL_EPISODE_DATA,
+  CMD_GET_LOCAL_EPISODE_EDIT_HISTORY,
   CMD_LIST_LOCAL_EPISODE_SUMMARIES,
+  CMD_REDO_LOCAL_EPISODE_EDIT,
   CMD_SYNC_LOCAL_DELTA_BLOCKS,
+  CMD_UNDO_LOCAL_EPISODE_EDIT,
   CMD_UPDATE_LOCAL_EPISODE_METADATA,
 } from "~/services/tauri/constants"
 
@@ -132,19 +139,95 @@ export const listLocalEpisodeSummaries = async (
   }
 }
 
+/**
+ * 블록 변경분을 로컬 에피소드에 반영합니다.
+ * @param baseRevision 변경분을 만들 때 기준으로 삼은 에피소드 리비전 (생략하면 변경분 시각으로만 충돌 판단)
+ */
 export const syncLocalDeltaBlocks = async (
   episodeId: string,
   deltaBlocks: DeltaBlock[],
-) => {
+  baseRevision?: number,
+): Promise<SyncResult> => {
   const { invoke } = await getCoreApi()
   try {
-    return await invoke(CMD_SYNC_LOCAL_DELTA_BLOCKS, { episodeId, deltaBlocks })
+    return await invoke<SyncResult>(CMD_SYNC_LOCAL_DELTA_BLOCKS, {
+      episodeId,
+      deltaBlocks,
+      baseRevision,
+    })
   } catch (error) {
     console.error(`Error syncing local delta blocks:`, error)
     throw error
   }
 }
 
+/**
+ * 로컬 에피소드의 편집 저널을 에피소드 파일에 합칩니다. (에디터를 닫을 때 호출)
+ */
+export const compactLocalEpisodeJournal = async (
+  episodeId: string,
+): Promise<void> => {
+  const { invoke } = await getCoreApi()
+  try {
+    await invoke<void>(CMD_COMPACT_LOCAL_EPISODE_JOURNAL, { episodeId })
+  } catch (error) {
+    console.error(`Error compacting local episode journal:`, error)
+    throw error
+  }
+}
+
+/**
+ * 로컬 에피소드의 실행 취소/다시 실행 가능 여부를 가져옵니다.
+ * 기록은 앱을 다시 시작해도 유지됩니다.
+ */
+export const getLocalEpisodeEditHistory = async (
+  episodeId: string,
+): Promise<EditHistoryState> => {
+  const { invoke } = await getCoreApi()
+  try {
+    return await invoke<EditHistoryState>(CMD_GET_LOCAL_EPISODE_EDIT_HISTORY, {
+      episodeId,
+    })
+  } catch (error) {
+    console.error(`Error getting local episode edit history:`, error)
+    throw error
+  }
+}
+
+/**
+ * 로컬 에피소드의 마지막 편집을 되돌립니다.
+ */
+export const undoLocalEpisodeEdit = async (
+  episodeId: string,
+): Promise<EditHistoryResult> => {
+  const { invoke } = await getCoreApi()
+  try {
+    return await invoke<EditHistoryResult>(CMD_UNDO_LOCAL_EPISODE_EDIT, {
+      episodeId,
+    })
+  } catch (error) {
+    console.error(`Error undoing local episode edit:`, error)
+    throw error
+  }
+}
+
+/**
+ * 로컬 에피소드에서 되돌린 편집을 다시 적용합니다.
+ */
+export const redoLocalEpisodeEdit = async (
+  episodeId: string,
+): Promise<EditHistoryResult> => {
+  const { invoke } = await getCoreApi()
+  try {
+    return await invoke<EditHistoryResult>(CMD_REDO_LOCAL_EPISODE_EDIT, {
+      episodeId,
+    })
+  } catch (error) {
+    console.error(`Error redoing local episode edit:`, error)
+    throw error
+  }
+}
+
 export const backupCloudEpisodeToLocal = async (
   fullEpisodeContext: GetEpisodeResponseDto & { blocks: EpisodeBlock[] },
 ) => {
@@ -0,0 +1,41 @@
+// app/services/tauri/errors.ts
+
+/** Rust(MuvelError)가 직렬화해 보내는 오류 종류 */
+export enum MuvelErrorCode {
+  NotFound = "NOT_FOUND",
+  PermissionDenied = "PERMISSION_DENIED",
+  AlreadyExists = "ALREADY_EXISTS",
+  InvalidInput = "INVALID_INPUT",
+  Parse = "PARSE_ERROR",
+  Conflict = "CONFLICT",
+  UnsupportedVersion = "UNSUPPORTED_VERSION",
+  Io = "IO_ERROR",
+  Internal = "INTERNAL",
+}
+
+/** Tauri 커맨드가 실패했을 때 전달되는 구조화된 오류 */
+export interface MuvelError {
+  code: MuvelErrorCode
+  message: string
+  path?: string | null
+  id?: string | null
+}
+
+export const isMuvelError = (error: unknown): error is MuvelError =>
+  typeof error === "object" &&
+  error !== null &&
+  "code" in error &&
+  "message" in error &&
+  Object.values(MuvelErrorCode).includes(
+    (error as { code: unknown }).code as MuvelErrorCode,
+  )
+
+/**
+ * Tauri 커맨드 오류에서 사용자에게 보여줄 메시지를 꺼냅니다.
+ * 구조화된 오류가 아니면 문자열로 변환합니다.
+ */
+export const getErrorMessage = (error: unknown): string => {
+  if (isMuvelError(error)) return error.message
+  if (error instanceof Error) return error.message
+  return String(error)
+}
@@ -0,0 +1,43 @@
+// app/services/tauri/externalChanges.ts
+import { getEventApi } from "./tauriApiProvider"
+import {
+  EVENT_EPISODE_CHANGED_EXTERNALLY,
+  EVENT_NOVEL_CHANGED_EXTERNALLY,
+  EVENT_WIKI_PAGE_CHANGED_EXTERNALLY,
+} from "~/services/tauri/constants"
+
+export type ExternalChangeKind = "created" | "modified" | "removed"
+
+/** 동기화 서비스 등 앱 밖에서 소설 폴더의 파일이 바뀌었을 때 전달되는 정보 */
+export interface ExternalChangeEvent {
+  novelId: string
+  /** 소설 메타데이터(.muvl) 변경이면 null */
+  itemId: string | null
+  change: ExternalChangeKind
+}
+
+export type ExternalChangeTarget = "novel" | "episode" | "wikiPage"
+
+const EVENT_BY_TARGET: Record<ExternalChangeTarget, string> = {
+  novel: EVENT_NOVEL_CHANGED_EXTERNALLY,
+  episode: EVENT_EPISODE_CHANGED_EXTERNALLY,
+  wikiPage: EVENT_WIKI_PAGE_CHANGED_EXTERNALLY,
+}
+
+/**
+ * 앱 밖에서 일어난 소설/에피소드/위키 페이지 파일 변경을 구독합니다.
+ * 반환된 함수를 호출하면 구독을 해제합니다.
+ */
+export const listenExternalChanges = async (
+  handler: (target: ExternalChangeTarget, event: ExternalChangeEvent) => void,
+): Promise<() => void> => {
+  const { listen } = await getEventApi()
+  const unlisteners = await Promise.all(
+    (Object.keys(EVENT_BY_TARGET) as ExternalChangeTarget[]).map((target) =>
+      listen<ExternalChangeEvent>(EVENT_BY_TARGET[target], (event) =>
+        handler(target, event.payload),
+      ),
+    ),
+  )
+  return () => unlisteners.forEach((unlisten) => unlisten())
+}
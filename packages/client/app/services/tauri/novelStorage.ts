@@ -13,7 +13,9 @@ import { getAllLocalNovelEntries as getAllTauriLocalNovelEntries } from "~/servi
 import type { EpisodeData } from "~/providers/EpisodeProvider"
 import {
   CMD_CREATE_LOCAL_NOVEL,
+  CMD_EXPORT_NOVEL_ARCHIVE,
   CMD_GET_LOCAL_NOVEL_DETAILS,
+  CMD_IMPORT_NOVEL_ARCHIVE,
   CMD_OPEN_NOVEL_PROJECT_FOLDER,
   CMD_SEARCH_IN_NOVEL,
   CMD_UPDATE_LOCAL_NOVEL_EPISODES_METADATA,
@@ -172,3 +174,48 @@ export const searchInLocalNovel = async (
     throw error
   }
 }
+
+/**
+ * 로컬 소설 프로젝트 전체(에피소드, 위키, 스냅샷, 이미지)를 `.muvlz` 아카이브 파일로 내보냅니다.
+ * @param novelId 내보낼 소설의 UUID
+ * @param archivePath 저장할 아카이브 파일 경로
+ */
+export const exportLocalNovelArchive = async (
+  novelId: string,
+  archivePath: string,
+): Promise<void> => {
+  const { invoke } = await getCoreApi()
+  try {
+    await invoke(CMD_EXPORT_NOVEL_ARCHIVE, { novelId, archivePath })
+  } catch (error) {
+    console.error(`Error exporting local novel archive for ${novelId}:`, error)
+    throw error
+  }
+}
+
+/**
+ * `.muvlz` 아카이브를 새 소설 폴더에 풀고 로컬 소설로 등록합니다.
+ * 같은 ID의 소설이 이미 등록되어 있으면 실패합니다.
+ * @param archivePath 가져올 아카이브 파일 경로
+ * @param targetDirectoryPath 소설 폴더를 만들 위치 (null이면 기본 경로)
+ */
+export const importLocalNovelArchive = async (
+  archivePath: string,
+  targetDirectoryPath: string | null,
+): Promise<GetLocalNovelResponseDto> => {
+  const { invoke } = await getCoreApi()
+  try {
+    const novel = await invoke<Omit<GetLocalNovelResponseDto, "permissions">>(
+      CMD_IMPORT_NOVEL_ARCHIVE,
+      { archivePath, targetDirectoryPath },
+    )
+
+    return {
+      ...novel,
+      permissions: masterPermission,
+    }
+  } catch (error) {
+    console.error(`Error importing local novel archive ${archivePath}:`, error)
+    throw error
+  }
+}
@@ -0,0 +1,76 @@
+// app/services/tauri/recoveryStorage.ts
+import { getCoreApi, getEventApi } from "./tauriApiProvider"
+import {
+  CMD_DISCARD_TEMP_FILE,
+  CMD_RECOVER_TEMP_FILE,
+  CMD_TAKE_RECOVERABLE_TEMP_FILES,
+  EVENT_LEFTOVER_TEMP_FILES_FOUND,
+} from "~/services/tauri/constants"
+
+/** 쓰는 도중 앱이 종료되어 남은 임시 파일 중 원본 대신 복구할 수 있는 파일 */
+export interface RecoverableTempFile {
+  tempPath: string
+  originalPath: string
+  tempModifiedAt: string
+  originalModifiedAt: string | null
+  /** false이면 원본이 없음, true이면 원본이 손상됨 */
+  originalExists: boolean
+}
+
+/** 이전 실행에서 쓰는 도중 중단되어 남은 임시 파일 */
+export interface LeftoverTempFiles {
+  recoverable: RecoverableTempFile[]
+  /** 원본이 온전하거나 임시 파일이 손상되어 복구할 필요가 없는 임시 파일 경로 */
+  stale: string[]
+}
+
+/**
+ * 앱 시작 시 발견된 남은 임시 파일 목록을 가져옵니다. (한 번만 반환)
+ * 검사는 백그라운드에서 진행되므로, 검사가 끝나기 전이면 빈 목록이 반환될 수 있습니다.
+ */
+export const takeRecoverableTempFiles = async (): Promise<LeftoverTempFiles> => {
+  const { invoke } = await getCoreApi()
+  try {
+    return await invoke<LeftoverTempFiles>(CMD_TAKE_RECOVERABLE_TEMP_FILES)
+  } catch (error) {
+    console.error("Error taking recoverable temp files:", error)
+    return { recoverable: [], stale: [] }
+  }
+}
+
+/**
+ * 남은 임시 파일 검사가 끝나고 확인할 파일이 있을 때 호출될 함수를 등록합니다.
+ * 반환된 함수를 호출하면 구독을 해제합니다.
+ */
+export const listenLeftoverTempFilesFound = async (
+  handler: () => void,
+): Promise<() => void> => {
+  const { listen } = await getEventApi()
+  return listen(EVENT_LEFTOVER_TEMP_FILES_FOUND, () => handler())
+}
+
+/**
+ * 임시 파일로 원본 파일을 교체합니다.
+ */
+export const recoverTempFile = async (tempPath: string): Promise<void> => {
+  const { invoke } = await getCoreApi()
+  try {
+    await invoke(CMD_RECOVER_TEMP_FILE, { tempPath })
+  } catch (error) {
+    console.error(`Error recovering temp file ${tempPath}:`, error)
+    throw error
+  }
+}
+
+/**
+ * 복구하지 않을 임시 파일을 삭제합니다.
+ */
+export const discardTempFile = async (tempPath: string): Promise<void> => {
+  const { invoke } = await getCoreApi()
+  try {
+    await invoke(CMD_DISCARD_TEMP_FILE, { tempPath })
+  } catch (error) {
+    console.error(`Error discarding temp file ${tempPath}:`, error)
+    throw error
+  }
+}
@@ -0,0 +1,36 @@
+// app/services/tauri/resourceStorage.ts
+import { getCoreApi } from "./tauriApiProvider"
+import { CMD_CLEAN_UNUSED_RESOURCES } from "~/services/tauri/constants"
+
+/** 사용하지 않는 이미지 리소스 정리 결과 */
+export interface ResourceCleanupResult {
+  /** 삭제한 파일의 resources 기준 경로. dryRun이면 삭제할 파일 목록 */
+  removedFiles: string[]
+  /** 삭제로 확보한 용량 (바이트) */
+  freedBytes: number
+  /** 참조되지 않지만 최근에 수정되어 남겨 둔 파일 수 */
+  skippedRecentCount: number
+  dryRun: boolean
+}
+
+/**
+ * 에피소드, 위키, 스냅샷 어디에서도 참조하지 않는 이미지를 정리합니다.
+ * 최근에 추가/수정된 이미지는 아직 저장되지 않은 블록이 참조할 수 있으므로 남겨 둡니다.
+ * @param novelId 정리할 소설의 UUID
+ * @param dryRun true이면 삭제하지 않고 삭제할 파일 목록만 반환
+ */
+export const cleanUnusedResources = async (
+  novelId: string,
+  dryRun = false,
+): Promise<ResourceCleanupResult> => {
+  const { invoke } = await getCoreApi()
+  try {
+    return await invoke<ResourceCleanupResult>(CMD_CLEAN_UNUSED_RESOURCES, {
+      novelId,
+      dryRun,
+    })
+  } catch (error) {
+    console.error(`Error cleaning unused resources for ${novelId}:`, error)
+    throw error
+  }
+}
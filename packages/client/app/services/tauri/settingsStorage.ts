@@ -0,0 +1,81 @@
+// app/services/tauri/settingsStorage.ts
+import { getCoreApi } from "./tauriApiProvider"
+import {
+  CMD_GET_APP_LOCALE,
+  CMD_GET_IMAGE_RESOURCE_SETTINGS,
+  CMD_UPDATE_APP_LOCALE,
+  CMD_UPDATE_IMAGE_RESOURCE_SETTINGS,
+} from "~/services/tauri/constants"
+
+/** 백엔드 오류 메시지와 기본 이름(새 에피소드 제목 등)에 사용하는 언어 */
+export type AppLocale = "ko" | "en" | "ja"
+
+/** 소설에 이미지를 저장할 때의 처리 설정 */
+export interface ImageResourceSettings {
+  /** 가로나 세로가 이 값(px)보다 큰 이미지는 줄여서 저장 (null이면 원본 그대로 저장) */
+  maxDimension: number | null
+  /** 줄인 이미지를 JPEG로 다시 인코딩할 때의 품질 (1~100) */
+  jpegQuality: number
+}
+
+/**
+ * 현재 백엔드 메시지 언어를 가져옵니다.
+ */
+export const getAppLocale = async (): Promise<AppLocale> => {
+  const { invoke } = await getCoreApi()
+  try {
+    return await invoke<AppLocale>(CMD_GET_APP_LOCALE)
+  } catch (error) {
+    console.error("Error getting app locale:", error)
+    throw error
+  }
+}
+
+/**
+ * 백엔드 메시지 언어를 변경합니다. 이후 발생하는 오류 메시지부터 적용됩니다.
+ */
+export const updateAppLocale = async (
+  locale: AppLocale,
+): Promise<AppLocale> => {
+  const { invoke } = await getCoreApi()
+  try {
+    return await invoke<AppLocale>(CMD_UPDATE_APP_LOCALE, { locale })
+  } catch (error) {
+    console.error(`Error updating app locale to ${locale}:`, error)
+    throw error
+  }
+}
+
+/**
+ * 이미지 저장 설정을 가져옵니다.
+ */
+export const getImageResourceSettings =
+  async (): Promise<ImageResourceSettings> => {
+    const { invoke } = await getCoreApi()
+    try {
+      return await invoke<ImageResourceSettings>(
+        CMD_GET_IMAGE_RESOURCE_SETTINGS,
+      )
+    } catch (error) {
+      console.error("Error getting image resource settings:", error)
+      throw error
+    }
+  }
+
+/**
+ * 이미지 저장 설정을 변경합니다. 이미 저장된 이미지에는 적용되지 않습니다.
+ */
+export const updateImageResourceSettings = async (
+  imageResources: ImageResourceSettings,
+): Promise<ImageResourceSettings> => {
+  const { invoke } = await getCoreApi()
+  try {
+    return await invoke<ImageResourceSettings>(
+      CMD_UPDATE_IMAGE_RESOURCE_SETTINGS,
+      { imageResources },
+    )
+  } catch (error) {
+    console.error("Error updating image resource settings:", error)
+    throw error
+  }
+}
@@ -0,0 +1,108 @@
+// app/services/tauri/syncConflictStorage.ts
+import { getCoreApi, getEventApi } from "./tauriApiProvider"
+import {
+  CMD_DISCARD_SYNC_CONFLICT,
+  CMD_LIST_SYNC_CONFLICTS,
+  CMD_MERGE_SYNC_CONFLICT,
+  EVENT_SYNC_CONFLICT_DETECTED,
+} from "~/services/tauri/constants"
+
+export type SyncConflictKind = "novel" | "episode" | "wiki_page"
+
+/** 동기화 서비스(Dropbox, Syncthing 등)가 만든 충돌 사본 파일 */
+export interface SyncConflictCopy {
+  kind: SyncConflictKind
+  /** 소설 메타데이터(.muvl)의 사본이면 null */
+  itemId: string | null
+  conflictPath: string
+  originalPath: string
+  conflictModifiedAt: string | null
+  originalModifiedAt: string | null
+  originalExists: boolean
+}
+
+/** 충돌 사본을 원본에 병합한 결과 */
+export interface SyncConflictMergeResult {
+  kind: SyncConflictKind
+  itemId: string | null
+  /** 사본에서 가져온 블록 수 */
+  mergedBlockCount: number
+  /** 원본 쪽이 더 나중에 수정되어 그대로 둔 블록 수 */
+  keptBlockCount: number
+  /** 원본에서 삭제된 것으로 보고 가져오지 않은 블록 수 */
+  skippedBlockCount: number
+  /** 제목 등 메타데이터를 사본에서 가져왔는지 여부 */
+  metadataFromCopy: boolean
+}
+
+export interface SyncConflictDetectedEvent {
+  novelId: string
+  conflict: SyncConflictCopy
+}
+
+/**
+ * 소설 폴더의 동기화 충돌 사본 목록을 가져옵니다.
+ */
+export const listSyncConflicts = async (
+  novelId: string,
+): Promise<SyncConflictCopy[]> => {
+  const { invoke } = await getCoreApi()
+  try {
+    return await invoke<SyncConflictCopy[]>(CMD_LIST_SYNC_CONFLICTS, {
+      novelId,
+    })
+  } catch (error) {
+    console.error(`Error listing sync conflicts for novel ${novelId}:`, error)
+    throw error
+  }
+}
+
+/**
+ * 충돌 사본을 원본에 블록 단위로 병합하고 사본을 삭제합니다.
+ * 병합 전 원본은 `merge` 사유의 스냅샷으로 보관됩니다.
+ */
+export const mergeSyncConflict = async (
+  novelId: string,
+  conflictPath: string,
+): Promise<SyncConflictMergeResult> => {
+  const { invoke } = await getCoreApi()
+  try {
+    return await invoke<SyncConflictMergeResult>(CMD_MERGE_SYNC_CONFLICT, {
+      novelId,
+      conflictPath,
+    })
+  } catch (error) {
+    console.error(`Error merging sync conflict ${conflictPath}:`, error)
+    throw error
+  }
+}
+
+/**
+ * 병합하지 않을 충돌 사본을 삭제합니다. (원본 유지)
+ */
+export const discardSyncConflict = async (
+  novelId: string,
+  conflictPath: string,
+): Promise<void> => {
+  const { invoke } = await getCoreApi()
+  try {
+    await invoke(CMD_DISCARD_SYNC_CONFLICT, { novelId, conflictPath })
+  } catch (error) {
+    console.error(`Error discarding sync conflict ${conflictPath}:`, error)
+    throw error
+  }
+}
+
+/**
+ * 감시 중인 소설 폴더에 새 충돌 사본이 생기면 알림을 받습니다.
+ * 반환된 함수를 호출하면 구독을 해제합니다.
+ */
+export const listenSyncConflicts = async (
+  handler: (event: SyncConflictDetectedEvent) => void,
+): Promise<() => void> => {
+  const { listen } = await getEventApi()
+  return listen<SyncConflictDetectedEvent>(
+    EVENT_SYNC_CONFLICT_DETECTED,
+    (event) => handler(event.payload),
+  )
+}
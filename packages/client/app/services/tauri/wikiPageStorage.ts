@@ -7,7 +7,13 @@ import type {
   UpdateWikiPageRequestBody,
   WikiBlockType,
   WikiPage,
+  WikiPageSyncResult,
 } from "muvel-api-types"
+import { getCoreApi } from "~/services/tauri/tauriApiProvider"
+import {
+  CMD_GET_LOCAL_WIKI_PAGE_CMD,
+  CMD_SYNC_LOCAL_WIKI_PAGE_BLOCKS_CMD,
+} from "~/services/tauri/constants"
 
 /**
  * 로컬 위키 페이지 메타데이터 타입 (예시)
@@ -69,18 +75,34 @@ export const deleteLocalWikiPage = async (
 export const getLocalWikiPageBlocks = async (
   wikiPageId: string,
 ): Promise<PartialWikiBlock[]> => {
-  // return tauriApi().invoke<Block[]>(GET_LOCAL_WIKI_PAGE_BLOCKS_CMD, { wikiPageId });
-  throw new Error(
-    "Tauri: 로컬 위키 페이지 블록 조회 기능이 아직 구현되지 않았습니다.",
-  )
+  const { invoke } = await getCoreApi()
+  try {
+    const page = await invoke<LocalWikiPageData>(CMD_GET_LOCAL_WIKI_PAGE_CMD, {
+      pageId: wikiPageId,
+    })
+    return (page.blocks ?? []) as PartialWikiBlock[]
+  } catch (error) {
+    console.error(`Error getting local wiki page blocks ${wikiPageId}:`, error)
+    throw error
+  }
 }
 
+/**
+ * 위키 페이지 블록 변경분을 저장합니다.
+ * 다른 곳에서 먼저 수정되어 적용하지 않은 변경분은 결과의 `rejected`로 돌아옵니다.
+ */
 export const syncLocalWikiPageBlocks = async (
   wikiPageId: string,
   deltaBlocks: DeltaBlock<WikiBlockType>[],
-): Promise<void> => {
-  // return tauriApi().invoke<Block[]>(SYNC_LOCAL_WIKI_PAGE_BLOCKS_CMD, { wikiPageId, deltaBlocks });
-  throw new Error(
-    "Tauri: 로컬 위키 페이지 블록 동기화 기능이 아직 구현되지 않았습니다.",
-  )
+): Promise<WikiPageSyncResult> => {
+  const { invoke } = await getCoreApi()
+  try {
+    return await invoke<WikiPageSyncResult>(
+      CMD_SYNC_LOCAL_WIKI_PAGE_BLOCKS_CMD,
+      { wikiPageId, deltaBlocks },
+    )
+  } catch (error) {
+    console.error(`Error syncing local wiki page blocks ${wikiPageId}:`, error)
+    throw error
+  }
 }
@@ -7,6 +7,7 @@ import {
   type UpdateWikiPageRequestBody,
   WikiBlockType,
   type WikiPage,
+  type WikiPageSyncResult,
 } from "muvel-api-types"
 import * as cloudWikiApi from "./api/api.wiki"
 import * as localWikiStorage from "./tauri/wikiPageStorage"
@@ -107,10 +108,14 @@ export const getWikiPageBlocks = async (
   return await cloudWikiApi.getCloudWikiPageBlocks(wikiPageInput.id)
 }
 
+/**
+ * 위키 페이지 블록 변경분을 저장합니다.
+ * 로컬 위키 페이지는 동기화 결과를 반환하고, 클라우드 위키 페이지는 null을 반환합니다.
+ */
 export const syncWikiPageBlocks = async (
   wikiPageInput: WikiPageInput,
   deltaBlocks: DeltaBlock<WikiBlockType>[],
-): Promise<void> => {
+): Promise<WikiPageSyncResult | null> => {
   const novelShareType = await resolveWikiPageNovelShareType(
     wikiPageInput.novelId,
     wikiPageInput.novelShareType,
@@ -123,5 +128,5 @@ export const syncWikiPageBlocks = async (
     )
   }
   await cloudWikiApi.syncCloudWikiPageBlocks(wikiPageInput.id, deltaBlocks)
-  return
+  return null
 }
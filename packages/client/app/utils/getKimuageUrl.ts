@@ -1,10 +1,13 @@
+import { resolveResourceUrl, toThumbnailUri } from "~/utils/resourceUrl"
+
 export const getKimuageUrl = (
   src?: string,
   isThumbnail?: boolean,
   thumbnailWidth?: number,
 ) => {
-  // 키뮈지 이미지 서버 URL이 아닌 경우
-  if (!src?.toString().includes("image.kimustory.net")) return src
+  // 키뮈지 이미지 서버 URL이 아닌 경우 (로컬 소설 리소스는 웹뷰용 URL로 변환)
+  if (!src?.toString().includes("image.kimustory.net"))
+    return resolveResourceUrl(src && isThumbnail ? toThumbnailUri(src) : src)
   let i = src + (isThumbnail ? "/thumbnail" : "/view")
   if (thumbnailWidth) {
     i += `?width=${thumbnailWidth}`
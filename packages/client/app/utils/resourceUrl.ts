@@ -0,0 +1,43 @@
+// 로컬 소설의 리소스 URI. Rust(storage/resource_uri.rs)와 같은 형식을 사용합니다.
+// 예: muvel-resource://localhost/{소설 ID}/images/{파일 이름}
+const RESOURCE_URI_PREFIX = "muvel-resource://localhost/"
+// Windows/Android 웹뷰는 사용자 정의 스킴을 http://{스킴}.localhost/ 로 요청해야 함
+const WEBVIEW_RESOURCE_URL_PREFIX = "http://muvel-resource.localhost/"
+
+const usesLocalhostSubdomain = () =>
+  typeof navigator !== "undefined" &&
+  /Windows|Android/.test(navigator.userAgent)
+
+/**
+ * 저장된 리소스 URI를 웹뷰에서 불러올 수 있는 URL로 바꿉니다.
+ * 리소스 URI가 아니면 그대로 반환합니다.
+ */
+export const resolveResourceUrl = (src?: string) => {
+  if (!src?.startsWith(RESOURCE_URI_PREFIX) || !usesLocalhostSubdomain()) {
+    return src
+  }
+  return WEBVIEW_RESOURCE_URL_PREFIX + src.slice(RESOURCE_URI_PREFIX.length)
+}
+
+/**
+ * 웹뷰용 리소스 URL을 저장 형식의 리소스 URI로 되돌립니다.
+ * 리소스 URL이 아니면 그대로 반환합니다.
+ */
+export const toResourceUri = (src: string) => {
+  if (!src.startsWith(WEBVIEW_RESOURCE_URL_PREFIX)) return src
+  return RESOURCE_URI_PREFIX + src.slice(WEBVIEW_RESOURCE_URL_PREFIX.length)
+}
+
+/**
+ * 이미지 리소스 URI를 썸네일 URI로 바꿉니다. (썸네일은 처음 요청될 때 만들어짐)
+ * 이미지 리소스 URI가 아니면 그대로 반환합니다.
+ */
+export const toThumbnailUri = (src: string) => {
+  if (!src.startsWith(RESOURCE_URI_PREFIX)) return src
+  // muvel-resource://localhost/{소설 ID}/images/... → .../{소설 ID}/thumbnails/...
+  const [novelId, subdir, ...rest] = src
+    .slice(RESOURCE_URI_PREFIX.length)
+    .split("/")
+  if (subdir !== "images" || rest.length === 0) return src
+  return `${RESOURCE_URI_PREFIX}${novelId}/thumbnails/${rest.join("/")}`
+}
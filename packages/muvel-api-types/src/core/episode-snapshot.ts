@@ -1,5 +1,6 @@
 import { EpisodeBlock } from "./block"
 import { SnapshotReason } from "../enums"
+import { WikiPage } from "./wiki-page"
 
 export interface EpisodeSnapshot {
   id: string
@@ -7,4 +8,18 @@ export interface EpisodeSnapshot {
   reason: SnapshotReason
   blocks: EpisodeBlock[]
   createdAt: string
+  label?: string
+  note?: string
+  pinned?: boolean
+}
+
+export interface WikiPageSnapshot {
+  id: string
+  wikiPageId: string
+  reason: SnapshotReason
+  createdAt: string
+  label?: string
+  note?: string
+  pinned?: boolean
+  page: WikiPage & { blocks: EpisodeBlock[] }
 }
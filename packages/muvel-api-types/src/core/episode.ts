@@ -20,6 +20,8 @@ export interface Episode {
 // *.mvle 형식과 완벽히 대응해야 함
 export interface LocalEpisode extends Episode {
   blocks: EpisodeBlock[]
+  // 블록 내용이 바뀔 때마다 증가하는 리비전
+  revision?: number
 }
 
 export const initialEpisode: Episode = {
@@ -6,4 +6,5 @@ export * from "./user"
 export * from "./permission"
 export * from "./ai-analysis"
 export * from "./episode-snapshot"
+export * from "./novel-snapshot"
 export * from "../dto/search-api"
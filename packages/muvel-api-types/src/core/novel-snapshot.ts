@@ -0,0 +1,44 @@
+import { SnapshotReason } from "../enums"
+import { EpisodeBlock } from "./block"
+import { LocalEpisode } from "./episode"
+import { BaseNovel } from "./novel"
+import { WikiPage } from "./wiki-page"
+
+// 소설 전체 스냅샷 (.muvl 메타데이터, 모든 에피소드와 위키 페이지)
+export interface NovelSnapshot {
+  id: string
+  novelId: string
+  reason: SnapshotReason
+  label?: string
+  note?: string
+  pinned?: boolean // 고정된 스냅샷은 보관 정책에 의해 삭제되지 않음
+  createdAt: string
+  novel: BaseNovel
+  episodes: LocalEpisode[]
+  wikiPages: (WikiPage & { blocks: EpisodeBlock[] })[]
+}
+
+export interface NovelSnapshotItemSummary {
+  id: string
+  title: string
+}
+
+export interface NovelSnapshotSummary {
+  id: string
+  novelId: string
+  reason: SnapshotReason
+  label?: string
+  note?: string
+  pinned?: boolean
+  createdAt: string
+  novelTitle: string
+  episodes: NovelSnapshotItemSummary[]
+  wikiPages: NovelSnapshotItemSummary[]
+}
+
+// 일부 항목만 복원할 때 사용. 생략하면 소설 전체를 복원
+export interface RestoreNovelSnapshotOptions {
+  novelMetadata?: boolean
+  episodeIds?: string[]
+  wikiPageIds?: string[]
+}
@@ -1,4 +1,4 @@
-import { BaseBlock } from "../core"
+import { BaseBlock, EpisodeBlock } from "../core"
 import { EpisodeBlockType, MuvelBlockType, WikiBlockType } from "../enums"
 
 export enum DeltaBlockAction {
@@ -15,3 +15,48 @@ export type DeltaBlock<BType = MuvelBlockType> = Pick<BaseBlock<BType>, "id"> &
 
 export type DeltaEpisodeBlock = DeltaBlock<EpisodeBlockType>
 export type DeltaWikiBlock = DeltaBlock<WikiBlockType>
+
+export enum DeltaRejectReason {
+  MissingBlock = "missingBlock",
+  Stale = "stale",
+}
+
+export interface RejectedDelta {
+  id: string
+  action: DeltaBlockAction
+  reason: DeltaRejectReason
+}
+
+// 로컬 에피소드 블록 동기화 결과
+export interface SyncResult {
+  revision: number
+  contentLength: number
+  appliedCount: number
+  rejected: RejectedDelta[]
+  staleBase: boolean
+  /** 서버에서 블록 order를 다시 매긴 경우 true (블록을 다시 불러와야 함) */
+  rebalanced: boolean
+}
+
+// 로컬 위키 페이지 블록 동기화 결과 (위키 페이지에는 리비전이 없음)
+export interface WikiPageSyncResult {
+  appliedCount: number
+  rejected: RejectedDelta[]
+  /** 서버에서 블록 order를 다시 매긴 경우 true (블록을 다시 불러와야 함) */
+  rebalanced: boolean
+}
+
+// 로컬 에피소드 실행 취소/다시 실행 가능 여부
+export interface EditHistoryState {
+  canUndo: boolean
+  canRedo: boolean
+}
+
+// 로컬 에피소드 실행 취소/다시 실행 결과
+export interface EditHistoryResult extends EditHistoryState {
+  /** 되돌리거나 다시 적용할 편집이 있었는지 여부 */
+  applied: boolean
+  revision: number
+  contentLength: number
+  blocks: EpisodeBlock[]
+}
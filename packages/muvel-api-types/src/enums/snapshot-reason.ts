@@ -2,4 +2,6 @@ export enum SnapshotReason {
   Merge = "merge",
   Manual = "manual",
   Autosave = "autosave",
+  Restore = "restore",
+  Update = "update",
 }
@@ -0,0 +1,65 @@
+use crate::models::attachment::{Attachment, CreateAttachmentOptions, UpdateAttachmentData};
+use crate::models::error::MuvelResult;
+use crate::repositories::attachment_repository::AttachmentRepository;
+use std::path::Path;
+use tauri::{command, AppHandle};
+
+/// `source_path`의 파일을 소설에 첨부합니다. (`resources/attachments`에 복사해 메타데이터와 함께 저장)
+/// 파일 내용을 IPC로 주고받지 않도록, 프런트엔드는 파일 선택 대화상자에서 고른 경로를 넘깁니다.
+#[command]
+pub fn add_attachment_command(
+    app_handle: AppHandle,
+    novel_id: String,
+    source_path: String,
+    options: Option<CreateAttachmentOptions>,
+) -> MuvelResult<Attachment> {
+    let repo = AttachmentRepository::new(&app_handle);
+    repo.add_attachment(
+        &novel_id,
+        Path::new(&source_path),
+        options.unwrap_or_default(),
+    )
+}
+
+/// 소설의 첨부 파일 목록을 가져옵니다. `linked_item_id`를 지정하면 그 에피소드/위키 페이지에 연결된 것만 반환합니다.
+#[command]
+pub fn list_attachments_command(
+    app_handle: AppHandle,
+    novel_id: String,
+    linked_item_id: Option<String>,
+) -> MuvelResult<Vec<Attachment>> {
+    let repo = AttachmentRepository::new(&app_handle);
+    repo.list_attachments(&novel_id, linked_item_id.as_deref())
+}
+
+#[command]
+pub fn rename_attachment_command(
+    app_handle: AppHandle,
+    novel_id: String,
+    attachment_id: String,
+    new_title: String,
+) -> MuvelResult<Attachment> {
+    let repo = AttachmentRepository::new(&app_handle);
+    repo.rename_attachment(&novel_id, &attachment_id, &new_title)
+}
+
+#[command]
+pub fn update_attachment_command(
+    app_handle: AppHandle,
+    novel_id: String,
+    attachment_id: String,
+    data: UpdateAttachmentData,
+) -> MuvelResult<Attachment> {
+    let repo = AttachmentRepository::new(&app_handle);
+    repo.update_attachment(&novel_id, &attachment_id, data)
+}
+
+#[command]
+pub fn delete_attachment_command(
+    app_handle: AppHandle,
+    novel_id: String,
+    attachment_id: String,
+) -> MuvelResult<()> {
+    let repo = AttachmentRepository::new(&app_handle);
+    repo.delete_attachment(&novel_id, &attachment_id)
+}
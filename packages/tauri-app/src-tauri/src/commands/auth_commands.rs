@@ -1,9 +1,11 @@
 // src-tauri/src/app_lib/auth.rs
+use crate::i18n::Message;
+use crate::models::error::{MuvelError, MuvelResult};
 use tiny_http::{Header, Response, Server};
 use url::Url;
 
 #[tauri::command]
-pub fn wait_for_token() -> Result<String, String> {
+pub fn wait_for_token() -> MuvelResult<String> {
     let html = r#"<!DOCTYPE html>
     <html lang="ko">
     <head>
@@ -20,7 +22,8 @@ pub fn wait_for_token() -> Result<String, String> {
     </body>
     </html>"#;
 
-    let server = Server::http("127.0.0.1:53682").map_err(|e| e.to_string())?;
+    let server = Server::http("127.0.0.1:53682")
+        .map_err(|e| MuvelError::internal(Message::LoginServerStartFailed).caused_by(e))?;
 
     for request in server.incoming_requests() {
         if request.url().starts_with("/callback") {
@@ -31,7 +34,7 @@ pub fn wait_for_token() -> Result<String, String> {
                 .query_pairs()
                 .find(|(k, _)| k == "token")
                 .map(|(_, v)| v.to_string())
-                .ok_or("no token")?;
+                .ok_or_else(|| MuvelError::invalid_input(Message::LoginTokenMissing))?;
 
             let _ = request.respond(
                 Response::from_string(html)
@@ -41,5 +44,5 @@ pub fn wait_for_token() -> Result<String, String> {
         }
     }
 
-    Err("콜백 수신 실패".into())
+    Err(MuvelError::internal(Message::LoginCallbackFailed))
 }
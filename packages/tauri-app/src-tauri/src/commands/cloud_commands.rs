@@ -1,5 +1,6 @@
 use crate::models::enums::share_type::ShareType;
 use crate::models::episode::{LocalEpisodeData, LocalEpisodeDataResponse};
+use crate::models::error::MuvelResult;
 use crate::models::novel::Novel;
 use crate::storage::{episode_io, novel_io};
 use chrono::Utc;
@@ -9,7 +10,7 @@ use tauri::{command, AppHandle};
 pub fn backup_cloud_episode_command(
     app_handle: AppHandle,
     data: LocalEpisodeDataResponse, // 프론트에서 LocalEpisodeDataResponse 형식으로 데이터를 전달
-) -> Result<(), String> {
+) -> MuvelResult<()> {
     let novel_id_from_context = data.novel.id.clone();
     let episode_id_from_data = data.id.clone();
 
@@ -59,6 +60,7 @@ pub fn backup_cloud_episode_command(
         created_at: data.created_at,
         updated_at: data.updated_at, // 에피소드 자체의 최종 수정 시간
         blocks: data.blocks,
+        revision: data.revision,
     };
 
     // 5. Episode 데이터 저장 (episodes/EPISODE_ID.mvle)
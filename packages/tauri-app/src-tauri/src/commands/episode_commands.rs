@@ -1,7 +1,9 @@
 use crate::models::block::DeltaBlock;
 use crate::models::episode::{
-    CreateLocalEpisodeOptions, LocalEpisodeDataResponse, UpdateLocalEpisodeMetadata,
+    CreateLocalEpisodeOptions, EditHistoryResult, EditHistoryState, LocalEpisodeDataResponse,
+    SyncResult, UpdateLocalEpisodeMetadata,
 };
+use crate::models::error::MuvelResult;
 use crate::models::novel::EpisodeSummaryData;
 use crate::repositories::episode_repository::EpisodeRepository;
 use tauri::{command, AppHandle};
@@ -11,7 +13,7 @@ pub fn create_local_episode_command(
     app_handle: AppHandle,
     novel_id: String,
     options: CreateLocalEpisodeOptions,
-) -> Result<LocalEpisodeDataResponse, String> {
+) -> MuvelResult<LocalEpisodeDataResponse> {
     let repo = EpisodeRepository::new(&app_handle);
     repo.create_episode(&novel_id, options)
 }
@@ -20,7 +22,7 @@ pub fn create_local_episode_command(
 pub fn get_local_episode_data_command(
     app_handle: AppHandle,
     episode_id: String,
-) -> Result<LocalEpisodeDataResponse, String> {
+) -> MuvelResult<LocalEpisodeDataResponse> {
     let repo = EpisodeRepository::new(&app_handle);
     repo.get_episode_data(&episode_id)
 }
@@ -30,17 +32,14 @@ pub fn update_local_episode_metadata_command(
     app_handle: AppHandle,
     episode_id: String,
     metadata: UpdateLocalEpisodeMetadata,
-) -> Result<EpisodeSummaryData, String> {
+) -> MuvelResult<EpisodeSummaryData> {
     // 반환 타입 변경 가능성 (리포지토리 반환 타입에 맞춤)
     let repo = EpisodeRepository::new(&app_handle);
     repo.update_episode_metadata(&episode_id, metadata)
 }
 
 #[command]
-pub fn delete_local_episode_command(
-    app_handle: AppHandle,
-    episode_id: String,
-) -> Result<(), String> {
+pub fn delete_local_episode_command(app_handle: AppHandle, episode_id: String) -> MuvelResult<()> {
     let repo = EpisodeRepository::new(&app_handle);
     repo.delete_episode(&episode_id)
 }
@@ -49,7 +48,7 @@ pub fn delete_local_episode_command(
 pub fn list_local_episode_summaries_command(
     app_handle: AppHandle,
     novel_id: String,
-) -> Result<Vec<EpisodeSummaryData>, String> {
+) -> MuvelResult<Vec<EpisodeSummaryData>> {
     let repo = EpisodeRepository::new(&app_handle);
     repo.list_episode_summaries_for_novel(&novel_id)
 }
@@ -59,7 +58,44 @@ pub fn sync_local_delta_blocks_command(
     app_handle: AppHandle,
     episode_id: String,
     delta_blocks: Vec<DeltaBlock>,
-) -> Result<(), String> {
+    base_revision: Option<u64>,
+) -> MuvelResult<SyncResult> {
+    let repo = EpisodeRepository::new(&app_handle);
+    repo.sync_delta_blocks(&episode_id, delta_blocks, base_revision)
+}
+
+#[command]
+pub fn compact_local_episode_journal_command(
+    app_handle: AppHandle,
+    episode_id: String,
+) -> MuvelResult<()> {
+    let repo = EpisodeRepository::new(&app_handle);
+    repo.compact_episode_journal(&episode_id)
+}
+
+#[command]
+pub fn get_local_episode_edit_history_command(
+    app_handle: AppHandle,
+    episode_id: String,
+) -> MuvelResult<EditHistoryState> {
+    let repo = EpisodeRepository::new(&app_handle);
+    repo.get_edit_history_state(&episode_id)
+}
+
+#[command]
+pub fn undo_local_episode_edit_command(
+    app_handle: AppHandle,
+    episode_id: String,
+) -> MuvelResult<EditHistoryResult> {
+    let repo = EpisodeRepository::new(&app_handle);
+    repo.undo_edit(&episode_id)
+}
+
+#[command]
+pub fn redo_local_episode_edit_command(
+    app_handle: AppHandle,
+    episode_id: String,
+) -> MuvelResult<EditHistoryResult> {
     let repo = EpisodeRepository::new(&app_handle);
-    repo.sync_delta_blocks(&episode_id, delta_blocks)
+    repo.redo_edit(&episode_id)
 }
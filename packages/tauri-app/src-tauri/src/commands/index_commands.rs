@@ -1,3 +1,4 @@
+use crate::models::error::MuvelResult;
 use crate::models::index::LocalNovelIndexEntry;
 use crate::repositories::novel_repository::NovelRepository;
 use tauri::{command, AppHandle};
@@ -5,7 +6,7 @@ use tauri::{command, AppHandle};
 #[command]
 pub fn get_all_local_novel_entries_command(
     app_handle: AppHandle,
-) -> Result<Vec<LocalNovelIndexEntry>, String> {
+) -> MuvelResult<Vec<LocalNovelIndexEntry>> {
     let repo = NovelRepository::new(&app_handle);
     repo.list_all_novel_entries()
 }
@@ -14,7 +15,7 @@ pub fn get_all_local_novel_entries_command(
 pub fn get_local_novel_entry_command(
     app_handle: AppHandle,
     novel_id: String,
-) -> Result<Option<LocalNovelIndexEntry>, String> {
+) -> MuvelResult<Option<LocalNovelIndexEntry>> {
     let repo = NovelRepository::new(&app_handle);
     repo.get_novel_entry(&novel_id)
 }
@@ -23,7 +24,7 @@ pub fn get_local_novel_entry_command(
 pub fn register_novel_from_path_command(
     app_handle: AppHandle,
     file_path: String,
-) -> Result<Option<String>, String> {
+) -> MuvelResult<Option<String>> {
     let repo = NovelRepository::new(&app_handle);
     repo.register_novel_from_path(&file_path)
 }
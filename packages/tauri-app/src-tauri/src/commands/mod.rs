@@ -14,3 +14,8 @@ reexport_module!(wiki_page_commands);
 reexport_module!(cloud_commands);
 reexport_module!(snapshot_command);
 reexport_module!(search_commands);
+reexport_module!(settings_commands);
+reexport_module!(recovery_commands);
+reexport_module!(sync_conflict_commands);
+reexport_module!(resource_commands);
+reexport_module!(attachment_commands);
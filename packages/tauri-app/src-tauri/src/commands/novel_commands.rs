@@ -1,9 +1,12 @@
+use crate::i18n::Message;
 use crate::models::episode::EpisodeMetadataUpdatePayload;
+use crate::models::error::{MuvelError, MuvelResult};
 use crate::models::novel::{
     CreateLocalNovelOptions, EpisodeSummaryData, Novel, NovelFullDetails, UpdateLocalNovelData,
 };
 use crate::repositories::episode_repository::EpisodeRepository;
 use crate::repositories::novel_repository::NovelRepository;
+use std::path::Path;
 use std::process::Command;
 use tauri::{command, AppHandle};
 
@@ -11,7 +14,7 @@ use tauri::{command, AppHandle};
 pub fn create_local_novel_command(
     app_handle: AppHandle,
     options: CreateLocalNovelOptions,
-) -> Result<Novel, String> {
+) -> MuvelResult<Novel> {
     let repo = NovelRepository::new(&app_handle);
     repo.create_novel(options)
 }
@@ -20,7 +23,7 @@ pub fn create_local_novel_command(
 pub fn get_local_novel_details_command(
     app_handle: AppHandle,
     novel_id: String,
-) -> Result<NovelFullDetails, String> {
+) -> MuvelResult<NovelFullDetails> {
     let repo = NovelRepository::new(&app_handle);
     repo.get_novel_details(&novel_id)
 }
@@ -30,13 +33,13 @@ pub fn update_local_novel_metadata_command(
     app_handle: AppHandle,
     novel_id: String,
     data: UpdateLocalNovelData,
-) -> Result<NovelFullDetails, String> {
+) -> MuvelResult<NovelFullDetails> {
     let repo = NovelRepository::new(&app_handle);
     repo.update_novel_metadata(&novel_id, data)
 }
 
 #[command]
-pub fn remove_novel_project_command(app_handle: AppHandle, novel_id: String) -> Result<(), String> {
+pub fn remove_novel_project_command(app_handle: AppHandle, novel_id: String) -> MuvelResult<()> {
     let repo = NovelRepository::new(&app_handle);
     repo.delete_novel_project(&novel_id)
 }
@@ -45,20 +48,17 @@ pub fn remove_novel_project_command(app_handle: AppHandle, novel_id: String) ->
 pub fn open_novel_project_folder_command(
     app_handle: AppHandle,
     novel_id: String,
-) -> Result<(), String> {
+) -> MuvelResult<()> {
     let repo = NovelRepository::new(&app_handle);
     let novel_root_path = repo.get_novel_root_path(&novel_id)?;
 
     if !novel_root_path.exists() || !novel_root_path.is_dir() {
-        return Err(format!(
-            "소설 프로젝트 경로를 찾을 수 없거나 디렉토리가 아닙니다: '{}'",
-            novel_root_path.display()
-        ));
+        return Err(MuvelError::not_found(Message::NovelFolderMissing).with_path(&novel_root_path));
     }
 
-    let path_to_open = novel_root_path
-        .to_str()
-        .ok_or_else(|| format!("경로를 문자열로 변환할 수 없습니다: {:?}", novel_root_path))?;
+    let path_to_open = novel_root_path.to_str().ok_or_else(|| {
+        MuvelError::internal(Message::PathToStringFailed).with_path(&novel_root_path)
+    })?;
 
     let open_result = if cfg!(target_os = "windows") {
         Command::new("explorer").arg(path_to_open).spawn()
@@ -67,16 +67,14 @@ pub fn open_novel_project_folder_command(
     } else if cfg!(target_os = "linux") {
         Command::new("xdg-open").arg(path_to_open).spawn()
     } else {
-        return Err("지원되지 않는 운영체제입니다.".to_string());
+        return Err(MuvelError::invalid_input(Message::UnsupportedOs));
     };
 
     match open_result {
         Ok(_) => Ok(()),
-        Err(e) => Err(format!(
-            "파일 탐색기에서 폴더를 여는 데 실패했습니다 ('{}'): {}",
-            novel_root_path.display(),
-            e
-        )),
+        Err(e) => Err(MuvelError::invalid_input(Message::OpenFolderFailed)
+            .caused_by(e)
+            .with_path(&novel_root_path)),
     }
 }
 
@@ -85,7 +83,7 @@ pub fn update_local_novel_episodes_metadata_command(
     app_handle: AppHandle,
     _novel_id: String,
     episode_diffs: Vec<EpisodeMetadataUpdatePayload>,
-) -> Result<Vec<EpisodeSummaryData>, String> {
+) -> MuvelResult<Vec<EpisodeSummaryData>> {
     let episode_repo = EpisodeRepository::new(&app_handle);
     episode_repo.batch_update_episode_metadata(episode_diffs)
 }
@@ -98,7 +96,27 @@ pub fn save_novel_image_command(
     novel_id: String,
     original_file_name: String,
     file_bytes: Vec<u8>, // Tauri는 Vec<u8>를 통해 바이너리 데이터를 잘 처리합니다.
-) -> Result<String, String> {
+) -> MuvelResult<String> {
     let repo = NovelRepository::new(&app_handle);
     repo.save_image_to_novel(&novel_id, &original_file_name, file_bytes)
 }
+
+#[command]
+pub fn export_novel_archive_command(
+    app_handle: AppHandle,
+    novel_id: String,
+    archive_path: String,
+) -> MuvelResult<()> {
+    let repo = NovelRepository::new(&app_handle);
+    repo.export_novel_archive(&novel_id, Path::new(&archive_path))
+}
+
+#[command]
+pub fn import_novel_archive_command(
+    app_handle: AppHandle,
+    archive_path: String,
+    target_directory_path: Option<String>,
+) -> MuvelResult<NovelFullDetails> {
+    let repo = NovelRepository::new(&app_handle);
+    repo.import_novel_archive(Path::new(&archive_path), target_directory_path.as_deref())
+}
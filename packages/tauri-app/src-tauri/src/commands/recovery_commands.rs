@@ -0,0 +1,26 @@
+use crate::models::commons::{LeftoverTempFiles, PendingRecovery};
+use crate::models::error::MuvelResult;
+use crate::storage::temp_file_recovery;
+use std::path::PathBuf;
+use std::sync::PoisonError;
+use tauri::{command, AppHandle, State};
+
+/// 시작 시 발견된 남은 임시 파일 목록을 가져옵니다. (한 번만 반환)
+/// 검사는 백그라운드에서 진행되며, 끝나면 `leftover-temp-files-found` 이벤트가 발생합니다.
+#[command]
+pub fn take_recoverable_temp_files_command(
+    state: State<PendingRecovery>,
+) -> MuvelResult<LeftoverTempFiles> {
+    let mut pending = state.0.lock().unwrap_or_else(PoisonError::into_inner);
+    Ok(std::mem::take(&mut *pending))
+}
+
+#[command]
+pub fn recover_temp_file_command(app_handle: AppHandle, temp_path: String) -> MuvelResult<()> {
+    temp_file_recovery::recover_temp_file(&app_handle, &PathBuf::from(temp_path))
+}
+
+#[command]
+pub fn discard_temp_file_command(app_handle: AppHandle, temp_path: String) -> MuvelResult<()> {
+    temp_file_recovery::discard_temp_file(&app_handle, &PathBuf::from(temp_path))
+}
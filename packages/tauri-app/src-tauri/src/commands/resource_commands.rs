@@ -0,0 +1,15 @@
+use crate::models::error::MuvelResult;
+use crate::models::resource::ResourceCleanupResult;
+use crate::repositories::resource_repository::ResourceRepository;
+use tauri::{command, AppHandle};
+
+/// 어디에서도 참조하지 않는 이미지를 정리합니다. `dry_run`이면 삭제할 파일 목록만 반환합니다.
+#[command]
+pub fn clean_unused_resources_command(
+    app_handle: AppHandle,
+    novel_id: String,
+    dry_run: Option<bool>,
+) -> MuvelResult<ResourceCleanupResult> {
+    let repo = ResourceRepository::new(&app_handle);
+    repo.clean_unused_resources(&novel_id, dry_run.unwrap_or(false))
+}
@@ -1,3 +1,4 @@
+use crate::models::error::MuvelResult;
 use crate::models::search_results::SearchInNovelResponse;
 use crate::repositories::search_repository::SearchRepository;
 use tauri::{command, AppHandle};
@@ -12,7 +13,7 @@ pub fn search_in_novel_command(
     query: String,
     limit: Option<usize>,
     offset: Option<usize>,
-) -> Result<SearchInNovelResponse, String> {
+) -> MuvelResult<SearchInNovelResponse> {
     if query.trim().is_empty() {
         return Ok(SearchInNovelResponse {
             hits: Vec::new(),
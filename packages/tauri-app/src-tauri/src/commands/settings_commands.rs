@@ -0,0 +1,65 @@
+use crate::i18n;
+use crate::models::enums::app_locale::AppLocale;
+use crate::models::error::MuvelResult;
+use crate::models::settings::{ImageResourceSettings, SnapshotRetentionPolicy};
+use crate::storage::settings_manager;
+use tauri::{command, AppHandle};
+
+#[command]
+pub fn get_snapshot_retention_policy_command(
+    app_handle: AppHandle,
+) -> MuvelResult<SnapshotRetentionPolicy> {
+    let settings = settings_manager::load_settings(&app_handle)?;
+    Ok(settings.snapshot_retention)
+}
+
+/// 스냅샷 보관 정책을 변경합니다. 변경된 정책은 다음 스냅샷 생성 시부터 적용됩니다.
+#[command]
+pub fn update_snapshot_retention_policy_command(
+    app_handle: AppHandle,
+    policy: SnapshotRetentionPolicy,
+) -> MuvelResult<SnapshotRetentionPolicy> {
+    let mut settings = settings_manager::load_settings(&app_handle)?;
+    settings.snapshot_retention = policy;
+    settings_manager::save_settings(&app_handle, &settings)?;
+    Ok(settings.snapshot_retention)
+}
+
+#[command]
+pub fn get_app_locale_command(app_handle: AppHandle) -> MuvelResult<AppLocale> {
+    let settings = settings_manager::load_settings(&app_handle)?;
+    Ok(settings.locale)
+}
+
+/// 백엔드 메시지 언어를 변경합니다. 저장 후 곧바로 이후의 오류 메시지와 기본 이름에 적용됩니다.
+#[command]
+pub fn update_app_locale_command(
+    app_handle: AppHandle,
+    locale: AppLocale,
+) -> MuvelResult<AppLocale> {
+    let mut settings = settings_manager::load_settings(&app_handle)?;
+    settings.locale = locale;
+    settings_manager::save_settings(&app_handle, &settings)?;
+    i18n::set_locale(settings.locale);
+    Ok(settings.locale)
+}
+
+#[command]
+pub fn get_image_resource_settings_command(
+    app_handle: AppHandle,
+) -> MuvelResult<ImageResourceSettings> {
+    let settings = settings_manager::load_settings(&app_handle)?;
+    Ok(settings.image_resources)
+}
+
+/// 이미지 저장 설정을 변경합니다. 이미 저장된 이미지에는 적용되지 않습니다.
+#[command]
+pub fn update_image_resource_settings_command(
+    app_handle: AppHandle,
+    image_resources: ImageResourceSettings,
+) -> MuvelResult<ImageResourceSettings> {
+    let mut settings = settings_manager::load_settings(&app_handle)?;
+    settings.image_resources = image_resources;
+    settings_manager::save_settings(&app_handle, &settings)?;
+    Ok(settings.image_resources)
+}
@@ -1,5 +1,15 @@
+use crate::models::block_diff::BlockDiff;
 use crate::models::enums::snapshot_reason::SnapshotReason;
-use crate::models::snapshot::EpisodeSnapshot;
+use crate::models::episode::LocalEpisodeDataResponse;
+use crate::models::error::MuvelResult;
+use crate::models::novel::NovelFullDetails;
+use crate::models::snapshot::{
+    EpisodeSnapshot, NovelSnapshot, NovelSnapshotSummary, RestoreNovelSnapshotOptions,
+    WikiPageSnapshot,
+};
+use crate::models::wiki_page::WikiPage;
+use crate::repositories::episode_repository::EpisodeRepository;
+use crate::repositories::novel_repository::NovelRepository;
 use crate::repositories::snapshot_repository::SnapshotRepository;
 use tauri::{command, AppHandle};
 
@@ -8,7 +18,7 @@ pub fn create_episode_snapshot_command(
     app_handle: AppHandle,
     episode_id: String,
     reason: SnapshotReason,
-) -> Result<EpisodeSnapshot, String> {
+) -> MuvelResult<EpisodeSnapshot> {
     let repo = SnapshotRepository::new(&app_handle);
     repo.create_snapshot(&episode_id, reason)
 }
@@ -17,7 +27,184 @@ pub fn create_episode_snapshot_command(
 pub fn get_episode_snapshots_command(
     app_handle: AppHandle,
     episode_id: String,
-) -> Result<Vec<EpisodeSnapshot>, String> {
+) -> MuvelResult<Vec<EpisodeSnapshot>> {
     let repo = SnapshotRepository::new(&app_handle);
     repo.get_all_snapshots(&episode_id)
 }
+
+/// 에피소드를 특정 스냅샷 시점으로 되돌리고, 복원된 에피소드 데이터를 반환합니다.
+#[command]
+pub fn restore_episode_snapshot_command(
+    app_handle: AppHandle,
+    episode_id: String,
+    snapshot_id: String,
+) -> MuvelResult<LocalEpisodeDataResponse> {
+    let repo = SnapshotRepository::new(&app_handle);
+    repo.restore_snapshot(&episode_id, &snapshot_id)?;
+    let episode_repo = EpisodeRepository::new(&app_handle);
+    episode_repo.get_episode_data(&episode_id)
+}
+
+/// 스냅샷의 이름/메모를 바꾸거나 고정 여부를 변경합니다. 전달하지 않은 항목은 유지됩니다.
+#[command]
+pub fn update_episode_snapshot_command(
+    app_handle: AppHandle,
+    episode_id: String,
+    snapshot_id: String,
+    label: Option<String>,
+    note: Option<String>,
+    pinned: Option<bool>,
+) -> MuvelResult<EpisodeSnapshot> {
+    let repo = SnapshotRepository::new(&app_handle);
+    repo.update_snapshot_metadata(&episode_id, &snapshot_id, label, note, pinned)
+}
+
+#[command]
+pub fn delete_episode_snapshot_command(
+    app_handle: AppHandle,
+    episode_id: String,
+    snapshot_id: String,
+) -> MuvelResult<()> {
+    let repo = SnapshotRepository::new(&app_handle);
+    repo.delete_snapshot(&episode_id, &snapshot_id)
+}
+
+/// 두 스냅샷, 또는 스냅샷과 현재 에피소드(`target_snapshot_id`가 없을 때)의 블록 차이를 반환합니다.
+#[command]
+pub fn diff_episode_snapshot_command(
+    app_handle: AppHandle,
+    episode_id: String,
+    base_snapshot_id: String,
+    target_snapshot_id: Option<String>,
+) -> MuvelResult<BlockDiff> {
+    let repo = SnapshotRepository::new(&app_handle);
+    repo.diff_snapshot(
+        &episode_id,
+        &base_snapshot_id,
+        target_snapshot_id.as_deref(),
+    )
+}
+
+/// 소설 전체(.muvl 메타데이터, 모든 에피소드와 위키 페이지)의 스냅샷을 생성합니다.
+#[command]
+pub fn create_novel_snapshot_command(
+    app_handle: AppHandle,
+    novel_id: String,
+    label: Option<String>,
+    note: Option<String>,
+) -> MuvelResult<NovelSnapshotSummary> {
+    let repo = SnapshotRepository::new(&app_handle);
+    repo.create_novel_snapshot(&novel_id, SnapshotReason::Manual, label, note)
+}
+
+#[command]
+pub fn get_novel_snapshots_command(
+    app_handle: AppHandle,
+    novel_id: String,
+) -> MuvelResult<Vec<NovelSnapshotSummary>> {
+    let repo = SnapshotRepository::new(&app_handle);
+    repo.get_all_novel_snapshots(&novel_id)
+}
+
+/// 미리보기용으로 소설 스냅샷 전체 내용을 반환합니다.
+#[command]
+pub fn get_novel_snapshot_command(
+    app_handle: AppHandle,
+    novel_id: String,
+    snapshot_id: String,
+) -> MuvelResult<NovelSnapshot> {
+    let repo = SnapshotRepository::new(&app_handle);
+    repo.get_novel_snapshot(&novel_id, &snapshot_id)
+}
+
+/// 소설 스냅샷을 복원하고 복원된 소설 정보를 반환합니다.
+/// `options`가 없으면 소설 전체를, 있으면 지정한 항목만 복원합니다.
+#[command]
+pub fn restore_novel_snapshot_command(
+    app_handle: AppHandle,
+    novel_id: String,
+    snapshot_id: String,
+    options: Option<RestoreNovelSnapshotOptions>,
+) -> MuvelResult<NovelFullDetails> {
+    let repo = SnapshotRepository::new(&app_handle);
+    repo.restore_novel_snapshot(&novel_id, &snapshot_id, options)?;
+    let novel_repo = NovelRepository::new(&app_handle);
+    novel_repo.get_novel_details(&novel_id)
+}
+
+/// 소설 스냅샷의 이름/메모를 바꾸거나 고정 여부를 변경합니다. 고정된 스냅샷은 보관 정책으로 삭제되지 않습니다.
+#[command]
+pub fn update_novel_snapshot_command(
+    app_handle: AppHandle,
+    novel_id: String,
+    snapshot_id: String,
+    label: Option<String>,
+    note: Option<String>,
+    pinned: Option<bool>,
+) -> MuvelResult<NovelSnapshotSummary> {
+    let repo = SnapshotRepository::new(&app_handle);
+    repo.update_novel_snapshot_metadata(&novel_id, &snapshot_id, label, note, pinned)
+}
+
+#[command]
+pub fn delete_novel_snapshot_command(
+    app_handle: AppHandle,
+    novel_id: String,
+    snapshot_id: String,
+) -> MuvelResult<()> {
+    let repo = SnapshotRepository::new(&app_handle);
+    repo.delete_novel_snapshot(&novel_id, &snapshot_id)
+}
+
+#[command]
+pub fn create_wiki_page_snapshot_command(
+    app_handle: AppHandle,
+    page_id: String,
+    reason: SnapshotReason,
+) -> MuvelResult<WikiPageSnapshot> {
+    let repo = SnapshotRepository::new(&app_handle);
+    repo.create_wiki_page_snapshot(&page_id, reason)
+}
+
+#[command]
+pub fn get_wiki_page_snapshots_command(
+    app_handle: AppHandle,
+    page_id: String,
+) -> MuvelResult<Vec<WikiPageSnapshot>> {
+    let repo = SnapshotRepository::new(&app_handle);
+    repo.get_all_wiki_page_snapshots(&page_id)
+}
+
+/// 위키 페이지를 특정 스냅샷 시점(메타데이터와 블록)으로 되돌리고, 복원된 페이지를 반환합니다.
+#[command]
+pub fn restore_wiki_page_snapshot_command(
+    app_handle: AppHandle,
+    page_id: String,
+    snapshot_id: String,
+) -> MuvelResult<WikiPage> {
+    let repo = SnapshotRepository::new(&app_handle);
+    repo.restore_wiki_page_snapshot(&page_id, &snapshot_id)
+}
+
+#[command]
+pub fn update_wiki_page_snapshot_command(
+    app_handle: AppHandle,
+    page_id: String,
+    snapshot_id: String,
+    label: Option<String>,
+    note: Option<String>,
+    pinned: Option<bool>,
+) -> MuvelResult<WikiPageSnapshot> {
+    let repo = SnapshotRepository::new(&app_handle);
+    repo.update_wiki_page_snapshot_metadata(&page_id, &snapshot_id, label, note, pinned)
+}
+
+#[command]
+pub fn delete_wiki_page_snapshot_command(
+    app_handle: AppHandle,
+    page_id: String,
+    snapshot_id: String,
+) -> MuvelResult<()> {
+    let repo = SnapshotRepository::new(&app_handle);
+    repo.delete_wiki_page_snapshot(&page_id, &snapshot_id)
+}
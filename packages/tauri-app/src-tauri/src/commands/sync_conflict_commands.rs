@@ -0,0 +1,34 @@
+use crate::models::commons::{SyncConflictCopy, SyncConflictMergeResult};
+use crate::models::error::MuvelResult;
+use crate::repositories::sync_conflict_repository::SyncConflictRepository;
+use std::path::PathBuf;
+use tauri::{command, AppHandle};
+
+/// 소설 폴더의 동기화 충돌 사본 목록을 가져옵니다.
+#[command]
+pub fn list_sync_conflicts_command(
+    app_handle: AppHandle,
+    novel_id: String,
+) -> MuvelResult<Vec<SyncConflictCopy>> {
+    SyncConflictRepository::new(&app_handle).list_conflicts(&novel_id)
+}
+
+#[command]
+pub fn merge_sync_conflict_command(
+    app_handle: AppHandle,
+    novel_id: String,
+    conflict_path: String,
+) -> MuvelResult<SyncConflictMergeResult> {
+    SyncConflictRepository::new(&app_handle)
+        .merge_conflict(&novel_id, &PathBuf::from(conflict_path))
+}
+
+#[command]
+pub fn discard_sync_conflict_command(
+    app_handle: AppHandle,
+    novel_id: String,
+    conflict_path: String,
+) -> MuvelResult<()> {
+    SyncConflictRepository::new(&app_handle)
+        .discard_conflict(&novel_id, &PathBuf::from(conflict_path))
+}
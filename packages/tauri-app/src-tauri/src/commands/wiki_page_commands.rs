@@ -1,5 +1,9 @@
+use crate::models::block::DeltaBlock;
+use crate::models::error::MuvelResult;
 use crate::models::novel::WikiPageSummaryData;
-use crate::models::wiki_page::{CreateWikiPageOptions, UpdateWikiPageData, WikiPage};
+use crate::models::wiki_page::{
+    CreateWikiPageOptions, UpdateWikiPageData, WikiPage, WikiPageSyncResult,
+};
 use crate::repositories::wiki_page_repository::WikiPageRepository;
 use tauri::{command, AppHandle};
 
@@ -8,13 +12,13 @@ pub fn create_wiki_page_command(
     app_handle: AppHandle,
     novel_id: String,
     options: CreateWikiPageOptions,
-) -> Result<WikiPage, String> {
+) -> MuvelResult<WikiPage> {
     let repo = WikiPageRepository::new(&app_handle);
     repo.create_wiki_page(&novel_id, options)
 }
 
 #[command]
-pub fn get_wiki_page_command(app_handle: AppHandle, page_id: String) -> Result<WikiPage, String> {
+pub fn get_wiki_page_command(app_handle: AppHandle, page_id: String) -> MuvelResult<WikiPage> {
     let repo = WikiPageRepository::new(&app_handle);
     repo.get_wiki_page(&page_id)
 }
@@ -24,13 +28,13 @@ pub fn update_wiki_page_command(
     app_handle: AppHandle,
     page_id: String,
     data: UpdateWikiPageData,
-) -> Result<WikiPage, String> {
+) -> MuvelResult<WikiPage> {
     let repo = WikiPageRepository::new(&app_handle);
     repo.update_wiki_page(&page_id, data)
 }
 
 #[command]
-pub fn delete_wiki_page_command(app_handle: AppHandle, page_id: String) -> Result<(), String> {
+pub fn delete_wiki_page_command(app_handle: AppHandle, page_id: String) -> MuvelResult<()> {
     let repo = WikiPageRepository::new(&app_handle);
     repo.delete_wiki_page(&page_id)
 }
@@ -39,7 +43,19 @@ pub fn delete_wiki_page_command(app_handle: AppHandle, page_id: String) -> Resul
 pub fn list_wiki_page_summaries_command(
     app_handle: AppHandle,
     novel_id: String,
-) -> Result<Vec<WikiPageSummaryData>, String> {
+) -> MuvelResult<Vec<WikiPageSummaryData>> {
     let repo = WikiPageRepository::new(&app_handle);
     repo.list_wiki_page_summaries_for_novel(&novel_id)
 }
+
+/// 위키 페이지 본문 블록의 변경분을 동기화합니다.
+/// 클라이언트의 CMD_SYNC_LOCAL_WIKI_PAGE_BLOCKS_CMD 이름에 맞춰 `_command` 접미사 없이 등록합니다.
+#[command]
+pub fn sync_local_wiki_page_blocks(
+    app_handle: AppHandle,
+    wiki_page_id: String,
+    delta_blocks: Vec<DeltaBlock>,
+) -> MuvelResult<WikiPageSyncResult> {
+    let repo = WikiPageRepository::new(&app_handle);
+    repo.sync_delta_blocks(&wiki_page_id, delta_blocks)
+}
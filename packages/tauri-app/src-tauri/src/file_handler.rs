@@ -1,4 +1,6 @@
+use crate::i18n::{FileKind, Message};
 use crate::models::commons::{OpenedItem, PendingOpen};
+use crate::models::error::{MuvelError, MuvelResult};
 use crate::models::index::LocalNovelIndexEntry;
 use crate::models::novel::Novel;
 use crate::storage::{episode_io, index_manager, item_index_manager, novel_io};
@@ -11,11 +13,11 @@ pub fn handle_opened_file(
     app_handle: &AppHandle,
     pending: &State<PendingOpen>,
     file_path: &Path,
-) -> Result<(), String> {
+) -> MuvelResult<()> {
     println!("파일 열기 시도: {:?}", file_path);
 
     if !file_path.exists() {
-        return Err(format!("파일이 존재하지 않습니다: {:?}", file_path));
+        return Err(MuvelError::not_found(Message::PathNotFound).with_path(file_path));
     }
 
     let extension = file_path.extension().and_then(OsStr::to_str);
@@ -23,17 +25,14 @@ pub fn handle_opened_file(
     match extension {
         Some("muvl") => {
             let novel_root_path = file_path.parent().ok_or_else(|| {
-                format!(
-                    "소설 파일의 부모 디렉토리를 가져올 수 없습니다: {:?}",
-                    file_path
-                )
+                MuvelError::invalid_input(Message::ParentDirNotFound(FileKind::NovelMetadata))
+                    .with_path(file_path)
             })?;
 
             // novel_io::read_novel_metadata_with_path 사용
             let (novel_data, _muvl_file_path) =
-                novel_io::read_novel_metadata_with_path(novel_root_path).map_err(|e| {
-                    format!("소설 메타데이터 읽기 실패 ({:?}): {}", novel_root_path, e)
-                })?;
+                novel_io::read_novel_metadata_with_path(novel_root_path)
+                    .map_err(|e| e.context(Message::FileReadFailed(FileKind::NovelMetadata)))?;
 
             let novel_id = novel_data.id.clone();
             let novel_title = novel_data.title.clone();
@@ -48,8 +47,13 @@ pub fn handle_opened_file(
                 last_opened: Some(current_time_iso),
             };
 
-            index_manager::upsert_novel_entry(app_handle, novel_id.clone(), entry)
-                .map_err(|e| format!("소설 {}의 인덱스 등록/업데이트 실패: {}", novel_id, e))?;
+            index_manager::upsert_novel_entry(app_handle, novel_id.clone(), entry).map_err(
+                |e| {
+                    e.context(Message::NovelIndexUpdateFailed {
+                        novel_id: novel_id.clone(),
+                    })
+                },
+            )?;
 
             println!(
                 "소설이 인덱스에 등록/업데이트됨: ID = {}, 경로 = {:?}",
@@ -63,41 +67,35 @@ pub fn handle_opened_file(
         }
         Some("mvle") => {
             let episode_id_osstr = file_path.file_stem().ok_or_else(|| {
-                format!(
-                    "파일 이름(에피소드 ID)을 가져올 수 없습니다: {}",
-                    file_path.display()
-                )
+                MuvelError::invalid_input(Message::FileNameUnreadable(FileKind::Episode))
+                    .with_path(file_path)
             })?;
             let episode_id = episode_id_osstr.to_string_lossy().into_owned();
             let episodes_dir = file_path.parent().ok_or_else(|| {
-                format!(
-                    "부모 디렉토리(episodes 폴더)를 가져올 수 없습니다: {}",
-                    file_path.display()
-                )
+                MuvelError::invalid_input(Message::NotInStandardDir(FileKind::Episode))
+                    .with_path(file_path)
             })?;
 
             if episodes_dir.file_name().and_then(OsStr::to_str)
                 != Some(episode_io::EPISODES_DIRNAME)
             {
-                return Err(format!(
-                    "에피소드 파일이 표준 'episodes' 디렉토리에 없습니다: {:?}",
-                    episodes_dir
-                ));
+                return Err(MuvelError::invalid_input(Message::NotInStandardDir(
+                    FileKind::Episode,
+                ))
+                .with_path(episodes_dir));
             }
             let novel_root_path = episodes_dir.parent().ok_or_else(|| {
-                format!(
-                    "episodes 디렉토리에서 소설 루트 디렉토리를 가져올 수 없습니다: {:?}",
-                    episodes_dir
-                )
+                MuvelError::invalid_input(Message::NotInStandardDir(FileKind::Episode))
+                    .with_path(episodes_dir)
             })?;
 
             // novel_io::read_novel_metadata_with_path 사용
             let (parent_novel_data, _) = novel_io::read_novel_metadata_with_path(novel_root_path)
                 .map_err(|e| {
-                format!(
-                    "에피소드 {}의 부모 소설 메타데이터 읽기 실패: {}",
-                    episode_id, e
-                )
+                e.context(Message::ParentNovelMetadataReadFailed {
+                    kind: FileKind::Episode,
+                    item_id: episode_id.to_string(),
+                })
             })?;
             let parent_novel_id = parent_novel_data.id.clone();
 
@@ -108,10 +106,11 @@ pub fn handle_opened_file(
                 "episode".to_string(),
             )
             .map_err(|e| {
-                format!(
-                    "에피소드 {}와 소설 {} 매핑 실패: {}",
-                    episode_id, parent_novel_id, e
-                )
+                e.context(Message::ItemMappingFailed {
+                    kind: FileKind::Episode,
+                    item_id: episode_id.to_string(),
+                    novel_id: parent_novel_id.clone(),
+                })
             })?;
             println!(
                 "에피소드 {}가 아이템 인덱스에서 소설 {}에 매핑됨 (타입: episode).",
@@ -149,42 +148,35 @@ pub fn handle_opened_file(
         }
         Some("mkwp") => {
             let page_id_osstr = file_path.file_stem().ok_or_else(|| {
-                format!(
-                    "파일 이름(위키 페이지 ID)을 가져올 수 없습니다: {}",
-                    file_path.display()
-                )
+                MuvelError::invalid_input(Message::FileNameUnreadable(FileKind::WikiPage))
+                    .with_path(file_path)
             })?;
             let page_id = page_id_osstr.to_string_lossy().into_owned();
             let wiki_pages_dir = file_path.parent().ok_or_else(|| {
-                format!(
-                    "부모 디렉토리(wiki 폴더)를 가져올 수 없습니다: {}",
-                    file_path.display()
-                )
+                MuvelError::invalid_input(Message::NotInStandardDir(FileKind::WikiPage))
+                    .with_path(file_path)
             })?;
 
             if wiki_pages_dir.file_name().and_then(OsStr::to_str)
                 != Some(crate::storage::wiki_page_io::WIKI_PAGES_DIRNAME)
             {
-                return Err(format!(
-                    "위키 페이지 파일이 표준 '{}' 디렉토리에 없습니다: {:?}",
-                    crate::storage::wiki_page_io::WIKI_PAGES_DIRNAME,
-                    wiki_pages_dir
-                ));
+                return Err(MuvelError::invalid_input(Message::NotInStandardDir(
+                    FileKind::WikiPage,
+                ))
+                .with_path(wiki_pages_dir));
             }
             let novel_root_path = wiki_pages_dir.parent().ok_or_else(|| {
-                format!(
-                    "wiki 디렉토리에서 소설 루트 디렉토리를 가져올 수 없습니다: {:?}",
-                    wiki_pages_dir
-                )
+                MuvelError::invalid_input(Message::NotInStandardDir(FileKind::WikiPage))
+                    .with_path(wiki_pages_dir)
             })?;
 
             // novel_io::read_novel_metadata_with_path 사용
             let (parent_novel_data, _) = novel_io::read_novel_metadata_with_path(novel_root_path)
                 .map_err(|e| {
-                format!(
-                    "위키 페이지 {}의 부모 소설 메타데이터 읽기 실패: {}",
-                    page_id, e
-                )
+                e.context(Message::ParentNovelMetadataReadFailed {
+                    kind: FileKind::WikiPage,
+                    item_id: page_id.to_string(),
+                })
             })?;
             let parent_novel_id = parent_novel_data.id.clone();
 
@@ -195,10 +187,11 @@ pub fn handle_opened_file(
                 "wiki_page".to_string(),
             )
             .map_err(|e| {
-                format!(
-                    "위키 페이지 {}와 소설 {} 매핑 실패: {}",
-                    page_id, parent_novel_id, e
-                )
+                e.context(Message::ItemMappingFailed {
+                    kind: FileKind::WikiPage,
+                    item_id: page_id.to_string(),
+                    novel_id: parent_novel_id.clone(),
+                })
             })?;
             println!(
                 "위키 페이지 {}가 아이템 인덱스에서 소설 {}에 매핑됨 (타입: wiki_page).",
@@ -235,10 +228,9 @@ pub fn handle_opened_file(
             );
         }
         _ => {
-            return Err(format!(
-                "지원하지 않는 파일 형식이거나 확장자가 없습니다: {:?}",
-                file_path
-            ));
+            return Err(
+                MuvelError::invalid_input(Message::UnsupportedFileType).with_path(file_path)
+            );
         }
     }
     Ok(())
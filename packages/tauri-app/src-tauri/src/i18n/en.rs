@@ -0,0 +1,241 @@
+use super::{FileKind, Message};
+
+fn file_kind(kind: FileKind) -> &'static str {
+    match kind {
+        FileKind::Episode => "episode",
+        FileKind::WikiPage => "wiki page",
+        FileKind::Novel => "novel",
+        FileKind::NovelMetadata => "novel metadata",
+        FileKind::NovelRoot => "novel root",
+        FileKind::EpisodeSnapshot => "episode snapshot",
+        FileKind::WikiPageSnapshot => "wiki page snapshot",
+        FileKind::NovelSnapshot => "novel snapshot",
+        FileKind::Block => "block",
+        FileKind::EpisodeJournal => "episode journal",
+        FileKind::EpisodeManifest => "episode summary manifest",
+        FileKind::EditHistory => "edit history",
+        FileKind::NovelIndex => "novel index",
+        FileKind::ItemIndex => "item index",
+        FileKind::LibraryDb => "library database",
+        FileKind::AppSettings => "app settings",
+        FileKind::TempFile => "temporary",
+        FileKind::ConflictCopy => "conflicted copy",
+        FileKind::Image => "image",
+        FileKind::Resource => "resource",
+        FileKind::NovelArchive => "novel archive",
+        FileKind::Attachment => "attachment",
+    }
+}
+
+pub(super) fn message(message: &Message) -> String {
+    match message {
+        Message::FileNotFound(kind) => format!("Could not find the {} file", file_kind(*kind)),
+        Message::FileOpenFailed(kind) => format!("Could not open the {} file", file_kind(*kind)),
+        Message::FileReadFailed(kind) => format!("Could not read the {} file", file_kind(*kind)),
+        Message::FileWriteFailed(kind) => format!("Failed to save the {} file", file_kind(*kind)),
+        Message::FileDeleteFailed(kind) => {
+            format!("Failed to delete the {} file", file_kind(*kind))
+        }
+        Message::FileParseFailed(kind) => format!("The {} file is malformed", file_kind(*kind)),
+        Message::SerializeFailed(kind) => {
+            format!("Failed to convert the {} data", file_kind(*kind))
+        }
+        Message::DirCreateFailed(kind) => {
+            format!("Failed to create the {} directory", file_kind(*kind))
+        }
+        Message::DirReadFailed(kind) => {
+            format!("Could not read the {} directory", file_kind(*kind))
+        }
+        Message::DirDeleteFailed(kind) => {
+            format!("Failed to delete the {} directory", file_kind(*kind))
+        }
+        Message::ParentDirNotFound(kind) => format!(
+            "Could not find the parent directory of the {} file",
+            file_kind(*kind)
+        ),
+        Message::PathNotFound => "The file does not exist".to_string(),
+        Message::DirEntryReadFailed => "Could not read a directory entry".to_string(),
+        Message::DirSyncFailed => "Failed to sync the directory to disk".to_string(),
+        Message::AtomicReplaceFailed => "Failed to safely replace the file".to_string(),
+        Message::FileFormatTooNew {
+            kind,
+            version,
+            supported,
+        } => format!(
+            "The {} file was created by a newer version of the app (format version: {}, supported: {}). Please update the app",
+            file_kind(*kind),
+            version,
+            supported
+        ),
+        Message::AppDataDirNotFound => "Could not find the application data directory".to_string(),
+        Message::FileNameUnreadable(kind) => format!(
+            "Could not get the ID from the {} file name",
+            file_kind(*kind)
+        ),
+        Message::NotInStandardDir(kind) => format!(
+            "The {} file is not in its standard directory",
+            file_kind(*kind)
+        ),
+        Message::PathToStringFailed => "Could not convert the path to a string".to_string(),
+        Message::UnsupportedFileType => {
+            "Unsupported file type or missing file extension".to_string()
+        }
+        Message::InvalidNovelFilePath => "Not a valid .muvl file path".to_string(),
+        Message::NotADirectory => "Not a directory".to_string(),
+        Message::FolderAlreadyExists => "A folder with the same name already exists".to_string(),
+        Message::FolderNameUnreadable => "Could not get the folder name from the path".to_string(),
+        Message::MultipleNovelFiles { found } => {
+            format!("The novel folder contains multiple .muvl files ({})", found)
+        }
+        Message::OpenFolderFailed => "Failed to open the folder in the file explorer".to_string(),
+        Message::UnsupportedOs => "Unsupported operating system".to_string(),
+
+        Message::NovelNotInIndex { novel_id } => {
+            format!("Could not find the novel in the index (ID: {})", novel_id)
+        }
+        Message::NovelPathMissing { novel_id } => {
+            format!("The index has no path for the novel (ID: {})", novel_id)
+        }
+        Message::NovelRootNotFound { novel_id } => {
+            format!("Could not find the novel project folder (ID: {})", novel_id)
+        }
+        Message::NovelFolderMissing => {
+            "The novel project folder does not exist or is not a directory".to_string()
+        }
+        Message::ParentNovelNotFound { item_id } => {
+            format!(
+                "Could not find the novel this item belongs to (ID: {})",
+                item_id
+            )
+        }
+        Message::NovelIndexUpdateFailed { novel_id } => {
+            format!(
+                "Failed to register the novel in the index (ID: {})",
+                novel_id
+            )
+        }
+        Message::ParentNovelMetadataReadFailed { kind, item_id } => format!(
+            "Could not read the metadata of the novel this {} belongs to (ID: {})",
+            file_kind(*kind),
+            item_id
+        ),
+        Message::ItemMappingFailed {
+            kind,
+            item_id,
+            novel_id,
+        } => format!(
+            "Failed to link the {} to its novel (ID: {}, novel ID: {})",
+            file_kind(*kind),
+            item_id,
+            novel_id
+        ),
+        Message::LibraryDbQueryFailed => "A library database operation failed".to_string(),
+        Message::LibraryImportFailed => {
+            "Failed to import the existing index files into the library database".to_string()
+        }
+
+        Message::EpisodeBatchUpdateFailed { episode_id } => {
+            format!(
+                "An error occurred while batch updating the episode (ID: {})",
+                episode_id
+            )
+        }
+        Message::BaseRevisionAhead {
+            episode_id,
+            base_revision,
+            current_revision,
+        } => format!(
+            "The base revision ({}) is newer than the current revision ({}) (episode ID: {})",
+            base_revision, current_revision, episode_id
+        ),
+        Message::JournalLineCorrupted { episode_id, line } => format!(
+            "Line {} of the episode journal is corrupted, so later changes cannot be applied (episode ID: {})",
+            line, episode_id
+        ),
+        Message::MissingBlockField { block_id, field } => format!(
+            "The block to create has no {} (block ID: {})",
+            field, block_id
+        ),
+        Message::BlockNotFoundInStore { hash } => {
+            format!(
+                "Could not find the block in the block store (hash: {})",
+                hash
+            )
+        }
+        Message::BlockCompressFailed => "Failed to compress the block data".to_string(),
+        Message::BlockDecompressFailed => "Failed to decompress the block data".to_string(),
+
+        Message::SnapshotOwnerMismatch {
+            snapshot_id,
+            kind,
+            owner_id,
+        } => format!(
+            "The snapshot does not belong to this {} (snapshot ID: {}, {} ID: {})",
+            file_kind(*kind),
+            snapshot_id,
+            file_kind(*kind),
+            owner_id
+        ),
+        Message::SnapshotItemMissing {
+            snapshot_id,
+            kind,
+            item_id,
+        } => format!(
+            "The snapshot does not contain this {} (snapshot ID: {}, {} ID: {})",
+            file_kind(*kind),
+            snapshot_id,
+            file_kind(*kind),
+            item_id
+        ),
+
+        Message::NotATempFile => "Not a temporary file path".to_string(),
+        Message::TempFileCorrupted => {
+            "The temporary file is corrupted and cannot be recovered".to_string()
+        }
+        Message::TempFileNotRecoverable => {
+            "This kind of temporary file cannot be recovered; it can only be discarded".to_string()
+        }
+        Message::TempPathResolveFailed => "Could not resolve the temporary file path".to_string(),
+        Message::OutsideManagedRoots => {
+            "The file is outside the app data and registered novel folders".to_string()
+        }
+
+        Message::NotAConflictCopy => "Not a sync conflicted copy".to_string(),
+        Message::ConflictCopyIdMismatch { expected, found } => format!(
+            "The conflicted copy has a different ID from the original (original ID: {}, copy ID: {})",
+            expected, found
+        ),
+
+        Message::ArchiveEntryInvalid { entry } => {
+            format!("The archive entry has an invalid path: {}", entry)
+        }
+        Message::ArchiveEntryMissing { entry } => {
+            format!("The archive is missing an entry listed in its manifest: {}", entry)
+        }
+        Message::ArchiveChecksumMismatch { entry } => format!(
+            "The archive entry failed its checksum check and may be corrupted: {}",
+            entry
+        ),
+        Message::ArchiveNovelIdMismatch { expected, found } => format!(
+            "The novel ID in the archive does not match its manifest (manifest ID: {}, novel ID: {})",
+            expected, found
+        ),
+        Message::NovelAlreadyRegistered { novel_id } => {
+            format!("This novel is already registered (novel ID: {})", novel_id)
+        }
+
+        Message::InvalidResourceUri { uri } => format!("Invalid resource URI: {}", uri),
+        Message::NotAnImageResource => "Not an image resource".to_string(),
+        Message::ImageProcessFailed => "Failed to process the image".to_string(),
+        Message::InvalidAttachmentName => "The attachment name is empty or invalid".to_string(),
+        Message::AttachmentLinkInvalid { item_id } => {
+            format!("Not an episode or wiki page of this novel: {}", item_id)
+        }
+
+        Message::LoginServerStartFailed => "Failed to start the login callback server".to_string(),
+        Message::LoginTokenMissing => "The login callback has no token".to_string(),
+        Message::LoginCallbackFailed => "Did not receive the login callback".to_string(),
+
+        Message::DefaultEpisodeTitle { order } => format!("New Episode {}", order),
+    }
+}
@@ -0,0 +1,252 @@
+use super::{FileKind, Message};
+
+fn file_kind(kind: FileKind) -> &'static str {
+    match kind {
+        FileKind::Episode => "エピソード",
+        FileKind::WikiPage => "Wikiページ",
+        FileKind::Novel => "小説",
+        FileKind::NovelMetadata => "小説メタデータ",
+        FileKind::NovelRoot => "小説ルート",
+        FileKind::EpisodeSnapshot => "エピソードスナップショット",
+        FileKind::WikiPageSnapshot => "Wikiページスナップショット",
+        FileKind::NovelSnapshot => "小説スナップショット",
+        FileKind::Block => "ブロック",
+        FileKind::EpisodeJournal => "エピソードジャーナル",
+        FileKind::EpisodeManifest => "エピソード要約マニフェスト",
+        FileKind::EditHistory => "編集履歴",
+        FileKind::NovelIndex => "小説インデックス",
+        FileKind::ItemIndex => "アイテムインデックス",
+        FileKind::LibraryDb => "ライブラリデータベース",
+        FileKind::AppSettings => "アプリ設定",
+        FileKind::TempFile => "一時",
+        FileKind::ConflictCopy => "競合コピー",
+        FileKind::Image => "画像",
+        FileKind::Resource => "リソース",
+        FileKind::NovelArchive => "小説アーカイブ",
+        FileKind::Attachment => "添付ファイル",
+    }
+}
+
+pub(super) fn message(message: &Message) -> String {
+    match message {
+        Message::FileNotFound(kind) => format!("{}ファイルが見つかりません", file_kind(*kind)),
+        Message::FileOpenFailed(kind) => format!("{}ファイルを開けません", file_kind(*kind)),
+        Message::FileReadFailed(kind) => format!("{}ファイルを読み込めません", file_kind(*kind)),
+        Message::FileWriteFailed(kind) => {
+            format!("{}ファイルの保存に失敗しました", file_kind(*kind))
+        }
+        Message::FileDeleteFailed(kind) => {
+            format!("{}ファイルの削除に失敗しました", file_kind(*kind))
+        }
+        Message::FileParseFailed(kind) => {
+            format!("{}ファイルの内容が正しくありません", file_kind(*kind))
+        }
+        Message::SerializeFailed(kind) => {
+            format!("{}データの変換に失敗しました", file_kind(*kind))
+        }
+        Message::DirCreateFailed(kind) => {
+            format!("{}ディレクトリの作成に失敗しました", file_kind(*kind))
+        }
+        Message::DirReadFailed(kind) => {
+            format!("{}ディレクトリを読み込めません", file_kind(*kind))
+        }
+        Message::DirDeleteFailed(kind) => {
+            format!("{}ディレクトリの削除に失敗しました", file_kind(*kind))
+        }
+        Message::ParentDirNotFound(kind) => {
+            format!(
+                "{}ファイルの親ディレクトリが見つかりません",
+                file_kind(*kind)
+            )
+        }
+        Message::PathNotFound => "ファイルが存在しません".to_string(),
+        Message::DirEntryReadFailed => "ディレクトリ項目を読み込めません".to_string(),
+        Message::DirSyncFailed => "ディレクトリをディスクに同期できませんでした".to_string(),
+        Message::AtomicReplaceFailed => "ファイルを安全に置き換えられませんでした".to_string(),
+        Message::FileFormatTooNew {
+            kind,
+            version,
+            supported,
+        } => format!(
+            "新しいバージョンのアプリで作成された{}ファイルです (ファイル形式バージョン: {}, 対応バージョン: {})。アプリを更新してください",
+            file_kind(*kind),
+            version,
+            supported
+        ),
+        Message::AppDataDirNotFound => {
+            "アプリケーションデータディレクトリが見つかりません".to_string()
+        }
+        Message::FileNameUnreadable(kind) => {
+            format!("{}ファイル名からIDを取得できません", file_kind(*kind))
+        }
+        Message::NotInStandardDir(kind) => {
+            format!("{}ファイルが標準ディレクトリにありません", file_kind(*kind))
+        }
+        Message::PathToStringFailed => "パスを文字列に変換できません".to_string(),
+        Message::UnsupportedFileType => {
+            "対応していないファイル形式か、拡張子がありません".to_string()
+        }
+        Message::InvalidNovelFilePath => "有効な.muvlファイルのパスではありません".to_string(),
+        Message::NotADirectory => "ディレクトリではありません".to_string(),
+        Message::FolderAlreadyExists => "同じ名前のフォルダがすでに存在します".to_string(),
+        Message::FolderNameUnreadable => "パスからフォルダ名を取得できません".to_string(),
+        Message::MultipleNovelFiles { found } => {
+            format!("小説フォルダに.muvlファイルが複数あります ({})", found)
+        }
+        Message::OpenFolderFailed => {
+            "ファイルエクスプローラーでフォルダを開けませんでした".to_string()
+        }
+        Message::UnsupportedOs => "対応していないOSです".to_string(),
+
+        Message::NovelNotInIndex { novel_id } => {
+            format!("インデックスに小説が見つかりません (ID: {})", novel_id)
+        }
+        Message::NovelPathMissing { novel_id } => {
+            format!(
+                "インデックスに小説のパス情報がありません (ID: {})",
+                novel_id
+            )
+        }
+        Message::NovelRootNotFound { novel_id } => {
+            format!(
+                "小説プロジェクトのフォルダが見つかりません (ID: {})",
+                novel_id
+            )
+        }
+        Message::NovelFolderMissing => {
+            "小説プロジェクトのフォルダが存在しないか、ディレクトリではありません".to_string()
+        }
+        Message::ParentNovelNotFound { item_id } => {
+            format!("項目が属する小説が見つかりません (ID: {})", item_id)
+        }
+        Message::NovelIndexUpdateFailed { novel_id } => {
+            format!(
+                "小説をインデックスに登録できませんでした (ID: {})",
+                novel_id
+            )
+        }
+        Message::ParentNovelMetadataReadFailed { kind, item_id } => format!(
+            "{}が属する小説のメタデータを読み込めませんでした (ID: {})",
+            file_kind(*kind),
+            item_id
+        ),
+        Message::ItemMappingFailed {
+            kind,
+            item_id,
+            novel_id,
+        } => format!(
+            "{}を小説に関連付けられませんでした (ID: {}, 小説ID: {})",
+            file_kind(*kind),
+            item_id,
+            novel_id
+        ),
+        Message::LibraryDbQueryFailed => "ライブラリデータベースの操作に失敗しました".to_string(),
+        Message::LibraryImportFailed => {
+            "既存のインデックスファイルをライブラリデータベースに取り込めませんでした".to_string()
+        }
+
+        Message::EpisodeBatchUpdateFailed { episode_id } => {
+            format!(
+                "エピソードの一括更新中にエラーが発生しました (ID: {})",
+                episode_id
+            )
+        }
+        Message::BaseRevisionAhead {
+            episode_id,
+            base_revision,
+            current_revision,
+        } => format!(
+            "基準リビジョン({})が現在のリビジョン({})より新しいです (エピソードID: {})",
+            base_revision, current_revision, episode_id
+        ),
+        Message::JournalLineCorrupted { episode_id, line } => format!(
+            "エピソードジャーナルの{}行目が破損しているため、以降の変更を適用できません (エピソードID: {})",
+            line, episode_id
+        ),
+        Message::MissingBlockField { block_id, field } => format!(
+            "作成するブロックに{}がありません (ブロックID: {})",
+            field, block_id
+        ),
+        Message::BlockNotFoundInStore { hash } => {
+            format!(
+                "ブロックストアにブロックが見つかりません (ハッシュ: {})",
+                hash
+            )
+        }
+        Message::BlockCompressFailed => "ブロックデータの圧縮に失敗しました".to_string(),
+        Message::BlockDecompressFailed => "ブロックデータの展開に失敗しました".to_string(),
+
+        Message::SnapshotOwnerMismatch {
+            snapshot_id,
+            kind,
+            owner_id,
+        } => format!(
+            "この{}のスナップショットではありません (スナップショットID: {}, {}ID: {})",
+            file_kind(*kind),
+            snapshot_id,
+            file_kind(*kind),
+            owner_id
+        ),
+        Message::SnapshotItemMissing {
+            snapshot_id,
+            kind,
+            item_id,
+        } => format!(
+            "スナップショットにこの{}が含まれていません (スナップショットID: {}, {}ID: {})",
+            file_kind(*kind),
+            snapshot_id,
+            file_kind(*kind),
+            item_id
+        ),
+
+        Message::NotATempFile => "一時ファイルのパスではありません".to_string(),
+        Message::TempFileCorrupted => "一時ファイルが破損しているため復元できません".to_string(),
+        Message::TempFileNotRecoverable => {
+            "この種類の一時ファイルは復元できません。削除のみ可能です".to_string()
+        }
+        Message::TempPathResolveFailed => "一時ファイルのパスを確認できません".to_string(),
+        Message::OutsideManagedRoots => {
+            "アプリデータまたは登録された小説フォルダの外にあるファイルです".to_string()
+        }
+
+        Message::NotAConflictCopy => "同期の競合コピーではありません".to_string(),
+        Message::ConflictCopyIdMismatch { expected, found } => format!(
+            "競合コピーのIDが元のファイルと異なります (元のID: {}, コピーのID: {})",
+            expected, found
+        ),
+
+        Message::ArchiveEntryInvalid { entry } => {
+            format!("アーカイブ項目のパスが正しくありません: {}", entry)
+        }
+        Message::ArchiveEntryMissing { entry } => {
+            format!("マニフェストに記載された項目がアーカイブにありません: {}", entry)
+        }
+        Message::ArchiveChecksumMismatch { entry } => format!(
+            "アーカイブ項目のチェックサムが一致しません。ファイルが破損している可能性があります: {}",
+            entry
+        ),
+        Message::ArchiveNovelIdMismatch { expected, found } => format!(
+            "アーカイブの小説IDがマニフェストと異なります (マニフェストのID: {}, 小説のID: {})",
+            expected, found
+        ),
+        Message::NovelAlreadyRegistered { novel_id } => {
+            format!("すでに登録されている小説です (小説ID: {})", novel_id)
+        }
+
+        Message::InvalidResourceUri { uri } => format!("無効なリソースURIです: {}", uri),
+        Message::NotAnImageResource => "画像リソースではありません".to_string(),
+        Message::ImageProcessFailed => "画像を処理できませんでした".to_string(),
+        Message::InvalidAttachmentName => "添付ファイル名が空か、使用できません".to_string(),
+        Message::AttachmentLinkInvalid { item_id } => {
+            format!("この小説のエピソードまたはWikiページではありません: {}", item_id)
+        }
+
+        Message::LoginServerStartFailed => {
+            "ログインコールバックサーバーを起動できませんでした".to_string()
+        }
+        Message::LoginTokenMissing => "ログインコールバックにトークンがありません".to_string(),
+        Message::LoginCallbackFailed => "ログインコールバックを受信できませんでした".to_string(),
+
+        Message::DefaultEpisodeTitle { order } => format!("新しいエピソード {}", order),
+    }
+}
@@ -0,0 +1,232 @@
+use super::{FileKind, Message};
+
+fn file_kind(kind: FileKind) -> &'static str {
+    match kind {
+        FileKind::Episode => "에피소드",
+        FileKind::WikiPage => "위키 페이지",
+        FileKind::Novel => "소설",
+        FileKind::NovelMetadata => "소설 메타데이터",
+        FileKind::NovelRoot => "소설 루트",
+        FileKind::EpisodeSnapshot => "에피소드 스냅샷",
+        FileKind::WikiPageSnapshot => "위키 페이지 스냅샷",
+        FileKind::NovelSnapshot => "소설 스냅샷",
+        FileKind::Block => "블록",
+        FileKind::EpisodeJournal => "에피소드 저널",
+        FileKind::EpisodeManifest => "에피소드 요약 매니페스트",
+        FileKind::EditHistory => "편집 기록",
+        FileKind::NovelIndex => "소설 인덱스",
+        FileKind::ItemIndex => "아이템 인덱스",
+        FileKind::LibraryDb => "라이브러리 데이터베이스",
+        FileKind::AppSettings => "앱 설정",
+        FileKind::TempFile => "임시",
+        FileKind::ConflictCopy => "충돌 사본",
+        FileKind::Image => "이미지",
+        FileKind::Resource => "리소스",
+        FileKind::NovelArchive => "소설 아카이브",
+        FileKind::Attachment => "첨부 파일",
+    }
+}
+
+pub(super) fn message(message: &Message) -> String {
+    match message {
+        Message::FileNotFound(kind) => format!("{} 파일을 찾을 수 없습니다", file_kind(*kind)),
+        Message::FileOpenFailed(kind) => format!("{} 파일을 열 수 없습니다", file_kind(*kind)),
+        Message::FileReadFailed(kind) => format!("{} 파일을 읽을 수 없습니다", file_kind(*kind)),
+        Message::FileWriteFailed(kind) => format!("{} 파일 저장에 실패했습니다", file_kind(*kind)),
+        Message::FileDeleteFailed(kind) => format!("{} 파일 삭제에 실패했습니다", file_kind(*kind)),
+        Message::FileParseFailed(kind) => {
+            format!("{} 파일의 내용이 올바르지 않습니다", file_kind(*kind))
+        }
+        Message::SerializeFailed(kind) => {
+            format!("{} 데이터를 변환하는 데 실패했습니다", file_kind(*kind))
+        }
+        Message::DirCreateFailed(kind) => {
+            format!("{} 디렉토리 생성에 실패했습니다", file_kind(*kind))
+        }
+        Message::DirReadFailed(kind) => format!("{} 디렉토리를 읽을 수 없습니다", file_kind(*kind)),
+        Message::DirDeleteFailed(kind) => {
+            format!("{} 디렉토리 삭제에 실패했습니다", file_kind(*kind))
+        }
+        Message::ParentDirNotFound(kind) => {
+            format!(
+                "{} 파일의 상위 디렉토리를 찾을 수 없습니다",
+                file_kind(*kind)
+            )
+        }
+        Message::PathNotFound => "파일이 존재하지 않습니다".to_string(),
+        Message::DirEntryReadFailed => "디렉토리 항목을 읽을 수 없습니다".to_string(),
+        Message::DirSyncFailed => "디렉토리를 디스크에 동기화하지 못했습니다".to_string(),
+        Message::AtomicReplaceFailed => "파일을 안전하게 교체하는 데 실패했습니다".to_string(),
+        Message::FileFormatTooNew {
+            kind,
+            version,
+            supported,
+        } => format!(
+            "더 새로운 버전의 앱에서 만든 {} 파일입니다 (파일 형식 버전: {}, 지원하는 버전: {}). 앱을 업데이트해 주세요",
+            file_kind(*kind),
+            version,
+            supported
+        ),
+        Message::AppDataDirNotFound => {
+            "애플리케이션 데이터 디렉토리를 찾을 수 없습니다".to_string()
+        }
+        Message::FileNameUnreadable(kind) => {
+            format!("{} 파일 이름에서 ID를 가져올 수 없습니다", file_kind(*kind))
+        }
+        Message::NotInStandardDir(kind) => {
+            format!("{} 파일이 표준 디렉토리에 있지 않습니다", file_kind(*kind))
+        }
+        Message::PathToStringFailed => "경로를 문자열로 변환할 수 없습니다".to_string(),
+        Message::UnsupportedFileType => {
+            "지원하지 않는 파일 형식이거나 확장자가 없습니다".to_string()
+        }
+        Message::InvalidNovelFilePath => "유효한 .muvl 파일 경로가 아닙니다".to_string(),
+        Message::NotADirectory => "디렉토리가 아닙니다".to_string(),
+        Message::FolderAlreadyExists => "이미 같은 이름의 폴더가 존재합니다".to_string(),
+        Message::FolderNameUnreadable => "경로에서 폴더 이름을 가져올 수 없습니다".to_string(),
+        Message::MultipleNovelFiles { found } => {
+            format!("소설 폴더에 .muvl 파일이 여러 개 있습니다 ({})", found)
+        }
+        Message::OpenFolderFailed => "파일 탐색기에서 폴더를 여는 데 실패했습니다".to_string(),
+        Message::UnsupportedOs => "지원하지 않는 운영체제입니다".to_string(),
+
+        Message::NovelNotInIndex { novel_id } => {
+            format!("인덱스에서 소설을 찾을 수 없습니다 (ID: {})", novel_id)
+        }
+        Message::NovelPathMissing { novel_id } => {
+            format!("인덱스에 소설 경로 정보가 없습니다 (ID: {})", novel_id)
+        }
+        Message::NovelRootNotFound { novel_id } => {
+            format!("소설 프로젝트 폴더를 찾을 수 없습니다 (ID: {})", novel_id)
+        }
+        Message::NovelFolderMissing => {
+            "소설 프로젝트 폴더가 없거나 디렉토리가 아닙니다".to_string()
+        }
+        Message::ParentNovelNotFound { item_id } => {
+            format!("항목이 속한 소설을 찾을 수 없습니다 (ID: {})", item_id)
+        }
+        Message::NovelIndexUpdateFailed { novel_id } => {
+            format!("소설을 인덱스에 등록하지 못했습니다 (ID: {})", novel_id)
+        }
+        Message::ParentNovelMetadataReadFailed { kind, item_id } => format!(
+            "{}가 속한 소설의 메타데이터를 읽지 못했습니다 (ID: {})",
+            file_kind(*kind),
+            item_id
+        ),
+        Message::ItemMappingFailed {
+            kind,
+            item_id,
+            novel_id,
+        } => format!(
+            "{}를 소설에 연결하지 못했습니다 (ID: {}, 소설 ID: {})",
+            file_kind(*kind),
+            item_id,
+            novel_id
+        ),
+        Message::LibraryDbQueryFailed => "라이브러리 데이터베이스 작업에 실패했습니다".to_string(),
+        Message::LibraryImportFailed => {
+            "기존 인덱스 파일을 라이브러리 데이터베이스로 가져오지 못했습니다".to_string()
+        }
+
+        Message::EpisodeBatchUpdateFailed { episode_id } => {
+            format!(
+                "에피소드 일괄 업데이트 중 오류가 발생했습니다 (ID: {})",
+                episode_id
+            )
+        }
+        Message::BaseRevisionAhead {
+            episode_id,
+            base_revision,
+            current_revision,
+        } => format!(
+            "기준 리비전({})이 현재 리비전({})보다 큽니다 (에피소드 ID: {})",
+            base_revision, current_revision, episode_id
+        ),
+        Message::JournalLineCorrupted { episode_id, line } => format!(
+            "에피소드 저널의 {}번째 줄이 손상되어 이후 변경분을 적용할 수 없습니다 (에피소드 ID: {})",
+            line, episode_id
+        ),
+        Message::MissingBlockField { block_id, field } => {
+            format!(
+                "생성할 블록에 {} 값이 없습니다 (블록 ID: {})",
+                field, block_id
+            )
+        }
+        Message::BlockNotFoundInStore { hash } => {
+            format!("블록 저장소에서 블록을 찾을 수 없습니다 (해시: {})", hash)
+        }
+        Message::BlockCompressFailed => "블록 데이터 압축에 실패했습니다".to_string(),
+        Message::BlockDecompressFailed => "블록 데이터 압축 해제에 실패했습니다".to_string(),
+
+        Message::SnapshotOwnerMismatch {
+            snapshot_id,
+            kind,
+            owner_id,
+        } => format!(
+            "스냅샷이 해당 {}의 스냅샷이 아닙니다 (스냅샷 ID: {}, {} ID: {})",
+            file_kind(*kind),
+            snapshot_id,
+            file_kind(*kind),
+            owner_id
+        ),
+        Message::SnapshotItemMissing {
+            snapshot_id,
+            kind,
+            item_id,
+        } => format!(
+            "스냅샷에 해당 {}가 없습니다 (스냅샷 ID: {}, {} ID: {})",
+            file_kind(*kind),
+            snapshot_id,
+            file_kind(*kind),
+            item_id
+        ),
+
+        Message::NotATempFile => "임시 파일 경로가 아닙니다".to_string(),
+        Message::TempFileCorrupted => "임시 파일이 손상되어 복구할 수 없습니다".to_string(),
+        Message::TempFileNotRecoverable => {
+            "복구할 수 없는 종류의 임시 파일입니다. 삭제만 할 수 있습니다".to_string()
+        }
+        Message::TempPathResolveFailed => "임시 파일 경로를 확인할 수 없습니다".to_string(),
+        Message::OutsideManagedRoots => {
+            "앱 데이터 또는 등록된 소설 폴더 밖의 파일입니다".to_string()
+        }
+
+        Message::NotAConflictCopy => "동기화 충돌 사본 파일이 아닙니다".to_string(),
+        Message::ConflictCopyIdMismatch { expected, found } => format!(
+            "충돌 사본의 ID가 원본과 다릅니다 (원본 ID: {}, 사본 ID: {})",
+            expected, found
+        ),
+
+        Message::ArchiveEntryInvalid { entry } => {
+            format!("아카이브 항목의 경로가 올바르지 않습니다: {}", entry)
+        }
+        Message::ArchiveEntryMissing { entry } => {
+            format!("아카이브에 매니페스트의 항목이 없습니다: {}", entry)
+        }
+        Message::ArchiveChecksumMismatch { entry } => format!(
+            "아카이브 항목의 체크섬이 맞지 않습니다. 파일이 손상되었을 수 있습니다: {}",
+            entry
+        ),
+        Message::ArchiveNovelIdMismatch { expected, found } => format!(
+            "아카이브의 소설 ID가 매니페스트와 다릅니다 (매니페스트 ID: {}, 소설 ID: {})",
+            expected, found
+        ),
+        Message::NovelAlreadyRegistered { novel_id } => {
+            format!("이미 등록된 소설입니다 (소설 ID: {})", novel_id)
+        }
+
+        Message::InvalidResourceUri { uri } => format!("잘못된 리소스 주소입니다: {}", uri),
+        Message::NotAnImageResource => "이미지 리소스가 아닙니다".to_string(),
+        Message::ImageProcessFailed => "이미지를 처리하지 못했습니다".to_string(),
+        Message::InvalidAttachmentName => "첨부 파일 이름이 비어 있거나 사용할 수 없습니다".to_string(),
+        Message::AttachmentLinkInvalid { item_id } => {
+            format!("이 소설의 에피소드나 위키 페이지가 아닙니다: {}", item_id)
+        }
+
+        Message::LoginServerStartFailed => "로그인 콜백 서버를 시작하지 못했습니다".to_string(),
+        Message::LoginTokenMissing => "로그인 콜백에 토큰이 없습니다".to_string(),
+        Message::LoginCallbackFailed => "로그인 콜백을 받지 못했습니다".to_string(),
+
+        Message::DefaultEpisodeTitle { order } => format!("새 에피소드 {}", order),
+    }
+}
@@ -0,0 +1,240 @@
+//! 백엔드에서 사용자에게 보여주는 문구(오류 메시지, 기본 이름)의 언어별 카탈로그
+//!
+//! 문구는 `Message`로 정의하고, 현재 앱 언어(`AppLocale`)에 맞는 카탈로그(ko/en/ja)에서 텍스트를 가져옵니다.
+//! 앱 언어는 시작 시 앱 설정에서 불러오며, 설정이 바뀌면 `set_locale`로 갱신합니다.
+
+mod en;
+mod ja;
+mod ko;
+
+use crate::models::enums::app_locale::AppLocale;
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+static CURRENT_LOCALE: AtomicU8 = AtomicU8::new(AppLocale::Ko as u8);
+
+/// 이후 생성되는 메시지에 사용할 앱 언어를 바꿉니다.
+pub fn set_locale(locale: AppLocale) {
+    CURRENT_LOCALE.store(locale as u8, Ordering::Relaxed);
+}
+
+/// 현재 앱 언어
+pub fn current_locale() -> AppLocale {
+    match CURRENT_LOCALE.load(Ordering::Relaxed) {
+        x if x == AppLocale::En as u8 => AppLocale::En,
+        x if x == AppLocale::Ja as u8 => AppLocale::Ja,
+        _ => AppLocale::Ko,
+    }
+}
+
+/// 메시지에서 가리키는 파일(또는 디렉토리)의 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileKind {
+    Episode,
+    WikiPage,
+    Novel,
+    NovelMetadata,
+    NovelRoot,
+    EpisodeSnapshot,
+    WikiPageSnapshot,
+    NovelSnapshot,
+    Block,
+    EpisodeJournal,
+    EpisodeManifest,
+    EditHistory,
+    NovelIndex,
+    ItemIndex,
+    LibraryDb,
+    AppSettings,
+    TempFile,
+    ConflictCopy,
+    Image,
+    Resource,
+    NovelArchive,
+    Attachment,
+}
+
+/// 사용자에게 보여주는 백엔드 문구
+#[derive(Debug, Clone, PartialEq)]
+pub enum Message {
+    // --- 파일 입출력 ---
+    FileNotFound(FileKind),
+    FileOpenFailed(FileKind),
+    FileReadFailed(FileKind),
+    FileWriteFailed(FileKind),
+    FileDeleteFailed(FileKind),
+    FileParseFailed(FileKind),
+    SerializeFailed(FileKind),
+    DirCreateFailed(FileKind),
+    DirReadFailed(FileKind),
+    DirDeleteFailed(FileKind),
+    ParentDirNotFound(FileKind),
+    PathNotFound,
+    DirEntryReadFailed,
+    DirSyncFailed,
+    AtomicReplaceFailed,
+    FileFormatTooNew {
+        kind: FileKind,
+        version: u64,
+        supported: u32,
+    },
+    AppDataDirNotFound,
+    FileNameUnreadable(FileKind),
+    NotInStandardDir(FileKind),
+    PathToStringFailed,
+    UnsupportedFileType,
+    InvalidNovelFilePath,
+    NotADirectory,
+    FolderAlreadyExists,
+    FolderNameUnreadable,
+    MultipleNovelFiles {
+        found: String,
+    },
+    OpenFolderFailed,
+    UnsupportedOs,
+
+    // --- 인덱스 ---
+    NovelNotInIndex {
+        novel_id: String,
+    },
+    NovelPathMissing {
+        novel_id: String,
+    },
+    NovelRootNotFound {
+        novel_id: String,
+    },
+    NovelFolderMissing,
+    ParentNovelNotFound {
+        item_id: String,
+    },
+    NovelIndexUpdateFailed {
+        novel_id: String,
+    },
+    ParentNovelMetadataReadFailed {
+        kind: FileKind,
+        item_id: String,
+    },
+    ItemMappingFailed {
+        kind: FileKind,
+        item_id: String,
+        novel_id: String,
+    },
+    LibraryDbQueryFailed,
+    LibraryImportFailed,
+
+    // --- 에피소드 / 블록 ---
+    EpisodeBatchUpdateFailed {
+        episode_id: String,
+    },
+    BaseRevisionAhead {
+        episode_id: String,
+        base_revision: u64,
+        current_revision: u64,
+    },
+    JournalLineCorrupted {
+        episode_id: String,
+        line: usize,
+    },
+    MissingBlockField {
+        block_id: String,
+        field: &'static str,
+    },
+    BlockNotFoundInStore {
+        hash: String,
+    },
+    BlockCompressFailed,
+    BlockDecompressFailed,
+
+    // --- 스냅샷 ---
+    SnapshotOwnerMismatch {
+        snapshot_id: String,
+        kind: FileKind,
+        owner_id: String,
+    },
+    SnapshotItemMissing {
+        snapshot_id: String,
+        kind: FileKind,
+        item_id: String,
+    },
+
+    // --- 임시 파일 복구 ---
+    NotATempFile,
+    TempFileCorrupted,
+    TempFileNotRecoverable,
+    TempPathResolveFailed,
+    OutsideManagedRoots,
+
+    // --- 동기화 충돌 사본 ---
+    NotAConflictCopy,
+    ConflictCopyIdMismatch {
+        expected: String,
+        found: String,
+    },
+
+    // --- 소설 아카이브 ---
+    ArchiveEntryInvalid {
+        entry: String,
+    },
+    ArchiveEntryMissing {
+        entry: String,
+    },
+    ArchiveChecksumMismatch {
+        entry: String,
+    },
+    ArchiveNovelIdMismatch {
+        expected: String,
+        found: String,
+    },
+    NovelAlreadyRegistered {
+        novel_id: String,
+    },
+
+    // --- 리소스 ---
+    InvalidResourceUri {
+        uri: String,
+    },
+    NotAnImageResource,
+    ImageProcessFailed,
+    InvalidAttachmentName,
+    AttachmentLinkInvalid {
+        item_id: String,
+    },
+
+    // --- 로그인 ---
+    LoginServerStartFailed,
+    LoginTokenMissing,
+    LoginCallbackFailed,
+
+    // --- 기본 이름 ---
+    DefaultEpisodeTitle {
+        order: i64,
+    },
+}
+
+impl Message {
+    /// 지정한 언어의 텍스트를 반환합니다.
+    pub fn text_in(&self, locale: AppLocale) -> String {
+        match locale {
+            AppLocale::Ko => ko::message(self),
+            AppLocale::En => en::message(self),
+            AppLocale::Ja => ja::message(self),
+        }
+    }
+
+    /// 현재 앱 언어의 텍스트를 반환합니다.
+    pub fn text(&self) -> String {
+        self.text_in(current_locale())
+    }
+}
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text())
+    }
+}
+
+impl From<Message> for String {
+    fn from(message: Message) -> Self {
+        message.text()
+    }
+}
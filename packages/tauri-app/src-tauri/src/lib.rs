@@ -6,10 +6,15 @@ use tauri_plugin_deep_link::DeepLinkExt;
 #[cfg(not(any(target_os = "android", target_os = "ios")))]
 use tauri_plugin_cli::CliExt;
 
-use crate::models::commons::PendingOpen;
+use crate::models::commons::{PendingOpen, PendingRecovery};
+use crate::storage::index_cache::IndexCache;
+use crate::storage::library_db::LibraryDb;
+use crate::storage::novel_watcher::{self, NovelWatcher};
+use crate::storage::{resource_uri, settings_manager, temp_file_recovery};
 
 mod commands;
 mod file_handler;
+mod i18n;
 mod models;
 mod repositories;
 mod storage;
@@ -33,6 +38,46 @@ pub fn run() {
 
     builder
         .manage(PendingOpen::default())
+        .manage(PendingRecovery::default())
+        .manage(IndexCache::default())
+        .manage(LibraryDb::default())
+        .manage(NovelWatcher::default())
+        // 소설 리소스 URI(muvel-resource://localhost/{소설 ID}/...)를 요청 시점의 소설 폴더 기준으로 해석
+        .register_asynchronous_uri_scheme_protocol(
+            resource_uri::RESOURCE_URI_SCHEME,
+            |ctx, request, responder| {
+                let app_handle = ctx.app_handle().clone();
+                let uri_path = request.uri().path().to_string();
+                std::thread::spawn(move || {
+                    let response = match resource_uri::load_resource(&app_handle, &uri_path) {
+                        Ok((bytes, mime_type)) => {
+                            use tauri::http::header;
+                            let mut builder = tauri::http::Response::builder()
+                                .header(header::CONTENT_TYPE, mime_type)
+                                .header(header::X_CONTENT_TYPE_OPTIONS, "nosniff");
+                            // 첨부한 HTML, SVG의 스크립트가 앱 화면의 권한으로 실행되지 않도록 함
+                            // (<img>로 표시하는 SVG는 그대로 보임)
+                            if resource_uri::is_active_content(mime_type) {
+                                builder = builder
+                                    .header(header::CONTENT_SECURITY_POLICY, "sandbox")
+                                    .header(header::CONTENT_DISPOSITION, "attachment");
+                            }
+                            builder.body(bytes)
+                        }
+                        Err(e) => {
+                            eprintln!("리소스를 불러오지 못했습니다 ({uri_path}): {e}");
+                            tauri::http::Response::builder()
+                                .status(tauri::http::StatusCode::NOT_FOUND)
+                                .body(Vec::new())
+                        }
+                    };
+                    match response {
+                        Ok(response) => responder.respond(response),
+                        Err(e) => eprintln!("리소스 응답을 만들지 못했습니다 ({uri_path}): {e}"),
+                    }
+                });
+            },
+        )
         .setup(|app| {
             #[cfg(not(any(target_os = "android", target_os = "ios")))]
             {
@@ -66,6 +111,20 @@ pub fn run() {
                 )?;
             }
 
+            // 백엔드 메시지 언어 적용 (설정을 읽지 못하면 기본 언어 유지)
+            match settings_manager::load_settings(app.handle()) {
+                Ok(settings) => i18n::set_locale(settings.locale),
+                Err(e) => eprintln!("앱 설정 로드 실패, 기본 언어를 사용합니다: {e}"),
+            }
+
+            // 이전 실행에서 쓰는 도중 중단되어 남은 임시 파일 검사 (시작을 늦추지 않도록 백그라운드에서 실행)
+            temp_file_recovery::spawn_leftover_temp_file_scan(app.handle().clone());
+
+            // 동기화 서비스 등 앱 밖에서 바뀐 소설 파일 감시
+            if let Err(e) = novel_watcher::start(app.handle()) {
+                eprintln!("소설 폴더 감시를 시작하지 못했습니다: {e}");
+            }
+
             // Deeplink 설정
             app.deep_link().on_open_url(|event| {
                 println!("딥링크 URL 수신: {:?}", event.urls());
@@ -91,6 +150,16 @@ pub fn run() {
             remove_novel_project_command,
             open_novel_project_folder_command,
             save_novel_image_command,
+            export_novel_archive_command,
+            import_novel_archive_command,
+            // 리소스 관련 명령어
+            clean_unused_resources_command,
+            // 첨부 파일 관련 명령어
+            add_attachment_command,
+            list_attachments_command,
+            rename_attachment_command,
+            update_attachment_command,
+            delete_attachment_command,
             // 소설 검색 관련 명령어
             search_in_novel_command,
             // 에피소드 관련 명령어
@@ -100,17 +169,51 @@ pub fn run() {
             delete_local_episode_command,
             list_local_episode_summaries_command,
             sync_local_delta_blocks_command,
+            compact_local_episode_journal_command,
+            get_local_episode_edit_history_command,
+            undo_local_episode_edit_command,
+            redo_local_episode_edit_command,
             // 스냅숏 관련 명령어
             create_episode_snapshot_command,
             get_episode_snapshots_command,
+            restore_episode_snapshot_command,
+            update_episode_snapshot_command,
+            delete_episode_snapshot_command,
+            diff_episode_snapshot_command,
+            create_novel_snapshot_command,
+            get_novel_snapshots_command,
+            get_novel_snapshot_command,
+            restore_novel_snapshot_command,
+            update_novel_snapshot_command,
+            delete_novel_snapshot_command,
+            create_wiki_page_snapshot_command,
+            get_wiki_page_snapshots_command,
+            restore_wiki_page_snapshot_command,
+            update_wiki_page_snapshot_command,
+            delete_wiki_page_snapshot_command,
+            get_snapshot_retention_policy_command,
+            update_snapshot_retention_policy_command,
+            get_app_locale_command,
+            update_app_locale_command,
+            get_image_resource_settings_command,
+            update_image_resource_settings_command,
             // 위키 관련 명령어
             create_wiki_page_command,
             get_wiki_page_command,
             update_wiki_page_command,
             delete_wiki_page_command,
             list_wiki_page_summaries_command,
+            sync_local_wiki_page_blocks,
             // 파일 열기 관련 명령어
             take_initial_open,
+            // 임시 파일 복구 관련 명령어
+            take_recoverable_temp_files_command,
+            recover_temp_file_command,
+            discard_temp_file_command,
+            // 동기화 충돌 사본 관련 명령어
+            list_sync_conflicts_command,
+            merge_sync_conflict_command,
+            discard_sync_conflict_command,
             // 클라우드 백업 관련 명령어
             backup_cloud_episode_command
         ])
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+/// 소설에 첨부한 자료(참고 PDF, 지도, 배경 음악, 자료 스크랩 등)의 메타데이터.
+/// `resources/attachments/{id}/attachment.json`에 파일과 함께 저장됩니다.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentMetadata {
+    pub id: String,
+    pub title: String,
+    /// 첨부 폴더 안의 실제 파일 이름
+    pub file_name: String,
+    pub mime_type: String,
+    /// 파일 크기 (바이트)
+    pub size: u64,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub linked_wiki_page_ids: Vec<String>,
+    #[serde(default)]
+    pub linked_episode_ids: Vec<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// 프런트엔드에 전달하는 첨부 파일 정보 (메타데이터와 파일을 불러올 리소스 URI)
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Attachment {
+    #[serde(flatten)]
+    pub metadata: AttachmentMetadata,
+    pub uri: String,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateAttachmentOptions {
+    /// 첨부 폴더에 저장할 파일 이름. 지정하지 않으면 원본 파일의 이름을 사용합니다.
+    /// 제목을 지정하지 않으면 이 이름에서 확장자를 뺀 이름을 제목으로 사용합니다.
+    pub original_file_name: Option<String>,
+    pub title: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub linked_wiki_page_ids: Option<Vec<String>>,
+    pub linked_episode_ids: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateAttachmentData {
+    pub tags: Option<Vec<String>>,
+    pub linked_wiki_page_ids: Option<Vec<String>>,
+    pub linked_episode_ids: Option<Vec<String>>,
+}
@@ -22,7 +22,7 @@ pub struct Block {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attr: Option<serde_json::Value>,
 
-    pub order: i32,
+    pub order: f64, // 분수 순서: 두 블록 사이에 끼워 넣을 때 중간값 사용 (utils::block_order_utils)
     #[serde(rename = "updatedAt")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<String>,
@@ -52,5 +52,22 @@ pub struct DeltaBlock {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attr: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<f32>, // 클라이언트에서 순서 변경 시 f32로 올 수 있음
+    pub order: Option<f64>, // 클라이언트의 number를 정밀도 손실 없이 받음
+}
+
+/// 변경분(DeltaBlock)이 적용되지 않은 이유
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum DeltaRejectReason {
+    MissingBlock, // 수정하려는 블록이 존재하지 않음
+    Stale,        // 변경분이 만들어진 뒤(date 이후)에 블록이 이미 수정됨
+}
+
+/// 적용되지 않은 변경분 정보
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RejectedDelta {
+    pub id: String,
+    pub action: DeltaBlockAction,
+    pub reason: DeltaRejectReason,
 }
@@ -0,0 +1,58 @@
+use crate::models::block::Block;
+use serde::{Deserialize, Serialize};
+
+/// 글자 단위 diff 조각의 종류
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum TextDiffOp {
+    Equal,
+    Insert,
+    Delete,
+}
+
+/// 블록 text의 글자 단위 diff 조각 (같은 종류의 연속된 글자를 하나로 묶음)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TextDiffSegment {
+    pub op: TextDiffOp,
+    pub text: String,
+}
+
+/// 추가되거나 삭제된 블록과 그 위치 (순서대로 정렬했을 때의 인덱스)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockDiffEntry {
+    pub index: usize,
+    pub block: Block,
+}
+
+/// 위치가 바뀐 블록
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MovedBlockEntry {
+    pub id: String,
+    pub from_index: usize,
+    pub to_index: usize,
+}
+
+/// 내용(content, blockType, attr)이 바뀐 블록
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ModifiedBlockEntry {
+    pub id: String,
+    pub before: Block,
+    pub after: Block,
+    pub text_diff: Vec<TextDiffSegment>,
+}
+
+/// 두 블록 목록 사이의 블록 단위 비교 결과
+/// 위치와 내용이 모두 바뀐 블록은 `moved`와 `modified` 양쪽에 포함됩니다.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockDiff {
+    pub added: Vec<BlockDiffEntry>,
+    pub removed: Vec<BlockDiffEntry>,
+    pub moved: Vec<MovedBlockEntry>,
+    pub modified: Vec<ModifiedBlockEntry>,
+    pub unchanged_count: usize,
+}
@@ -2,6 +2,97 @@
 #[derive(Default)]
 pub struct PendingOpen(pub std::sync::Mutex<Vec<OpenedItem>>);
 
+/// Tauri State – 시작 시 발견된 남은 임시 파일 목록
+#[derive(Default)]
+pub struct PendingRecovery(pub std::sync::Mutex<LeftoverTempFiles>);
+
+/// 이전 실행에서 쓰는 도중 중단되어 남은 임시 파일
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeftoverTempFiles {
+    /// 원본 대신 복구할 수 있는 임시 파일
+    pub recoverable: Vec<RecoverableTempFile>,
+    /// 원본이 온전하거나 임시 파일이 손상되어 복구할 필요가 없는 임시 파일 경로 (삭제 여부는 사용자가 정함)
+    pub stale: Vec<String>,
+}
+
+impl LeftoverTempFiles {
+    pub fn is_empty(&self) -> bool {
+        self.recoverable.is_empty() && self.stale.is_empty()
+    }
+}
+
+/// 쓰는 도중 중단되어 남은 임시 파일 중, 원본 대신 복구할 수 있는 파일
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecoverableTempFile {
+    pub temp_path: String,
+    pub original_path: String,
+    pub temp_modified_at: String,
+    pub original_modified_at: Option<String>,
+    pub original_exists: bool, // false이면 원본이 없음, true이면 원본이 손상됨
+}
+
+/// 앱 밖에서 일어난 파일 변경의 종류
+#[derive(Clone, Copy, Debug, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExternalChangeKind {
+    Created,
+    Modified,
+    Removed,
+}
+
+/// 감시 중인 소설 폴더에서 앱 밖의 변경(동기화 서비스 등)이 감지되었을 때 프런트에 보내는 이벤트
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalChangeEvent {
+    pub novel_id: String,
+    pub item_id: Option<String>, // 소설 메타데이터 변경이면 None
+    pub change: ExternalChangeKind,
+}
+
+/// 동기화 충돌 사본이 가리키는 파일의 종류
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncConflictKind {
+    Novel,
+    Episode,
+    WikiPage,
+}
+
+/// 동기화 서비스(Dropbox, Syncthing 등)가 만든 충돌 사본 파일
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncConflictCopy {
+    pub kind: SyncConflictKind,
+    pub item_id: Option<String>, // 소설 메타데이터(.muvl)의 사본이면 None
+    pub conflict_path: String,
+    pub original_path: String,
+    pub conflict_modified_at: Option<String>,
+    pub original_modified_at: Option<String>,
+    pub original_exists: bool,
+}
+
+/// 감시 중인 소설 폴더에 새 충돌 사본이 생겼을 때 프런트에 보내는 이벤트
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncConflictDetectedEvent {
+    pub novel_id: String,
+    pub conflict: SyncConflictCopy,
+}
+
+/// 충돌 사본을 원본에 병합한 결과
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncConflictMergeResult {
+    pub kind: SyncConflictKind,
+    pub item_id: Option<String>,
+    pub merged_block_count: usize,  // 사본에서 가져온 블록 수
+    pub kept_block_count: usize,    // 원본 쪽이 더 나중에 수정되어 그대로 둔 블록 수
+    pub skipped_block_count: usize, // 원본에서 삭제된 것으로 보고 가져오지 않은 블록 수
+    pub metadata_from_copy: bool,   // 제목 등 메타데이터를 사본에서 가져왔는지 여부
+}
+
 /// 프런트에 넘겨줄 식별자 집합
 #[derive(Clone, serde::Serialize)]
 #[serde(tag = "kind", rename_all = "snake_case")]
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// 백엔드 오류 메시지와 기본 이름에 사용하는 앱 언어
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")] // "ko" | "en" | "ja"
+pub enum AppLocale {
+    #[default]
+    Ko,
+    En,
+    Ja,
+}
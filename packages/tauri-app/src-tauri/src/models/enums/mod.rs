@@ -1,3 +1,4 @@
+pub mod app_locale;
 pub mod episode_type;
 pub mod share_type;
 pub mod snapshot_reason;
@@ -1,9 +1,11 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "camelCase")] // TypeScript enum string values와 일치
 pub enum SnapshotReason {
     Merge,
     Manual,
     Autosave,
+    Restore, // 스냅샷 복원 직전에 자동 생성
+    Update,  // 위키 페이지 정보 수정 직전에 자동 생성
 }
@@ -1,4 +1,4 @@
-use crate::models::block::Block;
+use crate::models::block::{Block, RejectedDelta};
 use crate::models::enums::episode_type::EpisodeType;
 use crate::models::enums::share_type::ShareType;
 use serde::{Deserialize, Serialize};
@@ -32,8 +32,13 @@ pub struct LocalEpisodeData {
     #[serde(rename = "updatedAt")]
     pub updated_at: String,
 
-    #[serde(default)] // 이전 데이터에 blocks 필드가 없을 수 있으므로 기본값(빈 Vec) 사용
+    // 형식 버전이 없던 에피소드 파일은 format_migration에서 채우지만, 소설 스냅샷에 담긴 이전 데이터를 위해 기본값 유지
+    #[serde(default)]
     pub blocks: Vec<Block>,
+
+    /// 블록 내용이 바뀔 때마다 1씩 증가하는 리비전 (낙관적 동시성 제어용)
+    #[serde(default)]
+    pub revision: u64,
 }
 
 // --- 나머지 코드 (LocalEpisodeDataResponse, CreateLocalEpisodeOptions 등) ---
@@ -74,9 +79,46 @@ pub struct LocalEpisodeDataResponse {
     #[serde(rename = "updatedAt")]
     pub updated_at: String,
     pub blocks: Vec<Block>,
+    #[serde(default)] // 클라우드 백업 데이터 등에는 없을 수 있음
+    pub revision: u64,
     pub novel: EpisodeParentNovelContext,
 }
 
+/// 블록 변경분 동기화 결과
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncResult {
+    pub revision: u64, // 동기화 후 에피소드 리비전
+    #[serde(rename = "contentLength")]
+    pub content_length: i32,
+    pub applied_count: usize,
+    pub rejected: Vec<RejectedDelta>,
+    /// 클라이언트가 보낸 기준 리비전이 현재 리비전보다 오래되었는지 여부
+    pub stale_base: bool,
+    /// 블록 order를 다시 매겼는지 여부. true이면 클라이언트의 order가 더 이상 유효하지 않음
+    pub rebalanced: bool,
+}
+
+/// 실행 취소/다시 실행 가능 여부
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EditHistoryState {
+    pub can_undo: bool,
+    pub can_redo: bool,
+}
+
+/// 실행 취소/다시 실행 결과. 에디터는 `blocks`로 문서를 다시 그립니다.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EditHistoryResult {
+    pub applied: bool, // 되돌리거나 다시 적용할 편집이 있었는지 여부
+    pub revision: u64,
+    pub content_length: i32,
+    pub blocks: Vec<Block>,
+    pub can_undo: bool,
+    pub can_redo: bool,
+}
+
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateLocalEpisodeOptions {
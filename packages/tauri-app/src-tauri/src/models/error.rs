@@ -0,0 +1,189 @@
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+/// 오류에 덧붙이는 정보. 프런트엔드는 `message`를 그대로 보여주고, `path`/`id`로 대상을 찾을 수 있습니다.
+#[derive(Debug, Clone, Default)]
+pub struct ErrorDetail {
+    pub message: String,
+    pub path: Option<String>,
+    pub id: Option<String>,
+}
+
+/// 저장소, 리포지토리, 커맨드가 공통으로 사용하는 오류 타입
+///
+/// 프런트엔드에는 `{ code, message, path?, id? }` 형태로 직렬화됩니다.
+/// `code`는 오류 종류를 구분하기 위한 고정 문자열이므로 바꾸지 않습니다.
+#[derive(Debug, Clone)]
+pub enum MuvelError {
+    /// 파일, 소설, 에피소드 등 대상이 존재하지 않음
+    NotFound(ErrorDetail),
+    /// 파일 시스템 권한 부족
+    PermissionDenied(ErrorDetail),
+    /// 같은 대상이 이미 존재함
+    AlreadyExists(ErrorDetail),
+    /// 요청 값이 잘못됨
+    InvalidInput(ErrorDetail),
+    /// 파일 내용을 해석할 수 없음 (JSON 손상 등)
+    Parse(ErrorDetail),
+    /// 다른 곳에서 먼저 변경되어 요청을 처리할 수 없음
+    Conflict(ErrorDetail),
+    /// 더 새로운 버전의 앱에서 만든 파일이라 읽을 수 없음
+    UnsupportedVersion(ErrorDetail),
+    /// 그 밖의 입출력 오류
+    Io(ErrorDetail),
+    /// 분류되지 않은 내부 오류
+    Internal(ErrorDetail),
+}
+
+pub type MuvelResult<T> = Result<T, MuvelError>;
+
+impl MuvelError {
+    fn detail(message: impl Into<String>) -> ErrorDetail {
+        ErrorDetail {
+            message: message.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::NotFound(Self::detail(message))
+    }
+
+    pub fn invalid_input(message: impl Into<String>) -> Self {
+        Self::InvalidInput(Self::detail(message))
+    }
+
+    pub fn already_exists(message: impl Into<String>) -> Self {
+        Self::AlreadyExists(Self::detail(message))
+    }
+
+    pub fn parse(message: impl Into<String>) -> Self {
+        Self::Parse(Self::detail(message))
+    }
+
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self::Conflict(Self::detail(message))
+    }
+
+    pub fn unsupported_version(message: impl Into<String>) -> Self {
+        Self::UnsupportedVersion(Self::detail(message))
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::Internal(Self::detail(message))
+    }
+
+    /// 입출력 오류의 종류에 맞는 오류를 만듭니다. 메시지 뒤에 원인 오류가 덧붙습니다.
+    pub fn io(message: impl Into<String>, error: &io::Error) -> Self {
+        let detail = Self::detail(format!("{}: {}", message.into(), error));
+        match error.kind() {
+            io::ErrorKind::NotFound => Self::NotFound(detail),
+            io::ErrorKind::PermissionDenied => Self::PermissionDenied(detail),
+            io::ErrorKind::AlreadyExists => Self::AlreadyExists(detail),
+            _ => Self::Io(detail),
+        }
+    }
+
+    /// 프런트엔드가 오류 종류를 구분하는 데 사용하는 고정 코드
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::NotFound(_) => "NOT_FOUND",
+            Self::PermissionDenied(_) => "PERMISSION_DENIED",
+            Self::AlreadyExists(_) => "ALREADY_EXISTS",
+            Self::InvalidInput(_) => "INVALID_INPUT",
+            Self::Parse(_) => "PARSE_ERROR",
+            Self::Conflict(_) => "CONFLICT",
+            Self::UnsupportedVersion(_) => "UNSUPPORTED_VERSION",
+            Self::Io(_) => "IO_ERROR",
+            Self::Internal(_) => "INTERNAL",
+        }
+    }
+
+    pub fn detail_ref(&self) -> &ErrorDetail {
+        match self {
+            Self::NotFound(d)
+            | Self::PermissionDenied(d)
+            | Self::AlreadyExists(d)
+            | Self::InvalidInput(d)
+            | Self::Parse(d)
+            | Self::Conflict(d)
+            | Self::UnsupportedVersion(d)
+            | Self::Io(d)
+            | Self::Internal(d) => d,
+        }
+    }
+
+    fn detail_mut(&mut self) -> &mut ErrorDetail {
+        match self {
+            Self::NotFound(d)
+            | Self::PermissionDenied(d)
+            | Self::AlreadyExists(d)
+            | Self::InvalidInput(d)
+            | Self::Parse(d)
+            | Self::Conflict(d)
+            | Self::UnsupportedVersion(d)
+            | Self::Io(d)
+            | Self::Internal(d) => d,
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.detail_ref().message
+    }
+
+    /// 오류와 관련된 파일 경로를 덧붙입니다.
+    pub fn with_path(mut self, path: impl AsRef<Path>) -> Self {
+        self.detail_mut().path = Some(path.as_ref().to_string_lossy().into_owned());
+        self
+    }
+
+    /// 오류와 관련된 항목 ID(소설, 에피소드, 스냅샷 등)를 덧붙입니다.
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.detail_mut().id = Some(id.into());
+        self
+    }
+
+    /// 메시지 뒤에 원인 오류를 덧붙입니다.
+    pub fn caused_by(mut self, cause: impl fmt::Display) -> Self {
+        let detail = self.detail_mut();
+        detail.message = format!("{}: {}", detail.message, cause);
+        self
+    }
+
+    /// 종류와 정보는 유지한 채 메시지 앞에 상황 설명을 덧붙입니다.
+    pub fn context(mut self, context: impl fmt::Display) -> Self {
+        let detail = self.detail_mut();
+        detail.message = format!("{}: {}", context, detail.message);
+        self
+    }
+}
+
+impl fmt::Display for MuvelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl std::error::Error for MuvelError {}
+
+/// 분류되지 않은 문자열 오류는 내부 오류로 취급합니다.
+impl From<String> for MuvelError {
+    fn from(message: String) -> Self {
+        Self::internal(message)
+    }
+}
+
+impl Serialize for MuvelError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let detail = self.detail_ref();
+        let mut state = serializer.serialize_struct("MuvelError", 4)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("message", &detail.message)?;
+        state.serialize_field("path", &detail.path)?;
+        state.serialize_field("id", &detail.id)?;
+        state.end()
+    }
+}
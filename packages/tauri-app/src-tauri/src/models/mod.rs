@@ -1,9 +1,14 @@
+pub mod attachment;
 pub mod block;
+pub mod block_diff;
 pub mod commons;
 pub mod enums;
 pub mod episode;
+pub mod error;
 pub mod index;
 pub mod novel;
+pub mod resource;
 pub mod search_results;
+pub mod settings;
 pub mod snapshot;
 pub mod wiki_page;
@@ -0,0 +1,14 @@
+use serde::Serialize;
+
+/// 사용하지 않는 이미지 리소스 정리 결과
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceCleanupResult {
+    /// 삭제한 파일의 `resources` 기준 경로 (`/` 구분). dry_run이면 삭제할 파일 목록
+    pub removed_files: Vec<String>,
+    /// 삭제로 확보한 용량 (바이트)
+    pub freed_bytes: u64,
+    /// 참조되지 않지만 최근에 수정되어 남겨 둔 파일 수
+    pub skipped_recent_count: usize,
+    pub dry_run: bool,
+}
@@ -54,7 +54,7 @@ pub struct NovelSearchEpisodeBlockItem {
     pub item_type: NovelSearchItemType,
     pub content: String,    // 스니펫
     pub block_type: String, // EpisodeBlockType
-    pub order: i32,         // Block order
+    pub order: f64,         // Block order
     pub episode_id: String,
     pub episode_name: String,
     pub episode_number: f32, // Episode order
@@ -68,7 +68,7 @@ pub struct NovelSearchWikiBlockItem {
     pub item_type: NovelSearchItemType,
     pub content: String,    // 스니펫
     pub block_type: String, // WikiBlockType
-    pub order: i32,         // Block order
+    pub order: f64,         // Block order
     pub wiki_page_id: String,
     pub wiki_page_name: String,
 }
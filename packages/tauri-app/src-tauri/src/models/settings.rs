@@ -0,0 +1,139 @@
+use crate::models::enums::app_locale::AppLocale;
+use crate::models::enums::snapshot_reason::SnapshotReason;
+use serde::{Deserialize, Serialize};
+
+/// 한 종류(SnapshotReason)의 스냅샷에 적용되는 보관 규칙
+/// 모든 값이 None이면 해당 종류의 스냅샷은 삭제되지 않습니다.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotRetentionRule {
+    /// 최신 N개는 항상 보관 (None이면 개수 제한 없음)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keep_last: Option<usize>,
+    /// 생성된 지 이 일수가 지난 스냅샷은 하루에 하나(그날의 마지막 스냅샷)만 보관
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keep_daily_after_days: Option<i64>,
+    /// 생성된 지 이 일수가 지난 스냅샷은 삭제
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_age_days: Option<i64>,
+}
+
+/// SnapshotReason별 스냅샷 보관 정책
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotRetentionPolicy {
+    #[serde(default)]
+    pub manual: SnapshotRetentionRule,
+    #[serde(default)]
+    pub merge: SnapshotRetentionRule,
+    #[serde(default)]
+    pub autosave: SnapshotRetentionRule,
+    #[serde(default)]
+    pub restore: SnapshotRetentionRule,
+    #[serde(default = "default_update_rule")]
+    pub update: SnapshotRetentionRule,
+}
+
+fn default_update_rule() -> SnapshotRetentionRule {
+    // 위키 페이지 수정 직전 스냅샷은 자동 저장과 같은 규칙
+    SnapshotRetentionRule {
+        keep_last: Some(50),
+        keep_daily_after_days: Some(7),
+        max_age_days: None,
+    }
+}
+
+impl Default for SnapshotRetentionPolicy {
+    fn default() -> Self {
+        Self {
+            // 수동/병합 스냅샷은 모두 보관
+            manual: SnapshotRetentionRule::default(),
+            merge: SnapshotRetentionRule::default(),
+            // 자동 저장은 최근 50개 + 일주일이 지난 것은 하루에 하나씩
+            autosave: SnapshotRetentionRule {
+                keep_last: Some(50),
+                keep_daily_after_days: Some(7),
+                max_age_days: None,
+            },
+            // 복원 직전 안전 스냅샷은 최근 20개
+            restore: SnapshotRetentionRule {
+                keep_last: Some(20),
+                keep_daily_after_days: None,
+                max_age_days: None,
+            },
+            update: default_update_rule(),
+        }
+    }
+}
+
+impl SnapshotRetentionPolicy {
+    pub fn rule_for(&self, reason: &SnapshotReason) -> &SnapshotRetentionRule {
+        match reason {
+            SnapshotReason::Manual => &self.manual,
+            SnapshotReason::Merge => &self.merge,
+            SnapshotReason::Autosave => &self.autosave,
+            SnapshotReason::Restore => &self.restore,
+            SnapshotReason::Update => &self.update,
+        }
+    }
+}
+
+fn default_compress_snapshot_blocks() -> bool {
+    true
+}
+
+fn default_image_max_dimension() -> Option<u32> {
+    Some(2560)
+}
+
+fn default_image_jpeg_quality() -> u8 {
+    85
+}
+
+/// 소설에 이미지를 저장할 때의 처리 설정
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageResourceSettings {
+    /// 가로나 세로가 이 값(px)보다 큰 이미지는 줄여서 다시 인코딩 (None이면 원본 그대로 저장)
+    #[serde(default = "default_image_max_dimension")]
+    pub max_dimension: Option<u32>,
+    /// 줄인 이미지를 JPEG로 다시 인코딩할 때의 품질 (1~100)
+    #[serde(default = "default_image_jpeg_quality")]
+    pub jpeg_quality: u8,
+}
+
+impl Default for ImageResourceSettings {
+    fn default() -> Self {
+        Self {
+            max_dimension: default_image_max_dimension(),
+            jpeg_quality: default_image_jpeg_quality(),
+        }
+    }
+}
+
+/// 앱 전역 설정 (app_local_data_dir/app_settings.json)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AppSettings {
+    #[serde(default)]
+    pub snapshot_retention: SnapshotRetentionPolicy,
+    /// 스냅샷 블록 저장소에 새 블록을 gzip으로 압축하여 저장할지 여부
+    #[serde(default = "default_compress_snapshot_blocks")]
+    pub compress_snapshot_blocks: bool,
+    /// 백엔드 오류 메시지와 기본 이름(새 에피소드 제목 등)에 사용할 언어
+    #[serde(default)]
+    pub locale: AppLocale,
+    #[serde(default)]
+    pub image_resources: ImageResourceSettings,
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            snapshot_retention: SnapshotRetentionPolicy::default(),
+            compress_snapshot_blocks: default_compress_snapshot_blocks(),
+            locale: AppLocale::default(),
+            image_resources: ImageResourceSettings::default(),
+        }
+    }
+}
@@ -1,5 +1,8 @@
 use crate::models::block::Block; // EpisodeBlock[]은 Vec<Block>으로 표현
 use crate::models::enums::snapshot_reason::SnapshotReason;
+use crate::models::episode::LocalEpisodeData;
+use crate::models::novel::Novel;
+use crate::models::wiki_page::WikiPage;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -11,4 +14,99 @@ pub struct EpisodeSnapshot {
     pub blocks: Vec<Block>,     // 스냅샷 시점의 에피소드 블록 전체
     #[serde(rename = "createdAt")]
     pub created_at: String, // 스냅샷 생성 시각 (ISO 8601)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>, // 사용자가 붙인 이름 (예: "편집자 제출본")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>, // 사용자 메모
+    #[serde(default)]
+    pub pinned: bool, // 고정된 스냅샷은 보관 정책에 의해 삭제되지 않음
+}
+
+/// 스냅샷 파일에서 정리(pruning) 판단에 필요한 필드만 꺼내기 위한 구조체 (파일은 전체를 읽음)
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EpisodeSnapshotHeader {
+    pub id: String,
+    pub reason: SnapshotReason,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+    #[serde(default)]
+    pub pinned: bool,
+}
+
+/// 위키 페이지 스냅샷: 특정 시점의 페이지 메타데이터(제목, 요약, 속성, 태그 등)와 블록
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WikiPageSnapshot {
+    pub id: String,
+    pub wiki_page_id: String,
+    pub reason: SnapshotReason,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(default)]
+    pub pinned: bool,
+    pub page: WikiPage, // 스냅샷 시점의 위키 페이지 전체 (blocks 포함)
+}
+
+/// 소설 전체 스냅샷: 특정 시점의 .muvl 메타데이터, 모든 에피소드와 위키 페이지
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NovelSnapshot {
+    pub id: String,
+    pub novel_id: String,
+    pub reason: SnapshotReason,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(default)]
+    pub pinned: bool, // 고정된 스냅샷은 보관 정책에 의해 삭제되지 않음
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+    pub novel: Novel,
+    pub episodes: Vec<LocalEpisodeData>,
+    pub wiki_pages: Vec<WikiPage>,
+}
+
+/// 소설 스냅샷에 포함된 항목(에피소드/위키 페이지)의 간단한 정보
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NovelSnapshotItemSummary {
+    pub id: String,
+    pub title: String,
+}
+
+/// 소설 스냅샷 목록 표시용 요약 (블록 내용은 포함하지 않음)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NovelSnapshotSummary {
+    pub id: String,
+    pub novel_id: String,
+    pub reason: SnapshotReason,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    pub pinned: bool,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+    pub novel_title: String,
+    pub episodes: Vec<NovelSnapshotItemSummary>,
+    pub wiki_pages: Vec<NovelSnapshotItemSummary>,
+}
+
+/// 소설 스냅샷에서 일부 항목만 복원할 때 사용하는 옵션
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreNovelSnapshotOptions {
+    #[serde(default)]
+    pub novel_metadata: bool, // .muvl 메타데이터(제목, 설명, 태그 등) 복원 여부
+    #[serde(default)]
+    pub episode_ids: Vec<String>,
+    #[serde(default)]
+    pub wiki_page_ids: Vec<String>,
 }
@@ -1,4 +1,4 @@
-use crate::models::block::Block;
+use crate::models::block::{Block, RejectedDelta};
 pub(crate) use crate::models::enums::wiki_page_category::WikiPageCategory;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap; // WikiPage가 블록을 가지므로 Block 모델 사용
@@ -57,3 +57,14 @@ pub struct UpdateWikiPageData {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attributes: Option<HashMap<String, String>>,
 }
+
+/// 위키 페이지 블록 변경분 동기화 결과 (위키 페이지에는 리비전이 없음)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WikiPageSyncResult {
+    pub applied_count: usize,
+    /// 변경분 생성 이후 다른 곳에서 먼저 수정되어 적용하지 않은 변경분
+    pub rejected: Vec<RejectedDelta>,
+    /// 블록 order를 다시 매겼는지 여부. true이면 클라이언트의 order가 더 이상 유효하지 않음
+    pub rebalanced: bool,
+}
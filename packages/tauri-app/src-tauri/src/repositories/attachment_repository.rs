@@ -0,0 +1,270 @@
+use crate::i18n::{FileKind, Message};
+use crate::models::error::{MuvelError, MuvelResult};
+use chrono::Utc;
+use std::path::Path;
+use tauri::AppHandle;
+use uuid::Uuid;
+
+use crate::models::attachment::{
+    Attachment, AttachmentMetadata, CreateAttachmentOptions, UpdateAttachmentData,
+};
+use crate::storage::{attachment_io, index_resolver, item_index_manager, resource_uri};
+
+/// 첨부 ID는 폴더 이름으로 쓰이므로, 폴더 밖을 가리킬 수 없도록 UUID만 허용합니다.
+fn validate_attachment_id(attachment_id: &str) -> MuvelResult<()> {
+    Uuid::parse_str(attachment_id).map(|_| ()).map_err(|_| {
+        MuvelError::not_found(Message::FileNotFound(FileKind::Attachment)).with_id(attachment_id)
+    })
+}
+
+/// 제목 앞뒤 공백을 지웁니다. 비어 있으면 오류를 반환합니다.
+fn normalize_title(title: &str) -> MuvelResult<String> {
+    let title = title.trim();
+    if title.is_empty() {
+        return Err(MuvelError::invalid_input(Message::InvalidAttachmentName));
+    }
+    Ok(title.to_string())
+}
+
+/// 확장자를 뺀 파일 이름 (제목을 지정하지 않았을 때 사용)
+fn file_stem_of(file_name: &str) -> &str {
+    Path::new(file_name)
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or(file_name)
+}
+
+/// 새 제목으로 파일 이름을 만듭니다. 원래 확장자는 유지합니다.
+fn file_name_for_title(title: &str, current_file_name: &str) -> MuvelResult<String> {
+    let extension = Path::new(current_file_name)
+        .extension()
+        .and_then(|ext| ext.to_str());
+    let name = match extension {
+        Some(ext)
+            if !title
+                .to_lowercase()
+                .ends_with(&format!(".{}", ext.to_lowercase())) =>
+        {
+            format!("{}.{}", title, ext)
+        }
+        _ => title.to_string(),
+    };
+    attachment_io::sanitize_file_name(&name)
+        .ok_or_else(|| MuvelError::invalid_input(Message::InvalidAttachmentName))
+}
+
+/// 중복을 제거합니다. (처음 나온 순서 유지)
+fn dedup_ids(ids: Vec<String>) -> Vec<String> {
+    let mut deduped: Vec<String> = Vec::with_capacity(ids.len());
+    for id in ids {
+        if !deduped.contains(&id) {
+            deduped.push(id);
+        }
+    }
+    deduped
+}
+
+fn to_attachment(novel_id: &str, metadata: AttachmentMetadata) -> Attachment {
+    let relative_path = attachment_io::attachment_relative_path(&metadata.id, &metadata.file_name);
+    Attachment {
+        uri: resource_uri::resource_uri_for(novel_id, &relative_path).unwrap_or_default(),
+        metadata,
+    }
+}
+
+pub struct AttachmentRepository<'a> {
+    app_handle: &'a AppHandle,
+}
+
+impl<'a> AttachmentRepository<'a> {
+    pub fn new(app_handle: &'a AppHandle) -> Self {
+        Self { app_handle }
+    }
+
+    /// `source_path`의 파일을 소설의 `resources/attachments`에 복사해 첨부하고, 메타데이터와 함께 저장합니다.
+    pub fn add_attachment(
+        &self,
+        novel_id: &str,
+        source_path: &Path,
+        options: CreateAttachmentOptions,
+    ) -> MuvelResult<Attachment> {
+        let novel_root_path = index_resolver::resolve_novel_root(self.app_handle, novel_id)?;
+        let original_file_name = match options.original_file_name.as_deref() {
+            Some(name) => name.to_string(),
+            None => source_path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+        };
+        let file_name = attachment_io::sanitize_file_name(&original_file_name)
+            .ok_or_else(|| MuvelError::invalid_input(Message::InvalidAttachmentName))?;
+        let title = normalize_title(
+            options
+                .title
+                .as_deref()
+                .unwrap_or_else(|| file_stem_of(&file_name)),
+        )?;
+        let linked_wiki_page_ids = self.validate_links(
+            novel_id,
+            options.linked_wiki_page_ids.unwrap_or_default(),
+            "wiki_page",
+        )?;
+        let linked_episode_ids = self.validate_links(
+            novel_id,
+            options.linked_episode_ids.unwrap_or_default(),
+            "episode",
+        )?;
+
+        let attachment_id = Uuid::new_v4().to_string();
+        let current_time_iso = Utc::now().to_rfc3339();
+        // 실패하면 메타데이터 없이 파일만 남지 않도록 첨부 폴더를 정리
+        let remove_attachment_dir = || {
+            if let Err(cleanup_error) =
+                attachment_io::delete_attachment_dir(&novel_root_path, &attachment_id)
+            {
+                eprintln!(
+                    "첨부에 실패한 파일을 지우지 못했습니다 ({}): {}",
+                    attachment_id, cleanup_error
+                );
+            }
+        };
+        let size = attachment_io::copy_attachment_file(
+            &novel_root_path,
+            &attachment_id,
+            &file_name,
+            source_path,
+        )
+        .inspect_err(|_| remove_attachment_dir())?;
+        let metadata = AttachmentMetadata {
+            id: attachment_id.clone(),
+            title,
+            mime_type: resource_uri::resource_mime_type(Path::new(&file_name)).to_string(),
+            file_name,
+            size,
+            tags: options.tags.unwrap_or_default(),
+            linked_wiki_page_ids,
+            linked_episode_ids,
+            created_at: current_time_iso.clone(),
+            updated_at: current_time_iso,
+        };
+
+        attachment_io::write_attachment_metadata(&novel_root_path, &metadata)
+            .inspect_err(|_| remove_attachment_dir())?;
+        Ok(to_attachment(novel_id, metadata))
+    }
+
+    /// 소설의 첨부 파일 목록을 가져옵니다.
+    /// `linked_item_id`를 지정하면 그 에피소드나 위키 페이지에 연결된 첨부 파일만 반환합니다.
+    pub fn list_attachments(
+        &self,
+        novel_id: &str,
+        linked_item_id: Option<&str>,
+    ) -> MuvelResult<Vec<Attachment>> {
+        let novel_root_path = index_resolver::resolve_novel_root(self.app_handle, novel_id)?;
+        Ok(attachment_io::list_attachment_metadata(&novel_root_path)?
+            .into_iter()
+            .filter(|metadata| {
+                linked_item_id.map_or(true, |item_id| {
+                    metadata.linked_wiki_page_ids.iter().any(|id| id == item_id)
+                        || metadata.linked_episode_ids.iter().any(|id| id == item_id)
+                })
+            })
+            .map(|metadata| to_attachment(novel_id, metadata))
+            .collect())
+    }
+
+    /// 첨부 파일의 제목을 바꾸고, 실제 파일 이름도 새 제목에 맞춥니다. (확장자 유지)
+    pub fn rename_attachment(
+        &self,
+        novel_id: &str,
+        attachment_id: &str,
+        new_title: &str,
+    ) -> MuvelResult<Attachment> {
+        validate_attachment_id(attachment_id)?;
+        let novel_root_path = index_resolver::resolve_novel_root(self.app_handle, novel_id)?;
+        let mut metadata =
+            attachment_io::read_attachment_metadata(&novel_root_path, attachment_id)?;
+        let title = normalize_title(new_title)?;
+        let new_file_name = file_name_for_title(&title, &metadata.file_name)?;
+        let old_file_name = std::mem::replace(&mut metadata.file_name, new_file_name);
+
+        attachment_io::rename_attachment_file(
+            &novel_root_path,
+            attachment_id,
+            &old_file_name,
+            &metadata.file_name,
+        )?;
+        metadata.title = title;
+        metadata.updated_at = Utc::now().to_rfc3339();
+        if let Err(e) = attachment_io::write_attachment_metadata(&novel_root_path, &metadata) {
+            // 메타데이터와 실제 파일 이름이 어긋나지 않도록 되돌림
+            if let Err(rollback_error) = attachment_io::rename_attachment_file(
+                &novel_root_path,
+                attachment_id,
+                &metadata.file_name,
+                &old_file_name,
+            ) {
+                eprintln!(
+                    "첨부 파일 이름을 되돌리지 못했습니다 ({}): {}",
+                    attachment_id, rollback_error
+                );
+            }
+            return Err(e);
+        }
+        Ok(to_attachment(novel_id, metadata))
+    }
+
+    /// 첨부 파일의 태그와 연결된 위키 페이지, 에피소드를 바꿉니다.
+    pub fn update_attachment(
+        &self,
+        novel_id: &str,
+        attachment_id: &str,
+        data: UpdateAttachmentData,
+    ) -> MuvelResult<Attachment> {
+        validate_attachment_id(attachment_id)?;
+        let novel_root_path = index_resolver::resolve_novel_root(self.app_handle, novel_id)?;
+        let mut metadata =
+            attachment_io::read_attachment_metadata(&novel_root_path, attachment_id)?;
+
+        if let Some(tags) = data.tags {
+            metadata.tags = tags;
+        }
+        if let Some(ids) = data.linked_wiki_page_ids {
+            metadata.linked_wiki_page_ids = self.validate_links(novel_id, ids, "wiki_page")?;
+        }
+        if let Some(ids) = data.linked_episode_ids {
+            metadata.linked_episode_ids = self.validate_links(novel_id, ids, "episode")?;
+        }
+        metadata.updated_at = Utc::now().to_rfc3339();
+        attachment_io::write_attachment_metadata(&novel_root_path, &metadata)?;
+        Ok(to_attachment(novel_id, metadata))
+    }
+
+    /// 첨부 파일과 메타데이터를 삭제합니다.
+    pub fn delete_attachment(&self, novel_id: &str, attachment_id: &str) -> MuvelResult<()> {
+        validate_attachment_id(attachment_id)?;
+        let novel_root_path = index_resolver::resolve_novel_root(self.app_handle, novel_id)?;
+        attachment_io::delete_attachment_dir(&novel_root_path, attachment_id)
+    }
+
+    /// 연결할 아이템이 이 소설의 에피소드나 위키 페이지(`item_type`)인지 확인하고, 중복을 제거해 반환합니다.
+    fn validate_links(
+        &self,
+        novel_id: &str,
+        item_ids: Vec<String>,
+        item_type: &str,
+    ) -> MuvelResult<Vec<String>> {
+        let item_ids = dedup_ids(item_ids);
+        for item_id in &item_ids {
+            let entry = item_index_manager::get_item_entry(self.app_handle, item_id)?;
+            let is_valid = entry
+                .is_some_and(|entry| entry.novel_id == novel_id && entry.item_type == item_type);
+            if !is_valid {
+                return Err(MuvelError::invalid_input(Message::AttachmentLinkInvalid {
+                    item_id: item_id.clone(),
+                }));
+            }
+        }
+        Ok(item_ids)
+    }
+}
@@ -1,18 +1,41 @@
+use crate::i18n::Message;
+use crate::models::error::{MuvelError, MuvelResult};
 use chrono::Utc;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tauri::AppHandle;
 use uuid::Uuid;
 
-use crate::models::block::DeltaBlock;
+use crate::models::block::{DeltaBlock, RejectedDelta};
 use crate::models::enums::episode_type::EpisodeType;
 use crate::models::episode::{
-    CreateLocalEpisodeOptions, EpisodeMetadataUpdatePayload, EpisodeParentNovelContext,
-    LocalEpisodeData, LocalEpisodeDataResponse, UpdateLocalEpisodeMetadata,
+    CreateLocalEpisodeOptions, EditHistoryResult, EditHistoryState, EpisodeMetadataUpdatePayload,
+    EpisodeParentNovelContext, LocalEpisodeData, LocalEpisodeDataResponse, SyncResult,
+    UpdateLocalEpisodeMetadata,
 };
 use crate::models::novel::EpisodeSummaryData;
 // models/novel.rs로 이동된 타입들
-use crate::storage::{episode_io, index_manager, item_index_manager, novel_io};
-use crate::utils::delta_block_utils;
+use crate::repositories::snapshot_repository::SnapshotRepository;
+use crate::storage::edit_history_io::{
+    self, EditHistoryEntry, EditHistoryRecord, EpisodeEditHistory,
+};
+use crate::storage::episode_journal_io::{self, EpisodeJournalEntry};
+use crate::storage::{
+    attachment_io, episode_io, index_manager, index_resolver, item_index_manager, novel_io,
+    snapshot_io,
+};
+use crate::utils::{block_order_utils, delta_block_utils};
+
+/// 저널 항목이 이 개수 이상 쌓이면 에피소드 파일에 합칩니다.
+const JOURNAL_COMPACTION_THRESHOLD: usize = 100;
+
+/// 변경분을 병합해 저널에 기록한 결과
+struct AppliedDeltas {
+    deltas: Vec<DeltaBlock>, // 실제로 적용된 변경분
+    applied_count: usize,
+    rejected: Vec<RejectedDelta>,
+    rebalanced: bool,
+    compacted: bool, // 저널을 에피소드 파일에 합쳤는지 여부
+}
 
 pub struct EpisodeRepository<'a> {
     app_handle: &'a AppHandle,
@@ -23,42 +46,13 @@ impl<'a> EpisodeRepository<'a> {
         Self { app_handle }
     }
 
-    fn get_novel_root_path_and_id(
-        &self,
-        id_param: &str,
-        is_novel_id_param: bool,
-    ) -> Result<(PathBuf, String), String> {
-        let novel_id = if is_novel_id_param {
-            id_param.to_string()
-        } else {
-            item_index_manager::get_item_entry(self.app_handle, id_param)?
-                .ok_or_else(|| {
-                    format!(
-                        "아이템 인덱스에서 ID {} 의 부모 소설 정보를 찾을 수 없습니다.",
-                        id_param
-                    )
-                })?
-                .novel_id
-        };
-
-        let novel_entry = index_manager::get_novel_entry(self.app_handle, &novel_id)?
-            .ok_or_else(|| format!("소설 ID {} 를 인덱스에서 찾을 수 없습니다.", novel_id))?;
-        let novel_root_path_str = novel_entry.path.ok_or_else(|| {
-            format!(
-                "소설 ID {} 에 대한 경로 정보가 인덱스에 없습니다.",
-                novel_id
-            )
-        })?;
-
-        Ok((PathBuf::from(novel_root_path_str), novel_id))
-    }
-
     pub fn create_episode(
         &self,
         novel_id_str: &str,
         options: CreateLocalEpisodeOptions,
-    ) -> Result<LocalEpisodeDataResponse, String> {
-        let (novel_root_path, novel_id) = self.get_novel_root_path_and_id(novel_id_str, true)?;
+    ) -> MuvelResult<LocalEpisodeDataResponse> {
+        let novel_root_path = index_resolver::resolve_novel_root(self.app_handle, novel_id_str)?;
+        let novel_id = novel_id_str.to_string();
         let (parent_novel_meta, _) = novel_io::read_novel_metadata_with_path(&novel_root_path)?;
 
         let episode_id = Uuid::new_v4().to_string();
@@ -81,9 +75,12 @@ impl<'a> EpisodeRepository<'a> {
         let episode_data_for_file = LocalEpisodeData {
             id: episode_id.clone(),
             novel_id: novel_id.clone(),
-            title: options
-                .title
-                .unwrap_or_else(|| format!("새 에피소드 {}", new_order.round())),
+            title: options.title.unwrap_or_else(|| {
+                Message::DefaultEpisodeTitle {
+                    order: new_order.round() as i64,
+                }
+                .text()
+            }),
             description: options.description.unwrap_or_default(),
             author_comment: None,
             content_length: 0, // 초기 contentLength는 0
@@ -94,6 +91,7 @@ impl<'a> EpisodeRepository<'a> {
             created_at: current_time_iso.clone(),
             updated_at: current_time_iso.clone(),
             blocks: Vec::new(),
+            revision: 0,
         };
 
         episode_io::write_episode_content(&novel_root_path, &episode_id, &episode_data_for_file)?;
@@ -119,6 +117,7 @@ impl<'a> EpisodeRepository<'a> {
             created_at: episode_data_for_file.created_at,
             updated_at: episode_data_for_file.updated_at,
             blocks: episode_data_for_file.blocks,
+            revision: episode_data_for_file.revision,
             novel: EpisodeParentNovelContext {
                 id: novel_id,
                 share: parent_novel_meta.share,
@@ -127,8 +126,9 @@ impl<'a> EpisodeRepository<'a> {
         })
     }
 
-    pub fn get_episode_data(&self, episode_id: &str) -> Result<LocalEpisodeDataResponse, String> {
-        let (novel_root_path, novel_id) = self.get_novel_root_path_and_id(episode_id, false)?;
+    pub fn get_episode_data(&self, episode_id: &str) -> MuvelResult<LocalEpisodeDataResponse> {
+        let (novel_root_path, novel_id) =
+            index_resolver::resolve_item_novel(self.app_handle, episode_id)?;
         let (parent_novel_meta, _) = novel_io::read_novel_metadata_with_path(&novel_root_path)?;
         let episode_data_core = episode_io::read_episode_content(&novel_root_path, episode_id)?;
         Ok(LocalEpisodeDataResponse {
@@ -145,6 +145,7 @@ impl<'a> EpisodeRepository<'a> {
             created_at: episode_data_core.created_at,
             updated_at: episode_data_core.updated_at,
             blocks: episode_data_core.blocks,
+            revision: episode_data_core.revision,
             novel: EpisodeParentNovelContext {
                 id: novel_id,
                 share: parent_novel_meta.share,
@@ -157,8 +158,9 @@ impl<'a> EpisodeRepository<'a> {
         &self,
         episode_id: &str,
         metadata_update: UpdateLocalEpisodeMetadata,
-    ) -> Result<EpisodeSummaryData, String> {
-        let (novel_root_path, novel_id) = self.get_novel_root_path_and_id(episode_id, false)?;
+    ) -> MuvelResult<EpisodeSummaryData> {
+        let (novel_root_path, novel_id) =
+            index_resolver::resolve_item_novel(self.app_handle, episode_id)?;
         let mut episode_data = episode_io::read_episode_content(&novel_root_path, episode_id)?;
         let mut changed = false;
         if let Some(title) = metadata_update.title {
@@ -217,7 +219,7 @@ impl<'a> EpisodeRepository<'a> {
     pub fn batch_update_episode_metadata(
         &self,
         diffs: Vec<EpisodeMetadataUpdatePayload>,
-    ) -> Result<Vec<EpisodeSummaryData>, String> {
+    ) -> MuvelResult<Vec<EpisodeSummaryData>> {
         let mut updated_summaries = Vec::new();
         let mut affected_novel_ids = std::collections::HashSet::new();
 
@@ -241,88 +243,381 @@ impl<'a> EpisodeRepository<'a> {
                     }
                 }
                 Err(e) => {
-                    return Err(format!(
-                        "에피소드 ID {} 일괄 업데이트 중 오류: {}",
-                        diff_item.id, e
-                    ));
+                    return Err(e
+                        .context(Message::EpisodeBatchUpdateFailed {
+                            episode_id: diff_item.id.clone(),
+                        })
+                        .with_id(diff_item.id.clone()));
                 }
             }
         }
 
         for novel_id in affected_novel_ids {
-            if let Ok((novel_root_path, _)) = self.get_novel_root_path_and_id(&novel_id, true) {
+            if let Ok(novel_root_path) =
+                index_resolver::resolve_novel_root(self.app_handle, &novel_id)
+            {
                 self.update_novel_timestamp_only(&novel_root_path, &novel_id)?;
             }
         }
         Ok(updated_summaries)
     }
 
-    pub fn delete_episode(&self, episode_id: &str) -> Result<(), String> {
-        let (novel_root_path, novel_id) = self.get_novel_root_path_and_id(episode_id, false)?;
-        episode_io::delete_episode_file(&novel_root_path, episode_id)?;
-        item_index_manager::remove_item_novel_mapping(self.app_handle, episode_id)?;
+    pub fn delete_episode(&self, episode_id: &str) -> MuvelResult<()> {
+        let (novel_root_path, novel_id) =
+            index_resolver::resolve_item_novel(self.app_handle, episode_id)?;
+        self.remove_episode_files(&novel_root_path, episode_id)?;
+        if let Err(e) =
+            SnapshotRepository::new(self.app_handle).collect_unreferenced_blocks(&novel_root_path)
+        {
+            eprintln!("블록 저장소 정리 실패 (에피소드 ID: {}): {}", episode_id, e);
+        }
         self.update_novel_episode_count_and_timestamp(&novel_root_path, &novel_id)?;
         Ok(())
     }
 
+    /// 에피소드 파일과 저널, 편집 기록, 스냅샷 폴더를 삭제하고 첨부 파일의 연결과 아이템 인덱스에서 제거합니다.
+    /// 소설 메타데이터 갱신과 블록 저장소 정리는 호출하는 쪽에서 합니다.
+    pub fn remove_episode_files(
+        &self,
+        novel_root_path: &Path,
+        episode_id: &str,
+    ) -> MuvelResult<()> {
+        episode_io::delete_episode_file(novel_root_path, episode_id)?;
+        edit_history_io::delete_edit_history_file(novel_root_path, episode_id)?;
+        snapshot_io::delete_episode_snapshots_dir(novel_root_path, episode_id)?;
+        attachment_io::unlink_item_from_attachments(novel_root_path, episode_id)?;
+        item_index_manager::remove_item_novel_mapping(self.app_handle, episode_id)
+    }
+
     pub fn list_episode_summaries_for_novel(
         &self,
         novel_id_str: &str,
-    ) -> Result<Vec<EpisodeSummaryData>, String> {
-        let (novel_root_path, _novel_id) = self.get_novel_root_path_and_id(novel_id_str, true)?;
+    ) -> MuvelResult<Vec<EpisodeSummaryData>> {
+        let novel_root_path = index_resolver::resolve_novel_root(self.app_handle, novel_id_str)?;
         episode_io::list_episode_summaries_from_files(&novel_root_path)
     }
 
+    /// 에피소드 블록에 변경분(DeltaBlock)을 반영합니다.
+    ///
+    /// `base_revision`은 클라이언트가 변경분을 만들 때 기준으로 삼은 에피소드 리비전입니다.
+    /// 현재 리비전과 같으면 모든 변경분을 그대로 적용하고, 더 오래되었거나(다른 창에서 먼저 저장됨)
+    /// 전달되지 않았으면 변경분 생성 이후에 수정된 블록에 대한 변경분을 거부합니다.
+    /// 하나라도 적용되면 리비전이 1 증가합니다.
     pub fn sync_delta_blocks(
         &self,
         episode_id: &str,
         delta_blocks: Vec<DeltaBlock>,
-    ) -> Result<(), String> {
-        let (novel_root_path, novel_id) = self.get_novel_root_path_and_id(episode_id, false)?;
-        let mut episode_data = episode_io::read_episode_content(&novel_root_path, episode_id)?;
+        base_revision: Option<u64>,
+    ) -> MuvelResult<SyncResult> {
+        let (novel_root_path, novel_id) =
+            index_resolver::resolve_item_novel(self.app_handle, episode_id)?;
+        let (mut episode_data, journal_len) =
+            episode_io::read_episode_content_with_journal_len(&novel_root_path, episode_id)?;
 
-        let merged_blocks =
-            delta_block_utils::merge_delta_blocks(episode_data.blocks, delta_blocks)?;
+        if base_revision.is_some_and(|base| base > episode_data.revision) {
+            return Err(MuvelError::conflict(Message::BaseRevisionAhead {
+                episode_id: episode_id.to_string(),
+                base_revision: base_revision.unwrap_or_default(),
+                current_revision: episode_data.revision,
+            })
+            .with_id(episode_id));
+        }
+        let stale_base = base_revision != Some(episode_data.revision);
+
+        let (mut history, history_valid) =
+            self.read_valid_edit_history(&novel_root_path, &episode_data);
+        let before_blocks = episode_data.blocks.clone();
+        let applied = self.apply_and_journal_deltas(
+            &novel_root_path,
+            &novel_id,
+            &mut episode_data,
+            journal_len,
+            delta_blocks,
+            stale_base,
+        )?;
+
+        let mut records = Vec::new();
+        if applied.rebalanced {
+            // 이전 편집 기록의 order는 다시 매기기 전 기준이므로 버림
+            records.push(EditHistoryRecord::Rebalance {
+                revision: episode_data.revision,
+            });
+        }
+        if applied.applied_count > 0 {
+            let mut deltas = applied.deltas;
+            let inverse_deltas = if applied.rebalanced {
+                // 되돌리거나 다시 적용할 때 다시 매긴 이웃 블록 사이의 같은 자리에 놓이도록 order를 옮김
+                let changed_ids = deltas.iter().map(|d| d.id.as_str()).collect();
+                let rebased_before = block_order_utils::rebase_block_orders(
+                    &before_blocks,
+                    &episode_data.blocks,
+                    &changed_ids,
+                );
+                let inverse_deltas = delta_block_utils::build_inverse_deltas(
+                    &rebased_before,
+                    &deltas,
+                    &episode_data.updated_at,
+                );
+                delta_block_utils::rebase_delta_orders(&mut deltas, &episode_data.blocks);
+                inverse_deltas
+            } else {
+                delta_block_utils::build_inverse_deltas(
+                    &before_blocks,
+                    &deltas,
+                    &episode_data.updated_at,
+                )
+            };
+            records.push(EditHistoryRecord::Edit {
+                revision: episode_data.revision,
+                entry: EditHistoryEntry {
+                    created_at: episode_data.updated_at.clone(),
+                    deltas,
+                    inverse_deltas,
+                },
+            });
+        }
+        let mut rewrite = !history_valid || applied.compacted;
+        for record in records {
+            self.record_edit_history(&novel_root_path, episode_id, &mut history, rewrite, record)?;
+            rewrite = false;
+        }
+
+        Ok(SyncResult {
+            revision: episode_data.revision,
+            content_length: episode_data.content_length,
+            applied_count: applied.applied_count,
+            rejected: applied.rejected,
+            stale_base,
+            rebalanced: applied.rebalanced,
+        })
+    }
+
+    /// 변경분을 병합하고, 적용된 변경분을 저널에 추가합니다.
+    /// 적용된 변경분이 있거나 order를 다시 매겼으면 리비전이 1 증가합니다.
+    /// `journal_len`은 에피소드를 읽을 때 적용된(아직 압축되지 않은) 저널 항목 수입니다.
+    fn apply_and_journal_deltas(
+        &self,
+        novel_root_path: &Path,
+        novel_id: &str,
+        episode_data: &mut LocalEpisodeData,
+        journal_len: usize,
+        delta_blocks: Vec<DeltaBlock>,
+        reject_stale: bool,
+    ) -> MuvelResult<AppliedDeltas> {
+        let outcome = delta_block_utils::merge_delta_blocks(
+            std::mem::take(&mut episode_data.blocks),
+            delta_blocks.clone(),
+            reject_stale,
+        )?;
+        episode_data.blocks = outcome.blocks;
+
+        let applied_deltas: Vec<DeltaBlock> = delta_blocks
+            .into_iter()
+            .filter(|delta| {
+                !outcome
+                    .rejected
+                    .iter()
+                    .any(|r| r.id == delta.id && r.action == delta.action)
+            })
+            .collect();
 
-        episode_data.blocks = merged_blocks;
-        episode_data.updated_at = Utc::now().to_rfc3339();
+        // 적용된 변경분이 없어도 order를 다시 매겼다면 기록해야 함
+        let mut compacted = false;
+        if outcome.applied_count > 0 || outcome.rebalanced {
+            episode_data.revision += 1;
+            episode_data.updated_at = Utc::now().to_rfc3339();
 
-        // contentLength 계산 로직 수정: 공백 제외 글자 수 합산
-        let calculated_content_length: usize = episode_data
-            .blocks
-            .iter()
-            .map(|block| block.text.chars().filter(|c| !c.is_whitespace()).count())
-            .sum();
-        episode_data.content_length = calculated_content_length as i32;
+            // contentLength 계산 로직 수정: 공백 제외 글자 수 합산
+            episode_data.content_length =
+                delta_block_utils::calculate_content_length(&episode_data.blocks);
+
+            // 에피소드 전체를 다시 쓰지 않고 적용된 변경분만 저널에 추가
+            let entry = EpisodeJournalEntry {
+                revision: episode_data.revision,
+                updated_at: episode_data.updated_at.clone(),
+                deltas: applied_deltas.clone(),
+            };
+            episode_journal_io::append_journal_entry(novel_root_path, &episode_data.id, &entry)?;
 
+            // 저널만 추가한 경우 매니페스트는 갱신하지 않음. 저널 상태가 달라진 항목은
+            // 다음 목록 조회 때 이 에피소드만 다시 읽어 갱신되고, 합치거나 에디터를 닫을 때도 갱신됨
+            if journal_len + 1 >= JOURNAL_COMPACTION_THRESHOLD {
+                // 에피소드 파일을 다시 쓰면서 매니페스트도 갱신됨
+                episode_io::write_episode_content(novel_root_path, &episode_data.id, episode_data)?;
+                self.update_novel_timestamp_only(novel_root_path, novel_id)?; // 부모 소설 타임스탬프만 업데이트
+                compacted = true;
+            } else {
+                episode_io::remember_episode_content(
+                    novel_root_path,
+                    episode_data,
+                    journal_len + 1,
+                );
+            }
+        }
+
+        Ok(AppliedDeltas {
+            deltas: applied_deltas,
+            applied_count: outcome.applied_count,
+            rejected: outcome.rejected,
+            rebalanced: outcome.rebalanced,
+            compacted,
+        })
+    }
+
+    /// 편집 기록을 읽고, 기록 밖에서 에피소드가 바뀌었으면(스냅샷 복원 등) 비웁니다.
+    /// 읽은 기록을 그대로 이어 쓸 수 있으면 true를 함께 반환합니다.
+    fn read_valid_edit_history(
+        &self,
+        novel_root_path: &Path,
+        episode_data: &LocalEpisodeData,
+    ) -> (EpisodeEditHistory, bool) {
+        let history = edit_history_io::read_edit_history(novel_root_path, &episode_data.id);
+        if history.revision == episode_data.revision {
+            (history, true)
+        } else {
+            let empty_history = EpisodeEditHistory {
+                revision: episode_data.revision,
+                ..Default::default()
+            };
+            (empty_history, false)
+        }
+    }
+
+    /// 편집 기록에 항목을 반영하고 저장합니다.
+    /// 보통은 파일 끝에 항목만 추가하고, `rewrite`이면(기록을 새로 시작했거나 저널을 압축한 경우)
+    /// 기록 전체를 한 항목으로 압축해 파일을 새로 씁니다.
+    fn record_edit_history(
+        &self,
+        novel_root_path: &Path,
+        episode_id: &str,
+        history: &mut EpisodeEditHistory,
+        rewrite: bool,
+        record: EditHistoryRecord,
+    ) -> MuvelResult<()> {
+        if rewrite {
+            history.apply(record);
+            edit_history_io::write_edit_history(novel_root_path, episode_id, history)
+        } else {
+            edit_history_io::append_edit_history_record(novel_root_path, episode_id, &record)?;
+            history.apply(record);
+            Ok(())
+        }
+    }
+
+    /// 실행 취소/다시 실행 가능 여부를 반환합니다.
+    pub fn get_edit_history_state(&self, episode_id: &str) -> MuvelResult<EditHistoryState> {
+        let (novel_root_path, _novel_id) =
+            index_resolver::resolve_item_novel(self.app_handle, episode_id)?;
+        let episode_data = episode_io::read_episode_content(&novel_root_path, episode_id)?;
+        let (history, _) = self.read_valid_edit_history(&novel_root_path, &episode_data);
+        Ok(EditHistoryState {
+            can_undo: !history.undo_stack.is_empty(),
+            can_redo: !history.redo_stack.is_empty(),
+        })
+    }
+
+    /// 마지막 편집을 되돌립니다. 되돌릴 편집이 없으면 `applied`가 false입니다.
+    pub fn undo_edit(&self, episode_id: &str) -> MuvelResult<EditHistoryResult> {
+        self.step_edit_history(episode_id, true)
+    }
+
+    /// 마지막으로 되돌린 편집을 다시 적용합니다. 다시 적용할 편집이 없으면 `applied`가 false입니다.
+    pub fn redo_edit(&self, episode_id: &str) -> MuvelResult<EditHistoryResult> {
+        self.step_edit_history(episode_id, false)
+    }
+
+    fn step_edit_history(&self, episode_id: &str, undo: bool) -> MuvelResult<EditHistoryResult> {
+        let (novel_root_path, novel_id) =
+            index_resolver::resolve_item_novel(self.app_handle, episode_id)?;
+        let (mut episode_data, journal_len) =
+            episode_io::read_episode_content_with_journal_len(&novel_root_path, episode_id)?;
+        let (mut history, history_valid) =
+            self.read_valid_edit_history(&novel_root_path, &episode_data);
+
+        let entry = if undo {
+            history.undo_stack.last()
+        } else {
+            history.redo_stack.last()
+        };
+        let applied = match entry {
+            Some(entry) => {
+                let mut deltas = if undo {
+                    entry.inverse_deltas.clone()
+                } else {
+                    entry.deltas.clone()
+                };
+                // 블록 수정 시각이 과거로 돌아가지 않도록 지금 시각으로 적용
+                let now = Utc::now().to_rfc3339();
+                for delta in &mut deltas {
+                    delta.date = now.clone();
+                }
+                // 기록된 편집은 그 사이 다른 수정이 있었더라도 그대로 적용
+                let outcome = self.apply_and_journal_deltas(
+                    &novel_root_path,
+                    &novel_id,
+                    &mut episode_data,
+                    journal_len,
+                    deltas,
+                    false,
+                )?;
+                let revision = episode_data.revision;
+                let record = if outcome.rebalanced {
+                    // order를 다시 매겼으면 남은 기록의 order가 맞지 않으므로 기록을 비움
+                    EditHistoryRecord::Rebalance { revision }
+                } else if undo {
+                    EditHistoryRecord::Undo { revision }
+                } else {
+                    EditHistoryRecord::Redo { revision }
+                };
+                self.record_edit_history(
+                    &novel_root_path,
+                    episode_id,
+                    &mut history,
+                    !history_valid || outcome.compacted,
+                    record,
+                )?;
+                true
+            }
+            None => false,
+        };
+
+        Ok(EditHistoryResult {
+            applied,
+            revision: episode_data.revision,
+            content_length: episode_data.content_length,
+            blocks: episode_data.blocks,
+            can_undo: !history.undo_stack.is_empty(),
+            can_redo: !history.redo_stack.is_empty(),
+        })
+    }
+
+    /// 에피소드 저널을 에피소드 파일(.mvle)에 합치고 저널을 비웁니다.
+    /// 에디터를 닫을 때 호출되며, 저널이 없으면 아무것도 하지 않습니다.
+    pub fn compact_episode_journal(&self, episode_id: &str) -> MuvelResult<()> {
+        let (novel_root_path, novel_id) =
+            index_resolver::resolve_item_novel(self.app_handle, episode_id)?;
+        if !episode_journal_io::journal_exists(&novel_root_path, episode_id) {
+            return Ok(());
+        }
+        // 읽을 때 저널이 적용되므로 그대로 다시 쓰면 압축됨
+        let episode_data = episode_io::read_episode_content(&novel_root_path, episode_id)?;
         episode_io::write_episode_content(&novel_root_path, episode_id, &episode_data)?;
-        self.update_novel_timestamp_only(&novel_root_path, &novel_id)?; // 부모 소설 타임스탬프만 업데이트
-
-        // 부모 소설의 .muvl 파일 내 에피소드 요약 정보도 업데이트 (선택적이지만, 정합성을 위해 권장)
-        // 이 부분은 현재 EpisodeSummaryData에는 contentLength가 Option이므로,
-        // novel_io를 직접 호출하여 .muvl 파일의 해당 에피소드 요약 정보를 수정해야 합니다.
-        // 또는, NovelRepository에 관련 기능을 만들고 호출할 수 있습니다.
-        // 여기서는 간단히 부모 소설의 updated_at만 갱신하는 것으로 유지합니다.
-        // 만약 .muvl 내 요약 정보 업데이트가 필요하면, 아래와 유사한 로직 추가:
-        /*
-        let (mut parent_novel_data, _) = novel_io::read_novel_metadata_with_path(&novel_root_path)?;
-        if let Some(episodes_summary_vec) = parent_novel_data.episodes.as_mut() { // Novel 모델에 episodes 필드가 있다면
-            if let Some(summary_to_update) = episodes_summary_vec.iter_mut().find(|s| s.id == episode_id) {
-                summary_to_update.content_length = Some(episode_data.content_length);
-                summary_to_update.updated_at = episode_data.updated_at.clone();
-                parent_novel_data.updated_at = episode_data.updated_at.clone(); // 소설 전체 업데이트 시간도 갱신
-                novel_io::update_existing_novel_metadata_file(&novel_root_path, &parent_novel_data)?;
+
+        // 저널과 함께 편집 기록 파일도 한 항목으로 압축
+        match self.read_valid_edit_history(&novel_root_path, &episode_data) {
+            (history, true) => {
+                edit_history_io::write_edit_history(&novel_root_path, episode_id, &history)?
             }
+            (_, false) => edit_history_io::delete_edit_history_file(&novel_root_path, episode_id)?,
         }
-        */
-        Ok(())
+        self.update_novel_timestamp_only(&novel_root_path, &novel_id)
     }
 
     fn update_novel_episode_count_and_timestamp(
         &self,
         novel_root_path: &PathBuf,
         novel_id: &str,
-    ) -> Result<(), String> {
+    ) -> MuvelResult<()> {
         let (mut novel_data, _) = novel_io::read_novel_metadata_with_path(novel_root_path)?;
         let episode_summaries = episode_io::list_episode_summaries_from_files(novel_root_path)?;
         let new_count = episode_summaries.len() as i32;
@@ -355,9 +650,9 @@ impl<'a> EpisodeRepository<'a> {
 
     fn update_novel_timestamp_only(
         &self,
-        novel_root_path: &PathBuf,
+        novel_root_path: &Path,
         _novel_id: &str,
-    ) -> Result<(), String> {
+    ) -> MuvelResult<()> {
         let (mut novel_data, _) = novel_io::read_novel_metadata_with_path(novel_root_path)?;
         let current_time = Utc::now().to_rfc3339();
         if novel_data.updated_at < current_time {
@@ -1,5 +1,8 @@
+pub mod attachment_repository;
 pub mod episode_repository;
 pub mod novel_repository;
+pub mod resource_repository;
 pub mod search_repository;
 pub mod snapshot_repository;
+pub mod sync_conflict_repository;
 pub mod wiki_page_repository;
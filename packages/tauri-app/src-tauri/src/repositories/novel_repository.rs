@@ -1,3 +1,5 @@
+use crate::i18n::{FileKind, Message};
+use crate::models::error::{MuvelError, MuvelResult};
 use chrono::Utc;
 use slug::slugify;
 use std::collections::HashSet;
@@ -11,7 +13,11 @@ use crate::models::novel::{
     CreateLocalNovelOptions, EpisodeSummaryData, Novel, NovelFullDetails, UpdateLocalNovelData,
     WikiPageSummaryData,
 };
-use crate::storage::{episode_io, index_manager, item_index_manager, novel_io, wiki_page_io};
+use crate::repositories::resource_repository::ResourceRepository;
+use crate::storage::{
+    episode_io, image_resource_io, index_manager, index_resolver, item_index_manager,
+    novel_archive_io, novel_io, resource_uri, settings_manager, wiki_page_io,
+};
 
 pub struct NovelRepository<'a> {
     app_handle: &'a AppHandle,
@@ -22,23 +28,33 @@ impl<'a> NovelRepository<'a> {
         Self { app_handle }
     }
 
-    pub fn create_novel(&self, options: CreateLocalNovelOptions) -> Result<Novel, String> {
-        let novel_id = Uuid::new_v4().to_string();
-        let novel_folder_name = slugify(&options.title);
-
-        let mut novel_root_path: PathBuf = if let Some(path_str) = &options.target_directory_path {
+    /// 소설 폴더를 만들 경로. 지정한 폴더가 없으면 앱 데이터 폴더의 `novels` 아래에 제목으로 만듭니다.
+    fn novel_root_path_for(
+        &self,
+        title: &str,
+        target_directory_path: Option<&str>,
+    ) -> MuvelResult<PathBuf> {
+        let mut novel_root_path: PathBuf = if let Some(path_str) = target_directory_path {
             PathBuf::from(path_str)
         } else {
             self.app_handle
                 .path()
                 .app_local_data_dir()
-                .map_err(|e| format!("애플리케이션 로컬 데이터 디렉토리 경로 실패: {:?}", e))?
+                .map_err(|e| MuvelError::internal(Message::AppDataDirNotFound).caused_by(e))?
                 .join("novels")
         };
-        novel_root_path.push(&novel_folder_name);
+        novel_root_path.push(slugify(title));
+        Ok(novel_root_path)
+    }
+
+    pub fn create_novel(&self, options: CreateLocalNovelOptions) -> MuvelResult<Novel> {
+        let novel_id = Uuid::new_v4().to_string();
+        let novel_root_path =
+            self.novel_root_path_for(&options.title, options.target_directory_path.as_deref())?;
 
         if novel_root_path.exists() {
-            return Err(format!("이미 폴더가 존재합니다: {:?}", novel_root_path));
+            return Err(MuvelError::already_exists(Message::FolderAlreadyExists)
+                .with_path(&novel_root_path));
         }
 
         novel_io::create_novel_directories(&novel_root_path)?;
@@ -73,36 +89,25 @@ impl<'a> NovelRepository<'a> {
         Ok(initial_novel_data)
     }
 
-    pub fn get_novel_details(&self, novel_id: &str) -> Result<NovelFullDetails, String> {
-        let novel_entry = index_manager::get_novel_entry(self.app_handle, novel_id)?
-            .ok_or_else(|| format!("인덱스에서 소설 ID {} 를 찾을 수 없습니다.", novel_id))?;
-        let novel_root_path = PathBuf::from(novel_entry.path.ok_or("소설 경로 없음")?);
+    pub fn get_novel_details(&self, novel_id: &str) -> MuvelResult<NovelFullDetails> {
+        let novel_root_path = index_resolver::resolve_novel_root(self.app_handle, novel_id)?;
 
         if !novel_root_path.exists() || !novel_root_path.is_dir() {
             eprintln!("경고: 소설 ID {}의 경로 {:?}가 존재하지 않거나 디렉토리가 아닙니다. 인덱스에서 제거합니다.", novel_id, novel_root_path);
             index_manager::remove_novel_entry(self.app_handle, novel_id)?;
-            let episode_ids = item_index_manager::get_item_ids_for_novel_by_type(
-                self.app_handle,
-                novel_id,
-                "episode",
-            )
-            .unwrap_or_default();
-            for ep_id in episode_ids {
-                item_index_manager::remove_item_novel_mapping(self.app_handle, &ep_id)?;
-            }
-            let wiki_page_ids = item_index_manager::get_item_ids_for_novel_by_type(
-                self.app_handle,
-                novel_id,
-                "wiki_page",
-            )
-            .unwrap_or_default();
-            for page_id in wiki_page_ids {
-                item_index_manager::remove_item_novel_mapping(self.app_handle, &page_id)?;
-            }
-            return Err(format!(
-                "소설 ID {}의 프로젝트 경로를 찾을 수 없습니다: {:?}",
-                novel_id, novel_root_path
-            ));
+            item_index_manager::remove_all_item_mappings_for_novel(self.app_handle, novel_id)?;
+            return Err(MuvelError::not_found(Message::NovelRootNotFound {
+                novel_id: novel_id.to_string(),
+            })
+            .with_id(novel_id)
+            .with_path(&novel_root_path));
+        }
+
+        // 이전 버전이 저장한 리소스 절대 경로를 한 번만 리소스 URI로 바꿈 (실패해도 소설은 열고 다음에 다시 시도)
+        if let Err(e) = ResourceRepository::new(self.app_handle)
+            .migrate_legacy_resource_refs(novel_id, &novel_root_path)
+        {
+            eprintln!("소설 {}의 리소스 참조 마이그레이션 실패: {}", novel_id, e);
         }
 
         // read_novel_metadata_with_path 사용 (실제 읽은 파일 경로도 반환하지만 여기서는 novel_data만 사용)
@@ -152,7 +157,7 @@ impl<'a> NovelRepository<'a> {
         &self,
         novel_id: &str,
         data: UpdateLocalNovelData,
-    ) -> Result<NovelFullDetails, String> {
+    ) -> MuvelResult<NovelFullDetails> {
         let novel_root_path = self.get_novel_root_path(novel_id)?;
         let (mut current_novel_data, _actual_muvl_path) =
             novel_io::read_novel_metadata_with_path(&novel_root_path)?;
@@ -208,7 +213,7 @@ impl<'a> NovelRepository<'a> {
 
     // delete_novel_project, get_novel_root_path, list_all_novel_entries, get_novel_entry, register_novel_from_path, save_image_to_novel는 이전과 동일
     // ensure_novel_data_synced 내부에서 novel_metadata 변경 후 저장 시 update_existing_novel_metadata_file 사용
-    pub fn delete_novel_project(&self, novel_id: &str) -> Result<(), String> {
+    pub fn delete_novel_project(&self, novel_id: &str) -> MuvelResult<()> {
         let novel_entry_opt = index_manager::get_novel_entry(self.app_handle, novel_id)?;
         if novel_entry_opt.is_none() {
             eprintln!(
@@ -226,74 +231,31 @@ impl<'a> NovelRepository<'a> {
             eprintln!("경고: 소설 ID {}의 경로가 인덱스에 없어 파일 시스템 삭제를 건너<0xEB><0><0x88>니다.", novel_id);
         }
 
-        let episode_ids = item_index_manager::get_item_ids_for_novel_by_type(
-            self.app_handle,
-            novel_id,
-            "episode",
-        )
-        .unwrap_or_default();
-        for ep_id in episode_ids {
-            item_index_manager::remove_item_novel_mapping(self.app_handle, &ep_id)?;
-        }
-        let wiki_page_ids = item_index_manager::get_item_ids_for_novel_by_type(
-            self.app_handle,
-            novel_id,
-            "wiki_page",
-        )
-        .unwrap_or_default();
-        for page_id in wiki_page_ids {
-            item_index_manager::remove_item_novel_mapping(self.app_handle, &page_id)?;
-        }
+        item_index_manager::remove_all_item_mappings_for_novel(self.app_handle, novel_id)?;
 
         index_manager::remove_novel_entry(self.app_handle, novel_id)?;
         Ok(())
     }
 
-    pub fn get_novel_root_path(&self, novel_id: &str) -> Result<PathBuf, String> {
-        let novel_entry = index_manager::get_novel_entry(self.app_handle, novel_id)?
-            .ok_or_else(|| format!("인덱스에서 소설 ID {} 를 찾을 수 없습니다.", novel_id))?;
-
-        let path_str = novel_entry.path.ok_or_else(|| {
-            format!(
-                "소설 ID {} 에 대한 경로 정보가 인덱스에 없습니다.",
-                novel_id
-            )
-        })?;
-
-        let path = PathBuf::from(path_str);
+    pub fn get_novel_root_path(&self, novel_id: &str) -> MuvelResult<PathBuf> {
+        let path = index_resolver::resolve_novel_root(self.app_handle, novel_id)?;
         if !path.exists() || !path.is_dir() {
             eprintln!(
                 "경고: 소설 ID {}의 인덱스 경로 {:?}가 유효하지 않습니다. 인덱스에서 제거합니다.",
                 novel_id, path
             );
             index_manager::remove_novel_entry(self.app_handle, novel_id)?;
-            let episode_ids = item_index_manager::get_item_ids_for_novel_by_type(
-                self.app_handle,
-                novel_id,
-                "episode",
-            )
-            .unwrap_or_default();
-            for ep_id in episode_ids {
-                item_index_manager::remove_item_novel_mapping(self.app_handle, &ep_id)?;
-            }
-            let wiki_page_ids = item_index_manager::get_item_ids_for_novel_by_type(
-                self.app_handle,
-                novel_id,
-                "wiki_page",
-            )
-            .unwrap_or_default();
-            for page_id in wiki_page_ids {
-                item_index_manager::remove_item_novel_mapping(self.app_handle, &page_id)?;
-            }
-            return Err(format!(
-                "소설 ID {}의 프로젝트 경로를 찾을 수 없습니다 (인덱스 수정됨): {:?}",
-                novel_id, path
-            ));
+            item_index_manager::remove_all_item_mappings_for_novel(self.app_handle, novel_id)?;
+            return Err(MuvelError::not_found(Message::NovelRootNotFound {
+                novel_id: novel_id.to_string(),
+            })
+            .with_id(novel_id)
+            .with_path(&path));
         }
         Ok(path)
     }
 
-    pub fn list_all_novel_entries(&self) -> Result<Vec<LocalNovelIndexEntry>, String> {
+    pub fn list_all_novel_entries(&self) -> MuvelResult<Vec<LocalNovelIndexEntry>> {
         let all_entries = index_manager::get_all_novel_entries(self.app_handle)?;
         let mut valid_entries = Vec::new();
         let mut index_modified_in_listing = false; // 변경 여부 플래그
@@ -325,24 +287,10 @@ impl<'a> NovelRepository<'a> {
             } else {
                 if index_manager::remove_novel_entry(self.app_handle, &entry.id).is_ok() {
                     index_modified_in_listing = true;
-                    let episode_ids = item_index_manager::get_item_ids_for_novel_by_type(
-                        self.app_handle,
-                        &entry.id,
-                        "episode",
-                    )
-                    .unwrap_or_default();
-                    for ep_id in episode_ids {
-                        item_index_manager::remove_item_novel_mapping(self.app_handle, &ep_id)?;
-                    }
-                    let wiki_page_ids = item_index_manager::get_item_ids_for_novel_by_type(
+                    item_index_manager::remove_all_item_mappings_for_novel(
                         self.app_handle,
                         &entry.id,
-                        "wiki_page",
-                    )
-                    .unwrap_or_default();
-                    for page_id in wiki_page_ids {
-                        item_index_manager::remove_item_novel_mapping(self.app_handle, &page_id)?;
-                    }
+                    )?;
                 }
             }
         }
@@ -352,7 +300,7 @@ impl<'a> NovelRepository<'a> {
         Ok(valid_entries)
     }
 
-    pub fn get_novel_entry(&self, novel_id: &str) -> Result<Option<LocalNovelIndexEntry>, String> {
+    pub fn get_novel_entry(&self, novel_id: &str) -> MuvelResult<Option<LocalNovelIndexEntry>> {
         let entry_opt = index_manager::get_novel_entry(self.app_handle, novel_id)?;
         if let Some(entry) = &entry_opt {
             if let Some(path_str) = &entry.path {
@@ -363,64 +311,36 @@ impl<'a> NovelRepository<'a> {
                 {
                     eprintln!("경고: 소설 ID {}의 인덱스 경로 {:?}가 유효하지 않거나 메타데이터를 읽을 수 없습니다. get_novel_entry 호출 시 인덱스에서 제거합니다.", novel_id, path);
                     index_manager::remove_novel_entry(self.app_handle, novel_id)?;
-                    let episode_ids = item_index_manager::get_item_ids_for_novel_by_type(
+                    item_index_manager::remove_all_item_mappings_for_novel(
                         self.app_handle,
                         novel_id,
-                        "episode",
-                    )
-                    .unwrap_or_default();
-                    for ep_id in episode_ids {
-                        item_index_manager::remove_item_novel_mapping(self.app_handle, &ep_id)?;
-                    }
-                    let wiki_page_ids = item_index_manager::get_item_ids_for_novel_by_type(
-                        self.app_handle,
-                        novel_id,
-                        "wiki_page",
-                    )
-                    .unwrap_or_default();
-                    for page_id in wiki_page_ids {
-                        item_index_manager::remove_item_novel_mapping(self.app_handle, &page_id)?;
-                    }
+                    )?;
                     return Ok(None);
                 }
             } else {
                 eprintln!("경고: 소설 ID {}에 경로 정보가 없습니다. get_novel_entry 호출 시 인덱스에서 제거합니다.", novel_id);
                 index_manager::remove_novel_entry(self.app_handle, novel_id)?;
-                let episode_ids = item_index_manager::get_item_ids_for_novel_by_type(
-                    self.app_handle,
-                    novel_id,
-                    "episode",
-                )
-                .unwrap_or_default();
-                for ep_id in episode_ids {
-                    item_index_manager::remove_item_novel_mapping(self.app_handle, &ep_id)?;
-                }
-                let wiki_page_ids = item_index_manager::get_item_ids_for_novel_by_type(
-                    self.app_handle,
-                    novel_id,
-                    "wiki_page",
-                )
-                .unwrap_or_default();
-                for page_id in wiki_page_ids {
-                    item_index_manager::remove_item_novel_mapping(self.app_handle, &page_id)?;
-                }
+                item_index_manager::remove_all_item_mappings_for_novel(self.app_handle, novel_id)?;
                 return Ok(None);
             }
         }
         Ok(entry_opt)
     }
 
-    pub fn register_novel_from_path(&self, file_path_str: &str) -> Result<Option<String>, String> {
+    pub fn register_novel_from_path(&self, file_path_str: &str) -> MuvelResult<Option<String>> {
         let file_path = Path::new(file_path_str);
         if !file_path.exists()
             || !file_path.is_file()
             || file_path.extension().map_or(true, |ext| ext != "muvl")
         {
-            return Err("유효한 .muvl 파일 경로가 아닙니다.".to_string());
+            return Err(
+                MuvelError::invalid_input(Message::InvalidNovelFilePath).with_path(file_path)
+            );
         }
-        let novel_root_path = file_path
-            .parent()
-            .ok_or("소설 루트 경로를 찾을 수 없습니다.")?;
+        let novel_root_path = file_path.parent().ok_or_else(|| {
+            MuvelError::not_found(Message::ParentDirNotFound(FileKind::NovelMetadata))
+                .with_path(file_path)
+        })?;
         // read_novel_metadata_with_path 사용
         let (novel_data, _actual_muvl_path) =
             novel_io::read_novel_metadata_with_path(&novel_root_path)?;
@@ -454,7 +374,7 @@ impl<'a> NovelRepository<'a> {
         fs_episode_summaries: &[EpisodeSummaryData],
         fs_wiki_page_summaries: &[WikiPageSummaryData],
         novel_metadata: &mut Novel,
-    ) -> Result<(), String> {
+    ) -> MuvelResult<()> {
         let indexed_episode_ids_vec = item_index_manager::get_item_ids_for_novel_by_type(
             self.app_handle,
             novel_id,
@@ -543,8 +463,48 @@ impl<'a> NovelRepository<'a> {
         novel_id: &str,
         original_file_name: &str,
         file_bytes: Vec<u8>,
-    ) -> Result<String, String> {
+    ) -> MuvelResult<String> {
+        let novel_root_path = self.get_novel_root_path(novel_id)?;
+        let settings = settings_manager::load_settings(self.app_handle)?;
+        let relative_path = image_resource_io::save_image(
+            &novel_root_path,
+            original_file_name,
+            file_bytes,
+            &settings.image_resources,
+        )?;
+        resource_uri::resource_uri_for(novel_id, &relative_path).ok_or_else(|| {
+            MuvelError::internal(Message::PathToStringFailed).with_path(&relative_path)
+        })
+    }
+
+    /// 소설 프로젝트 전체를 `.muvlz` 아카이브 파일 하나로 내보냅니다.
+    pub fn export_novel_archive(&self, novel_id: &str, archive_path: &Path) -> MuvelResult<()> {
         let novel_root_path = self.get_novel_root_path(novel_id)?;
-        novel_io::save_image_to_resources(&novel_root_path, original_file_name, file_bytes)
+        let (novel_data, _) = novel_io::read_novel_metadata_with_path(&novel_root_path)?;
+        novel_archive_io::write_novel_archive(&novel_root_path, &novel_data, archive_path)
+    }
+
+    /// `.muvlz` 아카이브를 새 소설 폴더에 풀고 인덱스에 등록합니다.
+    /// 이미 등록된 소설(같은 ID)은 덮어쓰지 않도록 거부합니다.
+    pub fn import_novel_archive(
+        &self,
+        archive_path: &Path,
+        target_directory_path: Option<&str>,
+    ) -> MuvelResult<NovelFullDetails> {
+        let manifest = novel_archive_io::read_archive_manifest(archive_path)?;
+        if index_manager::get_novel_entry(self.app_handle, &manifest.novel_id)?.is_some() {
+            return Err(MuvelError::already_exists(Message::NovelAlreadyRegistered {
+                novel_id: manifest.novel_id.clone(),
+            })
+            .with_id(&manifest.novel_id));
+        }
+
+        let novel_root_path = self.novel_root_path_for(&manifest.title, target_directory_path)?;
+        let metadata_path =
+            novel_archive_io::extract_novel_archive(archive_path, &manifest, &novel_root_path)?;
+
+        self.register_novel_from_path(&metadata_path.to_string_lossy())?;
+        // localPath를 새 폴더로 맞추고 아이템 인덱스를 다시 만듦
+        self.get_novel_details(&manifest.novel_id)
     }
 }
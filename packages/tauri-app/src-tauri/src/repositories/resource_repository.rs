@@ -0,0 +1,280 @@
+use crate::i18n::{FileKind, Message};
+use crate::models::block::Block;
+use crate::models::error::{MuvelError, MuvelResult};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use tauri::AppHandle;
+
+use crate::models::resource::ResourceCleanupResult;
+use crate::storage::block_store::{self, BlockRef};
+use crate::storage::novel_io::RESOURCES_DIRNAME;
+use crate::storage::{
+    durable_io, episode_io, image_resource_io, index_manager, index_resolver, novel_io,
+    novel_snapshot_io, novel_watcher, resource_uri, snapshot_io, wiki_page_io,
+};
+
+// 리소스 참조 마이그레이션을 마친 소설의 `resources` 폴더에 남기는 표시 파일
+const RESOURCE_URI_MIGRATION_MARKER: &str = ".resource-uri-v1";
+
+// 최근에 추가/수정된 이미지는 정리하지 않음 (참조하는 블록이 아직 저장되지 않았거나 동기화 중일 수 있음)
+const UNUSED_RESOURCE_GRACE_PERIOD: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// 파일이 유예 기간 안에 수정되었는지 확인합니다. 수정 시각을 알 수 없으면 최근 파일로 봅니다.
+fn is_recently_modified(path: &Path, now: SystemTime) -> bool {
+    fs::metadata(path)
+        .and_then(|m| m.modified())
+        .map_or(true, |modified| {
+            now.duration_since(modified)
+                .map_or(true, |age| age < UNUSED_RESOURCE_GRACE_PERIOD)
+        })
+}
+
+/// 블록의 content와 attr에서 이 소설의 리소스 참조를 모읍니다.
+fn collect_block_refs(
+    blocks: &[Block],
+    novel_id: &str,
+    novel_root_path: &Path,
+    found: &mut HashSet<PathBuf>,
+) {
+    for block in blocks {
+        for node in &block.content {
+            resource_uri::collect_resource_refs(node, novel_id, novel_root_path, found);
+        }
+        if let Some(attr) = &block.attr {
+            resource_uri::collect_resource_refs(attr, novel_id, novel_root_path, found);
+        }
+    }
+}
+
+fn collect_optional_ref(
+    reference: Option<&str>,
+    novel_id: &str,
+    novel_root_path: &Path,
+    found: &mut HashSet<PathBuf>,
+) {
+    if let Some(reference) = reference {
+        found.extend(resource_uri::referenced_resource_path(
+            reference,
+            novel_id,
+            novel_root_path,
+        ));
+    }
+}
+
+/// 상대 경로를 `/`로 구분된 문자열로 바꿉니다. (프런트엔드 표시용)
+fn to_slash_path(relative_path: &Path) -> String {
+    relative_path
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(part) => Some(part.to_string_lossy()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+pub struct ResourceRepository<'a> {
+    app_handle: &'a AppHandle,
+}
+
+impl<'a> ResourceRepository<'a> {
+    pub fn new(app_handle: &'a AppHandle) -> Self {
+        Self { app_handle }
+    }
+
+    /// 이전 버전이 저장한 리소스 절대 경로(블록, 소설/위키 썸네일)를 리소스 URI로 바꿉니다.
+    /// 소설마다 한 번만 실행되며, 바꾼 참조 수를 반환합니다.
+    /// 스냅샷은 고치지 않고, 복원할 때 같은 방식으로 바꿉니다. (`SnapshotRepository`)
+    pub fn migrate_legacy_resource_refs(
+        &self,
+        novel_id: &str,
+        novel_root_path: &Path,
+    ) -> MuvelResult<usize> {
+        let resources_dir = novel_root_path.join(RESOURCES_DIRNAME);
+        let marker_path = resources_dir.join(RESOURCE_URI_MIGRATION_MARKER);
+        if marker_path.is_file() {
+            return Ok(0);
+        }
+
+        let mut migrated_count = self.migrate_novel_thumbnail(novel_id, novel_root_path)?;
+
+        for summary in episode_io::list_episode_summaries_from_files(novel_root_path)? {
+            // 읽을 때 저널이 적용되고, 다시 쓸 때 저널이 합쳐짐
+            let mut episode_data = episode_io::read_episode_content(novel_root_path, &summary.id)?;
+            let count = resource_uri::relativize_block_refs(
+                &mut episode_data.blocks,
+                novel_id,
+                novel_root_path,
+            );
+            if count > 0 {
+                // 리비전을 올려, 이전 참조를 담은 편집 기록이 무효가 되도록 함
+                episode_data.revision += 1;
+                episode_io::write_episode_content(novel_root_path, &summary.id, &episode_data)?;
+                migrated_count += count;
+            }
+        }
+
+        for summary in wiki_page_io::list_wiki_page_summaries_from_files(novel_root_path)? {
+            let mut page_data = wiki_page_io::read_wiki_page_content(novel_root_path, &summary.id)?;
+            let count = resource_uri::relativize_optional_ref(
+                &mut page_data.thumbnail,
+                novel_id,
+                novel_root_path,
+            ) + resource_uri::relativize_block_refs(
+                &mut page_data.blocks,
+                novel_id,
+                novel_root_path,
+            );
+            if count > 0 {
+                wiki_page_io::write_wiki_page_content(novel_root_path, &summary.id, &page_data)?;
+                migrated_count += count;
+            }
+        }
+
+        fs::create_dir_all(&resources_dir).map_err(|e| {
+            MuvelError::io(Message::DirCreateFailed(FileKind::Resource), &e)
+                .with_path(&resources_dir)
+        })?;
+        durable_io::write_file_atomically(&marker_path, b"")?;
+        if migrated_count > 0 {
+            eprintln!(
+                "소설 {}의 리소스 참조 {}개를 리소스 URI로 바꿨습니다.",
+                novel_id, migrated_count
+            );
+        }
+        Ok(migrated_count)
+    }
+
+    /// 소설 썸네일(.muvl과 인덱스)의 이전 형식 리소스 참조를 바꿉니다.
+    fn migrate_novel_thumbnail(
+        &self,
+        novel_id: &str,
+        novel_root_path: &Path,
+    ) -> MuvelResult<usize> {
+        let (mut novel_data, _) = novel_io::read_novel_metadata_with_path(novel_root_path)?;
+        let count = resource_uri::relativize_optional_ref(
+            &mut novel_data.thumbnail,
+            novel_id,
+            novel_root_path,
+        );
+        if count == 0 {
+            return Ok(0);
+        }
+        novel_io::update_existing_novel_metadata_file(novel_root_path, &novel_data)?;
+        if let Some(mut entry) = index_manager::get_novel_entry(self.app_handle, novel_id)? {
+            entry.thumbnail = novel_data.thumbnail;
+            index_manager::upsert_novel_entry(self.app_handle, novel_id.to_string(), entry)?;
+        }
+        Ok(count)
+    }
+
+    /// 에피소드, 위키 페이지, 스냅샷 블록과 소설/위키 썸네일 어디에서도 참조하지 않는 이미지를 삭제합니다.
+    /// 썸네일은 원본 이미지가 참조되는 동안 유지됩니다. `dry_run`이면 삭제하지 않고 대상만 반환합니다.
+    /// 이미지나 그 원본이 유예 기간(`UNUSED_RESOURCE_GRACE_PERIOD`) 안에 수정되었으면 건너뜁니다.
+    pub fn clean_unused_resources(
+        &self,
+        novel_id: &str,
+        dry_run: bool,
+    ) -> MuvelResult<ResourceCleanupResult> {
+        let novel_root_path = index_resolver::resolve_novel_root(self.app_handle, novel_id)?;
+        let referenced = self.collect_referenced_resources(novel_id, &novel_root_path)?;
+
+        let mut result = ResourceCleanupResult {
+            dry_run,
+            ..Default::default()
+        };
+        let now = SystemTime::now();
+        for relative_path in image_resource_io::list_image_resource_files(&novel_root_path)? {
+            let owner_path = image_resource_io::image_relative_path_for(&relative_path)
+                .unwrap_or_else(|| relative_path.clone());
+            if referenced.contains(&owner_path) || referenced.contains(&relative_path) {
+                continue;
+            }
+
+            let resource_path =
+                resource_uri::resolve_resource_path(&novel_root_path, &relative_path);
+            let owner_resource_path =
+                resource_uri::resolve_resource_path(&novel_root_path, &owner_path);
+            if is_recently_modified(&resource_path, now)
+                || (owner_resource_path.is_file()
+                    && is_recently_modified(&owner_resource_path, now))
+            {
+                result.skipped_recent_count += 1;
+                continue;
+            }
+            let size = fs::metadata(&resource_path).map_or(0, |m| m.len());
+            if !dry_run {
+                novel_watcher::mark_internal_change(&resource_path);
+                fs::remove_file(&resource_path).map_err(|e| {
+                    MuvelError::io(Message::FileDeleteFailed(FileKind::Image), &e)
+                        .with_path(&resource_path)
+                })?;
+            }
+            result.removed_files.push(to_slash_path(&relative_path));
+            result.freed_bytes += size;
+        }
+        Ok(result)
+    }
+
+    /// 소설 안에서 참조되는 모든 리소스의 상대 경로를 모읍니다.
+    /// 하나라도 읽지 못하면 참조를 놓쳐 사용 중인 이미지를 지울 수 있으므로 오류를 반환합니다.
+    fn collect_referenced_resources(
+        &self,
+        novel_id: &str,
+        novel_root_path: &Path,
+    ) -> MuvelResult<HashSet<PathBuf>> {
+        let mut found = HashSet::new();
+
+        let (novel_data, _) = novel_io::read_novel_metadata_with_path(novel_root_path)?;
+        collect_optional_ref(
+            novel_data.thumbnail.as_deref(),
+            novel_id,
+            novel_root_path,
+            &mut found,
+        );
+
+        for summary in episode_io::list_episode_summaries_from_files(novel_root_path)? {
+            let episode_data = episode_io::read_episode_content(novel_root_path, &summary.id)?;
+            collect_block_refs(&episode_data.blocks, novel_id, novel_root_path, &mut found);
+        }
+
+        for summary in wiki_page_io::list_wiki_page_summaries_from_files(novel_root_path)? {
+            let page_data = wiki_page_io::read_wiki_page_content(novel_root_path, &summary.id)?;
+            collect_optional_ref(
+                page_data.thumbnail.as_deref(),
+                novel_id,
+                novel_root_path,
+                &mut found,
+            );
+            collect_block_refs(&page_data.blocks, novel_id, novel_root_path, &mut found);
+        }
+
+        // 스냅샷을 복원했을 때 이미지가 사라지지 않도록 스냅샷의 썸네일과 블록도 확인
+        let snapshot_thumbnails =
+            snapshot_io::collect_referenced_wiki_page_thumbnails(novel_root_path)?
+                .into_iter()
+                .chain(novel_snapshot_io::collect_referenced_thumbnails(
+                    novel_root_path,
+                )?);
+        for thumbnail in snapshot_thumbnails {
+            collect_optional_ref(Some(&thumbnail), novel_id, novel_root_path, &mut found);
+        }
+
+        let mut block_hashes = snapshot_io::collect_referenced_block_hashes(novel_root_path)?;
+        block_hashes.extend(novel_snapshot_io::collect_referenced_block_hashes(
+            novel_root_path,
+        )?);
+        for hash in block_hashes {
+            let block = block_store::get_block(novel_root_path, &BlockRef { hash, order: 0.0 })?;
+            collect_block_refs(
+                std::slice::from_ref(&block),
+                novel_id,
+                novel_root_path,
+                &mut found,
+            );
+        }
+        Ok(found)
+    }
+}
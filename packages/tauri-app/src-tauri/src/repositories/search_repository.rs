@@ -1,13 +1,12 @@
-use std::path::Path;
+use crate::models::error::MuvelResult;
 use tauri::AppHandle;
-// Path를 사용하기 위해 추가
 
 // Novel 관련 모델
 use crate::models::search_results::{
     NovelSearchEpisodeBlockItem, NovelSearchEpisodeItem, NovelSearchItemType, NovelSearchResult,
     NovelSearchWikiBlockItem, NovelSearchWikiPageItem,
 };
-use crate::storage::{episode_io, index_manager, novel_io, wiki_page_io};
+use crate::storage::{episode_io, index_resolver, novel_io, wiki_page_io};
 // 스토리지 접근
 use crate::utils::text_search_utils;
 // 텍스트 검색 유틸리티
@@ -15,7 +14,7 @@ use crate::utils::text_search_utils;
 pub struct SearchRepository<'a> {
     app_handle: &'a AppHandle,
     // NovelRepository를 직접 주입받는 대신, 필요한 정보(예: novel_root_path)는
-    // novel_id를 통해 index_manager에서 가져옵니다.
+    // novel_id를 통해 index_resolver에서 가져옵니다.
 }
 
 impl<'a> SearchRepository<'a> {
@@ -29,16 +28,14 @@ impl<'a> SearchRepository<'a> {
         &self,
         novel_id: &str,
         query: &str,
-    ) -> Result<Vec<NovelSearchResult>, String> {
+    ) -> MuvelResult<Vec<NovelSearchResult>> {
         let mut results: Vec<NovelSearchResult> = Vec::new();
 
         // 1. novel_id로부터 novel_root_path 가져오기
-        let novel_entry = index_manager::get_novel_entry(self.app_handle, novel_id)?
-            .ok_or_else(|| format!("인덱스에서 소설 ID {} 를 찾을 수 없습니다.", novel_id))?;
-        let novel_root_path = Path::new(novel_entry.path.as_ref().ok_or("소설 경로 없음")?);
+        let novel_root_path = index_resolver::resolve_novel_root(self.app_handle, novel_id)?;
 
         // 2. 소설 메타데이터 로드
-        let (novel_data, _) = novel_io::read_novel_metadata_with_path(novel_root_path)?;
+        let (novel_data, _) = novel_io::read_novel_metadata_with_path(&novel_root_path)?;
 
         let search_fn = text_search_utils::contains_ignore_case_whitespace;
         let snippet_fn = text_search_utils::create_snippet;
@@ -58,9 +55,9 @@ impl<'a> SearchRepository<'a> {
         // }
 
         // 4. 에피소드 검색
-        let episode_summaries = episode_io::list_episode_summaries_from_files(novel_root_path)?;
+        let episode_summaries = episode_io::list_episode_summaries_from_files(&novel_root_path)?;
         for summary in &episode_summaries {
-            match episode_io::read_episode_content(novel_root_path, &summary.id) {
+            match episode_io::read_episode_content(&novel_root_path, &summary.id) {
                 Ok(episode_data) => {
                     let mut episode_level_match_found = false;
                     if search_fn(&episode_data.title, query) {
@@ -119,9 +116,9 @@ impl<'a> SearchRepository<'a> {
 
         // 5. 위키 페이지 검색
         let wiki_page_summaries =
-            wiki_page_io::list_wiki_page_summaries_from_files(novel_root_path)?;
+            wiki_page_io::list_wiki_page_summaries_from_files(&novel_root_path)?;
         for summary in &wiki_page_summaries {
-            match wiki_page_io::read_wiki_page_content(novel_root_path, &summary.id) {
+            match wiki_page_io::read_wiki_page_content(&novel_root_path, &summary.id) {
                 Ok(wiki_page_data) => {
                     let mut wiki_page_level_match_found = false;
                     if search_fn(&wiki_page_data.title, query) {
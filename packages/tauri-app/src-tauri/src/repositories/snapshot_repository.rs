@@ -1,11 +1,31 @@
+use crate::i18n::{FileKind, Message};
+use crate::models::error::{MuvelError, MuvelResult};
 use chrono::Utc;
-use std::path::PathBuf;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 use tauri::AppHandle;
 use uuid::Uuid;
 
+use crate::models::block_diff::BlockDiff;
 use crate::models::enums::snapshot_reason::SnapshotReason;
-use crate::models::snapshot::EpisodeSnapshot;
-use crate::storage::{episode_io, index_manager, item_index_manager, snapshot_io}; // episode_io 등 필요
+use crate::models::snapshot::{
+    EpisodeSnapshot, EpisodeSnapshotHeader, NovelSnapshot, NovelSnapshotItemSummary,
+    NovelSnapshotSummary, RestoreNovelSnapshotOptions, WikiPageSnapshot,
+};
+use crate::models::wiki_page::WikiPage;
+use crate::repositories::episode_repository::EpisodeRepository;
+use crate::repositories::wiki_page_repository::WikiPageRepository;
+use crate::storage::{
+    block_store, episode_io, index_manager, index_resolver, item_index_manager, novel_io,
+    novel_snapshot_io, resource_uri, settings_manager, snapshot_io, wiki_page_io,
+}; // episode_io 등 필요
+use crate::utils::{block_diff_utils, delta_block_utils, snapshot_retention_utils};
+
+/// 스냅샷 이름/메모의 앞뒤 공백을 제거하고, 비어 있으면 None으로 바꿉니다.
+fn normalize_snapshot_text(value: String) -> Option<String> {
+    let trimmed = value.trim();
+    (!trimmed.is_empty()).then(|| trimmed.to_string())
+}
 
 pub struct SnapshotRepository<'a> {
     app_handle: &'a AppHandle,
@@ -16,24 +36,25 @@ impl<'a> SnapshotRepository<'a> {
         Self { app_handle }
     }
 
+    /// 아이템(에피소드/위키 페이지) ID로부터 부모 소설의 루트 경로를 알아냅니다.
+    fn get_novel_root_path(&self, item_id: &str) -> MuvelResult<PathBuf> {
+        index_resolver::resolve_item_novel(self.app_handle, item_id)
+            .map(|(novel_root_path, _)| novel_root_path)
+    }
+
+    /// novel_id로 소설의 루트 경로를 알아냅니다.
+    fn get_novel_root_path_by_novel_id(&self, novel_id: &str) -> MuvelResult<PathBuf> {
+        index_resolver::resolve_novel_root(self.app_handle, novel_id)
+    }
+
     /// 특정 에피소드의 현재 내용을 기반으로 새 스냅샷을 생성하고 저장합니다.
     pub fn create_snapshot(
         &self,
         episode_id: &str,
         reason: SnapshotReason,
-    ) -> Result<EpisodeSnapshot, String> {
+    ) -> MuvelResult<EpisodeSnapshot> {
         // 1. episode_id로부터 novel_root_path를 알아낸다.
-        let novel_id = item_index_manager::get_item_entry(self.app_handle, episode_id)?
-            .ok_or_else(|| {
-                format!(
-                    "아이템 인덱스에서 에피소드 ID {}의 부모 소설 정보를 찾을 수 없습니다.",
-                    episode_id
-                )
-            })?
-            .novel_id;
-        let novel_entry = index_manager::get_novel_entry(self.app_handle, &novel_id)?
-            .ok_or_else(|| format!("소설 ID {}를 인덱스에서 찾을 수 없습니다.", novel_id))?;
-        let novel_root_path = PathBuf::from(novel_entry.path.ok_or("소설 경로 없음")?);
+        let novel_root_path = self.get_novel_root_path(episode_id)?;
 
         // 2. 현재 에피소드 데이터를 읽어온다.
         let current_episode_data = episode_io::read_episode_content(&novel_root_path, episode_id)?;
@@ -45,29 +66,665 @@ impl<'a> SnapshotRepository<'a> {
             reason,
             blocks: current_episode_data.blocks, // 현재 에피소드의 블록 복사
             created_at: Utc::now().to_rfc3339(),
+            label: None,
+            note: None,
+            pinned: false,
         };
 
-        // 4. 스냅샷 파일 저장
-        snapshot_io::write_snapshot_file(&novel_root_path, episode_id, &snapshot)?;
+        // 4. 스냅샷 파일 저장 (블록은 블록 저장소에 중복 없이 저장)
+        let settings = settings_manager::load_settings(self.app_handle)?;
+        snapshot_io::write_snapshot_file(
+            &novel_root_path,
+            episode_id,
+            &snapshot,
+            settings.compress_snapshot_blocks,
+        )?;
+
+        // 5. 보관 정책에 따라 오래된 스냅샷 정리 (실패해도 스냅샷 생성은 성공으로 처리)
+        if let Err(e) = self.prune_snapshots(&novel_root_path, episode_id) {
+            eprintln!("스냅샷 정리 실패 (에피소드 ID: {}): {}", episode_id, e);
+        }
 
         Ok(snapshot)
     }
 
+    /// 보관 정책에 따라 오래된 스냅샷을 삭제하고, 삭제된 스냅샷 ID 목록을 반환합니다.
+    fn prune_snapshots(
+        &self,
+        novel_root_path: &Path,
+        episode_id: &str,
+    ) -> MuvelResult<Vec<String>> {
+        let settings = settings_manager::load_settings(self.app_handle)?;
+        let headers = snapshot_io::list_snapshot_headers_for_episode(novel_root_path, episode_id)?;
+        let to_prune = snapshot_retention_utils::select_snapshots_to_prune(
+            &headers,
+            &settings.snapshot_retention,
+            Utc::now(),
+        );
+        for snapshot_id in &to_prune {
+            snapshot_io::delete_snapshot_file(novel_root_path, episode_id, snapshot_id)?;
+        }
+        if !to_prune.is_empty() {
+            self.collect_unreferenced_blocks(novel_root_path)?;
+        }
+        Ok(to_prune)
+    }
+
+    /// 더 이상 어떤 스냅샷도 참조하지 않는 블록을 블록 저장소에서 삭제합니다.
+    /// 에피소드 스냅샷과 소설 스냅샷이 같은 블록 저장소를 공유하므로 양쪽의 참조를 모두 확인합니다.
+    pub fn collect_unreferenced_blocks(&self, novel_root_path: &Path) -> MuvelResult<usize> {
+        let mut referenced_hashes = snapshot_io::collect_referenced_block_hashes(novel_root_path)?;
+        referenced_hashes.extend(novel_snapshot_io::collect_referenced_block_hashes(
+            novel_root_path,
+        )?);
+        block_store::remove_unreferenced_blocks(novel_root_path, &referenced_hashes)
+    }
+
     /// 특정 에피소드에 대한 모든 스냅샷 목록을 가져옵니다.
-    pub fn get_all_snapshots(&self, episode_id: &str) -> Result<Vec<EpisodeSnapshot>, String> {
-        // episode_id로부터 novel_root_path를 알아낸다.
-        let novel_id = item_index_manager::get_item_entry(self.app_handle, episode_id)?
-            .ok_or_else(|| {
-                format!(
-                    "아이템 인덱스에서 에피소드 ID {}의 부모 소설 정보를 찾을 수 없습니다.",
-                    episode_id
+    pub fn get_all_snapshots(&self, episode_id: &str) -> MuvelResult<Vec<EpisodeSnapshot>> {
+        let novel_root_path = self.get_novel_root_path(episode_id)?;
+        snapshot_io::list_snapshots_for_episode(&novel_root_path, episode_id)
+    }
+
+    /// 에피소드의 블록을 특정 스냅샷의 블록으로 되돌립니다.
+    /// 되돌리기 전에 현재 내용을 `Restore` 사유의 스냅샷으로 먼저 저장합니다.
+    pub fn restore_snapshot(&self, episode_id: &str, snapshot_id: &str) -> MuvelResult<()> {
+        let novel_root_path = self.get_novel_root_path(episode_id)?;
+
+        // 1. 복원할 스냅샷을 읽는다. (다른 에피소드의 스냅샷이면 거부)
+        let snapshot = snapshot_io::read_snapshot_file(&novel_root_path, episode_id, snapshot_id)?;
+        if snapshot.episode_id != episode_id {
+            return Err(MuvelError::invalid_input(Message::SnapshotOwnerMismatch {
+                snapshot_id: snapshot_id.to_string(),
+                kind: FileKind::Episode,
+                owner_id: episode_id.to_string(),
+            }));
+        }
+
+        // 2. 현재 내용을 안전 스냅샷으로 보관
+        self.create_snapshot(episode_id, SnapshotReason::Restore)?;
+
+        // 3. 에피소드 블록 교체 및 contentLength 재계산
+        // 블록 수정 시각과 리비전을 갱신하여, 복원 이전 내용을 기준으로 만든 변경분이 거부되도록 함
+        let now = Utc::now().to_rfc3339();
+        let mut episode_data = episode_io::read_episode_content(&novel_root_path, episode_id)?;
+        episode_data.blocks = snapshot.blocks;
+        // 리소스 참조 마이그레이션 이전에 만든 스냅샷의 절대 경로 참조를 리소스 URI로 바꿈
+        resource_uri::relativize_block_refs(
+            &mut episode_data.blocks,
+            &episode_data.novel_id,
+            &novel_root_path,
+        );
+        for block in &mut episode_data.blocks {
+            block.updated_at = Some(now.clone());
+        }
+        episode_data.content_length =
+            delta_block_utils::calculate_content_length(&episode_data.blocks);
+        episode_data.revision += 1;
+        episode_data.updated_at = now;
+        episode_io::write_episode_content(&novel_root_path, episode_id, &episode_data)?;
+
+        // 4. 부모 소설 타임스탬프 갱신
+        let (mut novel_data, _) = novel_io::read_novel_metadata_with_path(&novel_root_path)?;
+        novel_data.updated_at = episode_data.updated_at;
+        novel_io::update_existing_novel_metadata_file(&novel_root_path, &novel_data)?;
+        Ok(())
+    }
+
+    /// 스냅샷과 다른 스냅샷 사이의 블록 차이를 계산합니다.
+    /// `target_snapshot_id`가 없으면 현재 에피소드 내용과 비교합니다.
+    pub fn diff_snapshot(
+        &self,
+        episode_id: &str,
+        base_snapshot_id: &str,
+        target_snapshot_id: Option<&str>,
+    ) -> MuvelResult<BlockDiff> {
+        let novel_root_path = self.get_novel_root_path(episode_id)?;
+        let base_snapshot =
+            snapshot_io::read_snapshot_file(&novel_root_path, episode_id, base_snapshot_id)?;
+
+        let target_blocks = match target_snapshot_id {
+            Some(target_id) => {
+                snapshot_io::read_snapshot_file(&novel_root_path, episode_id, target_id)?.blocks
+            }
+            None => episode_io::read_episode_content(&novel_root_path, episode_id)?.blocks,
+        };
+
+        Ok(block_diff_utils::diff_blocks(
+            &base_snapshot.blocks,
+            &target_blocks,
+        ))
+    }
+
+    /// 스냅샷의 이름(label), 메모(note), 고정(pinned) 여부를 변경합니다.
+    /// `None`인 항목은 그대로 두며, 빈 문자열을 넘기면 이름이나 메모를 지웁니다.
+    pub fn update_snapshot_metadata(
+        &self,
+        episode_id: &str,
+        snapshot_id: &str,
+        label: Option<String>,
+        note: Option<String>,
+        pinned: Option<bool>,
+    ) -> MuvelResult<EpisodeSnapshot> {
+        let novel_root_path = self.get_novel_root_path(episode_id)?;
+        let mut snapshot =
+            snapshot_io::read_snapshot_file(&novel_root_path, episode_id, snapshot_id)?;
+
+        if let Some(label) = label {
+            snapshot.label = normalize_snapshot_text(label);
+        }
+        if let Some(note) = note {
+            snapshot.note = normalize_snapshot_text(note);
+        }
+        if let Some(pinned) = pinned {
+            snapshot.pinned = pinned;
+        }
+
+        // 블록은 이미 저장소에 있으므로 다시 쓰이지 않고 참조만 기록됨
+        let settings = settings_manager::load_settings(self.app_handle)?;
+        snapshot_io::write_snapshot_file(
+            &novel_root_path,
+            episode_id,
+            &snapshot,
+            settings.compress_snapshot_blocks,
+        )?;
+        Ok(snapshot)
+    }
+
+    /// 특정 스냅샷 파일을 삭제합니다.
+    pub fn delete_snapshot(&self, episode_id: &str, snapshot_id: &str) -> MuvelResult<()> {
+        let novel_root_path = self.get_novel_root_path(episode_id)?;
+        snapshot_io::delete_snapshot_file(&novel_root_path, episode_id, snapshot_id)?;
+
+        // 블록 정리 실패는 삭제 자체의 실패로 보지 않음
+        if let Err(e) = self.collect_unreferenced_blocks(&novel_root_path) {
+            eprintln!("블록 저장소 정리 실패 (에피소드 ID: {}): {}", episode_id, e);
+        }
+        Ok(())
+    }
+
+    /// 소설의 현재 상태(.muvl 메타데이터, 모든 에피소드와 위키 페이지)로 소설 스냅샷을 생성합니다.
+    pub fn create_novel_snapshot(
+        &self,
+        novel_id: &str,
+        reason: SnapshotReason,
+        label: Option<String>,
+        note: Option<String>,
+    ) -> MuvelResult<NovelSnapshotSummary> {
+        let novel_root_path = self.get_novel_root_path_by_novel_id(novel_id)?;
+        let (novel, _) = novel_io::read_novel_metadata_with_path(&novel_root_path)?;
+
+        let episodes = episode_io::list_episode_summaries_from_files(&novel_root_path)?
+            .iter()
+            .map(|summary| episode_io::read_episode_content(&novel_root_path, &summary.id))
+            .collect::<MuvelResult<Vec<_>>>()?;
+        let wiki_pages = wiki_page_io::list_wiki_page_summaries_from_files(&novel_root_path)?
+            .iter()
+            .map(|summary| wiki_page_io::read_wiki_page_content(&novel_root_path, &summary.id))
+            .collect::<MuvelResult<Vec<_>>>()?;
+
+        let snapshot = NovelSnapshot {
+            id: Uuid::new_v4().to_string(),
+            novel_id: novel_id.to_string(),
+            reason,
+            label: label.and_then(normalize_snapshot_text),
+            note: note.and_then(normalize_snapshot_text),
+            pinned: false,
+            created_at: Utc::now().to_rfc3339(),
+            novel,
+            episodes,
+            wiki_pages,
+        };
+
+        let settings = settings_manager::load_settings(self.app_handle)?;
+        novel_snapshot_io::write_novel_snapshot_file(
+            &novel_root_path,
+            &snapshot,
+            settings.compress_snapshot_blocks,
+        )?;
+
+        if let Err(e) = self.prune_novel_snapshots(&novel_root_path) {
+            eprintln!("소설 스냅샷 정리 실패 (소설 ID: {}): {}", novel_id, e);
+        }
+
+        Ok(NovelSnapshotSummary {
+            id: snapshot.id,
+            novel_id: snapshot.novel_id,
+            reason: snapshot.reason,
+            label: snapshot.label,
+            note: snapshot.note,
+            pinned: snapshot.pinned,
+            created_at: snapshot.created_at,
+            novel_title: snapshot.novel.title,
+            episodes: snapshot
+                .episodes
+                .into_iter()
+                .map(|e| NovelSnapshotItemSummary {
+                    id: e.id,
+                    title: e.title,
+                })
+                .collect(),
+            wiki_pages: snapshot
+                .wiki_pages
+                .into_iter()
+                .map(|w| NovelSnapshotItemSummary {
+                    id: w.id,
+                    title: w.title,
+                })
+                .collect(),
+        })
+    }
+
+    /// 에피소드 스냅샷과 같은 보관 정책으로 오래된 소설 스냅샷을 삭제합니다. (고정된 스냅샷 제외)
+    fn prune_novel_snapshots(&self, novel_root_path: &Path) -> MuvelResult<Vec<String>> {
+        let settings = settings_manager::load_settings(self.app_handle)?;
+        let headers: Vec<EpisodeSnapshotHeader> =
+            novel_snapshot_io::list_novel_snapshot_summaries(novel_root_path)?
+                .into_iter()
+                .map(|summary| EpisodeSnapshotHeader {
+                    id: summary.id,
+                    reason: summary.reason,
+                    created_at: summary.created_at,
+                    pinned: summary.pinned,
+                })
+                .collect();
+        let to_prune = snapshot_retention_utils::select_snapshots_to_prune(
+            &headers,
+            &settings.snapshot_retention,
+            Utc::now(),
+        );
+        for snapshot_id in &to_prune {
+            novel_snapshot_io::delete_novel_snapshot_file(novel_root_path, snapshot_id)?;
+        }
+        if !to_prune.is_empty() {
+            self.collect_unreferenced_blocks(novel_root_path)?;
+        }
+        Ok(to_prune)
+    }
+
+    /// 소설의 모든 소설 스냅샷 요약 목록을 가져옵니다.
+    pub fn get_all_novel_snapshots(
+        &self,
+        novel_id: &str,
+    ) -> MuvelResult<Vec<NovelSnapshotSummary>> {
+        let novel_root_path = self.get_novel_root_path_by_novel_id(novel_id)?;
+        novel_snapshot_io::list_novel_snapshot_summaries(&novel_root_path)
+    }
+
+    /// 미리보기를 위해 소설 스냅샷 전체(블록 포함)를 가져옵니다.
+    pub fn get_novel_snapshot(
+        &self,
+        novel_id: &str,
+        snapshot_id: &str,
+    ) -> MuvelResult<NovelSnapshot> {
+        let novel_root_path = self.get_novel_root_path_by_novel_id(novel_id)?;
+        let snapshot = novel_snapshot_io::read_novel_snapshot_file(&novel_root_path, snapshot_id)?;
+        if snapshot.novel_id != novel_id {
+            return Err(MuvelError::invalid_input(Message::SnapshotOwnerMismatch {
+                snapshot_id: snapshot_id.to_string(),
+                kind: FileKind::Novel,
+                owner_id: novel_id.to_string(),
+            }));
+        }
+        Ok(snapshot)
+    }
+
+    /// 소설 스냅샷을 복원합니다.
+    ///
+    /// `options`가 없으면 소설 전체를 스냅샷 시점으로 되돌립니다. 이때 스냅샷 이후에 생긴
+    /// 에피소드와 위키 페이지는 삭제됩니다. `options`가 있으면 지정한 항목만 복원하며,
+    /// 현재 삭제된 항목도 다시 만들어집니다.
+    /// 복원 전에 현재 상태를 `Restore` 사유의 소설 스냅샷으로 먼저 저장합니다.
+    pub fn restore_novel_snapshot(
+        &self,
+        novel_id: &str,
+        snapshot_id: &str,
+        options: Option<RestoreNovelSnapshotOptions>,
+    ) -> MuvelResult<()> {
+        let novel_root_path = self.get_novel_root_path_by_novel_id(novel_id)?;
+        let snapshot = self.get_novel_snapshot(novel_id, snapshot_id)?;
+
+        let snapshot_episode_ids: HashSet<&str> =
+            snapshot.episodes.iter().map(|e| e.id.as_str()).collect();
+        let snapshot_wiki_ids: HashSet<&str> =
+            snapshot.wiki_pages.iter().map(|w| w.id.as_str()).collect();
+
+        // 1. 복원 대상 결정 (None이면 전체). 스냅샷에 없는 항목을 지정하면 거부
+        let (restore_novel_metadata, episode_filter, wiki_filter) = match &options {
+            None => (true, None, None),
+            Some(opts) => {
+                if let Some(missing) = opts
+                    .episode_ids
+                    .iter()
+                    .find(|id| !snapshot_episode_ids.contains(id.as_str()))
+                {
+                    return Err(MuvelError::invalid_input(Message::SnapshotItemMissing {
+                        snapshot_id: snapshot_id.to_string(),
+                        kind: FileKind::Episode,
+                        item_id: missing.to_string(),
+                    }));
+                }
+                if let Some(missing) = opts
+                    .wiki_page_ids
+                    .iter()
+                    .find(|id| !snapshot_wiki_ids.contains(id.as_str()))
+                {
+                    return Err(MuvelError::invalid_input(Message::SnapshotItemMissing {
+                        snapshot_id: snapshot_id.to_string(),
+                        kind: FileKind::WikiPage,
+                        item_id: missing.to_string(),
+                    }));
+                }
+                (
+                    opts.novel_metadata,
+                    Some(
+                        opts.episode_ids
+                            .iter()
+                            .map(String::as_str)
+                            .collect::<HashSet<_>>(),
+                    ),
+                    Some(
+                        opts.wiki_page_ids
+                            .iter()
+                            .map(String::as_str)
+                            .collect::<HashSet<_>>(),
+                    ),
                 )
-            })?
-            .novel_id;
-        let novel_entry = index_manager::get_novel_entry(self.app_handle, &novel_id)?
-            .ok_or_else(|| format!("소설 ID {}를 인덱스에서 찾을 수 없습니다.", novel_id))?;
-        let novel_root_path = PathBuf::from(novel_entry.path.ok_or("소설 경로 없음")?);
+            }
+        };
 
-        snapshot_io::list_snapshots_for_episode(&novel_root_path, episode_id)
+        // 2. 현재 상태를 안전 스냅샷으로 보관
+        self.create_novel_snapshot(novel_id, SnapshotReason::Restore, None, None)?;
+        let now = Utc::now().to_rfc3339();
+
+        // 3. 에피소드 복원
+        for episode in &snapshot.episodes {
+            if episode_filter
+                .as_ref()
+                .is_some_and(|ids| !ids.contains(episode.id.as_str()))
+            {
+                continue;
+            }
+            let mut episode_data = episode.clone();
+            episode_data.novel_id = novel_id.to_string();
+            episode_data.updated_at = now.clone();
+            resource_uri::relativize_block_refs(
+                &mut episode_data.blocks,
+                novel_id,
+                &novel_root_path,
+            );
+            // 현재 파일이 있으면 그 리비전 다음 값으로 (복원 이전 기준의 변경분이 거부되도록)
+            episode_data.revision = episode_io::read_episode_content(&novel_root_path, &episode.id)
+                .map_or(episode.revision, |current| {
+                    current.revision.max(episode.revision) + 1
+                });
+            for block in &mut episode_data.blocks {
+                block.updated_at = Some(now.clone());
+            }
+            episode_io::write_episode_content(&novel_root_path, &episode.id, &episode_data)?;
+            item_index_manager::upsert_item_novel_mapping(
+                self.app_handle,
+                episode.id.clone(),
+                novel_id.to_string(),
+                "episode".to_string(),
+            )?;
+        }
+
+        // 4. 위키 페이지 복원
+        for page in &snapshot.wiki_pages {
+            if wiki_filter
+                .as_ref()
+                .is_some_and(|ids| !ids.contains(page.id.as_str()))
+            {
+                continue;
+            }
+            let mut page_data = page.clone();
+            page_data.updated_at = now.clone();
+            resource_uri::relativize_optional_ref(
+                &mut page_data.thumbnail,
+                novel_id,
+                &novel_root_path,
+            );
+            resource_uri::relativize_block_refs(&mut page_data.blocks, novel_id, &novel_root_path);
+            wiki_page_io::write_wiki_page_content(&novel_root_path, &page.id, &page_data)?;
+            item_index_manager::upsert_item_novel_mapping(
+                self.app_handle,
+                page.id.clone(),
+                novel_id.to_string(),
+                "wiki_page".to_string(),
+            )?;
+        }
+
+        // 5. 전체 복원이면 스냅샷 이후에 생긴 항목 삭제 (개별 삭제와 같이 편집 기록, 스냅샷까지 정리)
+        if options.is_none() {
+            let episode_repo = EpisodeRepository::new(self.app_handle);
+            for summary in episode_io::list_episode_summaries_from_files(&novel_root_path)? {
+                if !snapshot_episode_ids.contains(summary.id.as_str()) {
+                    episode_repo.remove_episode_files(&novel_root_path, &summary.id)?;
+                }
+            }
+            let wiki_page_repo = WikiPageRepository::new(self.app_handle);
+            for summary in wiki_page_io::list_wiki_page_summaries_from_files(&novel_root_path)? {
+                if !snapshot_wiki_ids.contains(summary.id.as_str()) {
+                    wiki_page_repo.remove_wiki_page_files(&novel_root_path, &summary.id)?;
+                }
+            }
+            if let Err(e) = self.collect_unreferenced_blocks(&novel_root_path) {
+                eprintln!("블록 저장소 정리 실패 (소설 ID: {}): {}", novel_id, e);
+            }
+        }
+
+        // 6. 소설 메타데이터 복원 및 에피소드 수/타임스탬프 갱신
+        let (mut novel_data, _) = novel_io::read_novel_metadata_with_path(&novel_root_path)?;
+        if restore_novel_metadata {
+            let local_path = novel_data.local_path.clone();
+            novel_data = snapshot.novel.clone();
+            novel_data.id = novel_id.to_string();
+            novel_data.local_path = local_path;
+            resource_uri::relativize_optional_ref(
+                &mut novel_data.thumbnail,
+                novel_id,
+                &novel_root_path,
+            );
+        }
+        novel_data.episode_count =
+            Some(episode_io::list_episode_summaries_from_files(&novel_root_path)?.len() as i32);
+        novel_data.updated_at = now;
+        novel_io::update_existing_novel_metadata_file(&novel_root_path, &novel_data)?;
+
+        if let Some(mut entry) = index_manager::get_novel_entry(self.app_handle, novel_id)? {
+            entry.title = novel_data.title.clone();
+            entry.thumbnail = novel_data.thumbnail.clone();
+            entry.episode_count = novel_data.episode_count;
+            index_manager::upsert_novel_entry(self.app_handle, novel_id.to_string(), entry)?;
+        }
+        Ok(())
+    }
+
+    /// 소설 스냅샷의 이름/메모/고정 여부를 변경합니다. (`update_snapshot_metadata`와 동일한 규칙)
+    pub fn update_novel_snapshot_metadata(
+        &self,
+        novel_id: &str,
+        snapshot_id: &str,
+        label: Option<String>,
+        note: Option<String>,
+        pinned: Option<bool>,
+    ) -> MuvelResult<NovelSnapshotSummary> {
+        let novel_root_path = self.get_novel_root_path_by_novel_id(novel_id)?;
+        let summary =
+            novel_snapshot_io::read_novel_snapshot_summary(&novel_root_path, snapshot_id)?;
+        if summary.novel_id != novel_id {
+            return Err(MuvelError::invalid_input(Message::SnapshotOwnerMismatch {
+                snapshot_id: snapshot_id.to_string(),
+                kind: FileKind::Novel,
+                owner_id: novel_id.to_string(),
+            }));
+        }
+
+        novel_snapshot_io::update_novel_snapshot_metadata(
+            &novel_root_path,
+            snapshot_id,
+            label.map_or(summary.label, normalize_snapshot_text),
+            note.map_or(summary.note, normalize_snapshot_text),
+            pinned.unwrap_or(summary.pinned),
+        )
+    }
+
+    /// 소설 스냅샷 파일을 삭제합니다.
+    pub fn delete_novel_snapshot(&self, novel_id: &str, snapshot_id: &str) -> MuvelResult<()> {
+        let novel_root_path = self.get_novel_root_path_by_novel_id(novel_id)?;
+        novel_snapshot_io::delete_novel_snapshot_file(&novel_root_path, snapshot_id)?;
+
+        if let Err(e) = self.collect_unreferenced_blocks(&novel_root_path) {
+            eprintln!("블록 저장소 정리 실패 (소설 ID: {}): {}", novel_id, e);
+        }
+        Ok(())
+    }
+
+    /// 위키 페이지의 현재 내용(메타데이터와 블록)으로 새 스냅샷을 생성합니다.
+    pub fn create_wiki_page_snapshot(
+        &self,
+        page_id: &str,
+        reason: SnapshotReason,
+    ) -> MuvelResult<WikiPageSnapshot> {
+        let novel_root_path = self.get_novel_root_path(page_id)?;
+        let page = wiki_page_io::read_wiki_page_content(&novel_root_path, page_id)?;
+
+        let snapshot = WikiPageSnapshot {
+            id: Uuid::new_v4().to_string(),
+            wiki_page_id: page_id.to_string(),
+            reason,
+            created_at: Utc::now().to_rfc3339(),
+            label: None,
+            note: None,
+            pinned: false,
+            page,
+        };
+
+        let settings = settings_manager::load_settings(self.app_handle)?;
+        snapshot_io::write_wiki_page_snapshot_file(
+            &novel_root_path,
+            &snapshot,
+            settings.compress_snapshot_blocks,
+        )?;
+
+        if let Err(e) = self.prune_wiki_page_snapshots(&novel_root_path, page_id) {
+            eprintln!(
+                "위키 페이지 스냅샷 정리 실패 (페이지 ID: {}): {}",
+                page_id, e
+            );
+        }
+        Ok(snapshot)
+    }
+
+    /// 보관 정책에 따라 오래된 위키 페이지 스냅샷을 삭제합니다.
+    fn prune_wiki_page_snapshots(
+        &self,
+        novel_root_path: &Path,
+        page_id: &str,
+    ) -> MuvelResult<Vec<String>> {
+        let settings = settings_manager::load_settings(self.app_handle)?;
+        let headers = snapshot_io::list_snapshot_headers_for_wiki_page(novel_root_path, page_id)?;
+        let to_prune = snapshot_retention_utils::select_snapshots_to_prune(
+            &headers,
+            &settings.snapshot_retention,
+            Utc::now(),
+        );
+        for snapshot_id in &to_prune {
+            snapshot_io::delete_wiki_page_snapshot_file(novel_root_path, page_id, snapshot_id)?;
+        }
+        if !to_prune.is_empty() {
+            self.collect_unreferenced_blocks(novel_root_path)?;
+        }
+        Ok(to_prune)
+    }
+
+    /// 특정 위키 페이지의 모든 스냅샷 목록을 가져옵니다.
+    pub fn get_all_wiki_page_snapshots(&self, page_id: &str) -> MuvelResult<Vec<WikiPageSnapshot>> {
+        let novel_root_path = self.get_novel_root_path(page_id)?;
+        snapshot_io::list_snapshots_for_wiki_page(&novel_root_path, page_id)
+    }
+
+    /// 위키 페이지를 스냅샷 시점의 메타데이터와 블록으로 되돌리고, 복원된 페이지를 반환합니다.
+    /// 되돌리기 전에 현재 내용을 `Restore` 사유의 스냅샷으로 먼저 저장합니다.
+    pub fn restore_wiki_page_snapshot(
+        &self,
+        page_id: &str,
+        snapshot_id: &str,
+    ) -> MuvelResult<WikiPage> {
+        let (novel_root_path, novel_id) =
+            index_resolver::resolve_item_novel(self.app_handle, page_id)?;
+        let snapshot =
+            snapshot_io::read_wiki_page_snapshot_file(&novel_root_path, page_id, snapshot_id)?;
+        if snapshot.wiki_page_id != page_id {
+            return Err(MuvelError::invalid_input(Message::SnapshotOwnerMismatch {
+                snapshot_id: snapshot_id.to_string(),
+                kind: FileKind::WikiPage,
+                owner_id: page_id.to_string(),
+            }));
+        }
+
+        self.create_wiki_page_snapshot(page_id, SnapshotReason::Restore)?;
+
+        let current_page = wiki_page_io::read_wiki_page_content(&novel_root_path, page_id)?;
+        let mut page_data = snapshot.page;
+        page_data.id = page_id.to_string();
+        page_data.created_at = current_page.created_at;
+        page_data.updated_at = Utc::now().to_rfc3339();
+        // 리소스 참조 마이그레이션 이전에 만든 스냅샷의 절대 경로 참조를 리소스 URI로 바꿈
+        resource_uri::relativize_optional_ref(
+            &mut page_data.thumbnail,
+            &novel_id,
+            &novel_root_path,
+        );
+        resource_uri::relativize_block_refs(&mut page_data.blocks, &novel_id, &novel_root_path);
+        wiki_page_io::write_wiki_page_content(&novel_root_path, page_id, &page_data)?;
+
+        let (mut novel_data, _) = novel_io::read_novel_metadata_with_path(&novel_root_path)?;
+        novel_data.updated_at = page_data.updated_at.clone();
+        novel_io::update_existing_novel_metadata_file(&novel_root_path, &novel_data)?;
+        Ok(page_data)
+    }
+
+    /// 위키 페이지 스냅샷의 이름/메모/고정 여부를 변경합니다. (`update_snapshot_metadata`와 동일한 규칙)
+    pub fn update_wiki_page_snapshot_metadata(
+        &self,
+        page_id: &str,
+        snapshot_id: &str,
+        label: Option<String>,
+        note: Option<String>,
+        pinned: Option<bool>,
+    ) -> MuvelResult<WikiPageSnapshot> {
+        let novel_root_path = self.get_novel_root_path(page_id)?;
+        let mut snapshot =
+            snapshot_io::read_wiki_page_snapshot_file(&novel_root_path, page_id, snapshot_id)?;
+        if let Some(label) = label {
+            snapshot.label = normalize_snapshot_text(label);
+        }
+        if let Some(note) = note {
+            snapshot.note = normalize_snapshot_text(note);
+        }
+        if let Some(pinned) = pinned {
+            snapshot.pinned = pinned;
+        }
+
+        let settings = settings_manager::load_settings(self.app_handle)?;
+        snapshot_io::write_wiki_page_snapshot_file(
+            &novel_root_path,
+            &snapshot,
+            settings.compress_snapshot_blocks,
+        )?;
+        Ok(snapshot)
+    }
+
+    /// 특정 위키 페이지 스냅샷을 삭제합니다.
+    pub fn delete_wiki_page_snapshot(&self, page_id: &str, snapshot_id: &str) -> MuvelResult<()> {
+        let novel_root_path = self.get_novel_root_path(page_id)?;
+        snapshot_io::delete_wiki_page_snapshot_file(&novel_root_path, page_id, snapshot_id)?;
+
+        if let Err(e) = self.collect_unreferenced_blocks(&novel_root_path) {
+            eprintln!("블록 저장소 정리 실패 (페이지 ID: {}): {}", page_id, e);
+        }
+        Ok(())
     }
 }
@@ -0,0 +1,289 @@
+use crate::i18n::{FileKind, Message};
+use crate::models::error::{MuvelError, MuvelResult};
+use chrono::{DateTime, Utc};
+use std::path::Path;
+use tauri::AppHandle;
+
+use crate::models::block::Block;
+use crate::models::commons::{SyncConflictCopy, SyncConflictKind, SyncConflictMergeResult};
+use crate::models::enums::snapshot_reason::SnapshotReason;
+use crate::models::episode::LocalEpisodeData;
+use crate::models::novel::{Novel, UpdateLocalNovelData};
+use crate::models::wiki_page::WikiPage;
+use crate::repositories::novel_repository::NovelRepository;
+use crate::repositories::snapshot_repository::SnapshotRepository;
+use crate::storage::{
+    durable_io, episode_io, index_resolver, item_index_manager, novel_io, sync_conflict_io,
+    wiki_page_io,
+};
+use crate::utils::delta_block_utils::{self, MergeOutcome};
+
+/// `a` 시각이 `b`보다 나중인지 확인합니다. 어느 한쪽을 해석할 수 없으면 false를 반환합니다.
+fn is_later(a: &str, b: &str) -> bool {
+    match (
+        DateTime::parse_from_rfc3339(a),
+        DateTime::parse_from_rfc3339(b),
+    ) {
+        (Ok(a), Ok(b)) => a > b,
+        _ => false,
+    }
+}
+
+/// 사본의 블록을 원본 블록에 병합합니다. 같은 블록은 더 나중에 수정된 쪽을 따릅니다.
+/// 원본에서 삭제한 것으로 보이는 블록은 되살리지 않고, 그 수를 함께 반환합니다.
+fn merge_copy_blocks(
+    blocks: Vec<Block>,
+    updated_at: &str,
+    copy_blocks: &[Block],
+    copy_updated_at: &str,
+) -> MuvelResult<(MergeOutcome, usize)> {
+    let (deltas, skipped_count) = delta_block_utils::build_copy_merge_deltas(
+        &blocks,
+        updated_at,
+        copy_blocks,
+        copy_updated_at,
+    );
+    Ok((
+        delta_block_utils::merge_delta_blocks(blocks, deltas, true)?,
+        skipped_count,
+    ))
+}
+
+fn id_mismatch(expected: &str, found: &str) -> MuvelError {
+    MuvelError::invalid_input(Message::ConflictCopyIdMismatch {
+        expected: expected.to_string(),
+        found: found.to_string(),
+    })
+}
+
+pub struct SyncConflictRepository<'a> {
+    app_handle: &'a AppHandle,
+}
+
+impl<'a> SyncConflictRepository<'a> {
+    pub fn new(app_handle: &'a AppHandle) -> Self {
+        Self { app_handle }
+    }
+
+    /// 소설 폴더에 있는 동기화 충돌 사본 목록을 가져옵니다.
+    pub fn list_conflicts(&self, novel_id: &str) -> MuvelResult<Vec<SyncConflictCopy>> {
+        let novel_root_path = index_resolver::resolve_novel_root(self.app_handle, novel_id)?;
+        sync_conflict_io::find_conflict_copies(&novel_root_path)
+    }
+
+    /// 충돌 사본을 원본에 병합하고 사본을 삭제합니다.
+    /// 에피소드와 위키 페이지는 블록 단위로 병합하며, 병합 전에 원본을 `Merge` 사유의 스냅샷으로 보관합니다.
+    /// 원본이 없으면 사본을 원본 자리로 옮깁니다.
+    pub fn merge_conflict(
+        &self,
+        novel_id: &str,
+        conflict_path: &Path,
+    ) -> MuvelResult<SyncConflictMergeResult> {
+        let novel_root_path = index_resolver::resolve_novel_root(self.app_handle, novel_id)?;
+        let conflict = sync_conflict_io::inspect_conflict_copy(&novel_root_path, conflict_path)?;
+        let conflict_path = Path::new(&conflict.conflict_path);
+
+        let mut result = SyncConflictMergeResult {
+            kind: conflict.kind,
+            item_id: conflict.item_id.clone(),
+            merged_block_count: 0,
+            kept_block_count: 0,
+            skipped_block_count: 0,
+            metadata_from_copy: false,
+        };
+
+        if !conflict.original_exists {
+            self.restore_missing_original(novel_id, &conflict)?;
+            result.metadata_from_copy = true;
+            return Ok(result);
+        }
+
+        match (conflict.kind, conflict.item_id.as_deref()) {
+            (SyncConflictKind::Episode, Some(episode_id)) => {
+                self.merge_episode(&novel_root_path, episode_id, conflict_path, &mut result)?
+            }
+            (SyncConflictKind::WikiPage, Some(page_id)) => {
+                self.merge_wiki_page(&novel_root_path, page_id, conflict_path, &mut result)?
+            }
+            _ => {
+                self.merge_novel_metadata(novel_id, &novel_root_path, conflict_path, &mut result)?
+            }
+        }
+
+        sync_conflict_io::delete_conflict_copy(conflict_path)?;
+        Ok(result)
+    }
+
+    /// 충돌 사본을 병합하지 않고 삭제합니다. (원본 유지)
+    pub fn discard_conflict(&self, novel_id: &str, conflict_path: &Path) -> MuvelResult<()> {
+        let novel_root_path = index_resolver::resolve_novel_root(self.app_handle, novel_id)?;
+        let conflict = sync_conflict_io::inspect_conflict_copy(&novel_root_path, conflict_path)?;
+        sync_conflict_io::delete_conflict_copy(Path::new(&conflict.conflict_path))
+    }
+
+    /// 원본이 사라지고 사본만 남은 경우, 사본을 원본 자리로 옮기고 아이템 인덱스에 등록합니다.
+    fn restore_missing_original(
+        &self,
+        novel_id: &str,
+        conflict: &SyncConflictCopy,
+    ) -> MuvelResult<()> {
+        let conflict_path = Path::new(&conflict.conflict_path);
+        let item_type = match conflict.kind {
+            SyncConflictKind::Novel => None,
+            SyncConflictKind::Episode => {
+                let data: LocalEpisodeData =
+                    sync_conflict_io::read_conflict_copy(conflict_path, FileKind::Episode)?;
+                Some((data.id, "episode"))
+            }
+            SyncConflictKind::WikiPage => {
+                let data: WikiPage =
+                    sync_conflict_io::read_conflict_copy(conflict_path, FileKind::WikiPage)?;
+                Some((data.id, "wiki_page"))
+            }
+        };
+        if let (Some((found_id, _)), Some(item_id)) = (&item_type, conflict.item_id.as_deref()) {
+            if found_id != item_id {
+                return Err(id_mismatch(item_id, found_id));
+            }
+        }
+
+        durable_io::rename_durably(conflict_path, Path::new(&conflict.original_path))?;
+        if let Some((item_id, item_type)) = item_type {
+            item_index_manager::upsert_item_novel_mapping(
+                self.app_handle,
+                item_id,
+                novel_id.to_string(),
+                item_type.to_string(),
+            )?;
+        }
+        Ok(())
+    }
+
+    fn merge_episode(
+        &self,
+        novel_root_path: &Path,
+        episode_id: &str,
+        conflict_path: &Path,
+        result: &mut SyncConflictMergeResult,
+    ) -> MuvelResult<()> {
+        let copy: LocalEpisodeData =
+            sync_conflict_io::read_conflict_copy(conflict_path, FileKind::Episode)?;
+        if copy.id != episode_id {
+            return Err(id_mismatch(episode_id, &copy.id));
+        }
+
+        SnapshotRepository::new(self.app_handle)
+            .create_snapshot(episode_id, SnapshotReason::Merge)?;
+
+        let mut episode_data = episode_io::read_episode_content(novel_root_path, episode_id)?;
+        if is_later(&copy.updated_at, &episode_data.updated_at) {
+            episode_data.title = copy.title;
+            episode_data.description = copy.description;
+            episode_data.author_comment = copy.author_comment;
+            result.metadata_from_copy = true;
+        }
+
+        let (outcome, skipped_count) = merge_copy_blocks(
+            std::mem::take(&mut episode_data.blocks),
+            &episode_data.updated_at,
+            &copy.blocks,
+            &copy.updated_at,
+        )?;
+        result.merged_block_count = outcome.applied_count;
+        result.kept_block_count = outcome.rejected.len();
+        result.skipped_block_count = skipped_count;
+
+        // 리비전을 올려, 병합 이전 내용을 기준으로 만든 변경분과 편집 기록이 무효가 되도록 함
+        let now = Utc::now().to_rfc3339();
+        episode_data.blocks = outcome.blocks;
+        episode_data.content_length =
+            delta_block_utils::calculate_content_length(&episode_data.blocks);
+        episode_data.revision += 1;
+        episode_data.updated_at = now.clone();
+        episode_io::write_episode_content(novel_root_path, episode_id, &episode_data)?;
+        self.touch_novel(novel_root_path, now)
+    }
+
+    fn merge_wiki_page(
+        &self,
+        novel_root_path: &Path,
+        page_id: &str,
+        conflict_path: &Path,
+        result: &mut SyncConflictMergeResult,
+    ) -> MuvelResult<()> {
+        let copy: WikiPage =
+            sync_conflict_io::read_conflict_copy(conflict_path, FileKind::WikiPage)?;
+        if copy.id != page_id {
+            return Err(id_mismatch(page_id, &copy.id));
+        }
+
+        SnapshotRepository::new(self.app_handle)
+            .create_wiki_page_snapshot(page_id, SnapshotReason::Merge)?;
+
+        let mut page_data = wiki_page_io::read_wiki_page_content(novel_root_path, page_id)?;
+        if is_later(&copy.updated_at, &page_data.updated_at) {
+            page_data.title = copy.title;
+            page_data.summary = copy.summary;
+            page_data.category = copy.category;
+            page_data.tags = copy.tags;
+            page_data.thumbnail = copy.thumbnail;
+            page_data.attributes = copy.attributes;
+            result.metadata_from_copy = true;
+        }
+
+        let (outcome, skipped_count) = merge_copy_blocks(
+            std::mem::take(&mut page_data.blocks),
+            &page_data.updated_at,
+            &copy.blocks,
+            &copy.updated_at,
+        )?;
+        result.merged_block_count = outcome.applied_count;
+        result.kept_block_count = outcome.rejected.len();
+        result.skipped_block_count = skipped_count;
+
+        let now = Utc::now().to_rfc3339();
+        page_data.blocks = outcome.blocks;
+        page_data.updated_at = now.clone();
+        wiki_page_io::write_wiki_page_content(novel_root_path, page_id, &page_data)?;
+        self.touch_novel(novel_root_path, now)
+    }
+
+    /// 소설 메타데이터(.muvl)는 블록이 없으므로, 사본이 더 나중에 수정되었을 때만 제목 등을 사본에서 가져옵니다.
+    fn merge_novel_metadata(
+        &self,
+        novel_id: &str,
+        novel_root_path: &Path,
+        conflict_path: &Path,
+        result: &mut SyncConflictMergeResult,
+    ) -> MuvelResult<()> {
+        let copy: Novel =
+            sync_conflict_io::read_conflict_copy(conflict_path, FileKind::NovelMetadata)?;
+        if copy.id != novel_id {
+            return Err(id_mismatch(novel_id, &copy.id));
+        }
+
+        let (novel_data, _) = novel_io::read_novel_metadata_with_path(novel_root_path)?;
+        if !is_later(&copy.updated_at, &novel_data.updated_at) {
+            return Ok(());
+        }
+        NovelRepository::new(self.app_handle).update_novel_metadata(
+            novel_id,
+            UpdateLocalNovelData {
+                title: Some(copy.title),
+                description: copy.description,
+                tags: copy.tags,
+                thumbnail: copy.thumbnail,
+            },
+        )?;
+        result.metadata_from_copy = true;
+        Ok(())
+    }
+
+    /// 부모 소설의 수정 시각을 갱신합니다.
+    fn touch_novel(&self, novel_root_path: &Path, updated_at: String) -> MuvelResult<()> {
+        let (mut novel_data, _) = novel_io::read_novel_metadata_with_path(novel_root_path)?;
+        novel_data.updated_at = updated_at;
+        novel_io::update_existing_novel_metadata_file(novel_root_path, &novel_data)?;
+        Ok(())
+    }
+}
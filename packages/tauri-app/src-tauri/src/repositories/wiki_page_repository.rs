@@ -1,11 +1,20 @@
+use crate::models::error::MuvelResult;
 use chrono::Utc;
-use std::path::PathBuf;
+use std::path::Path;
 use tauri::AppHandle;
 use uuid::Uuid;
 
+use crate::models::block::DeltaBlock;
+use crate::models::enums::snapshot_reason::SnapshotReason;
 use crate::models::novel::WikiPageSummaryData;
-use crate::models::wiki_page::{CreateWikiPageOptions, UpdateWikiPageData, WikiPage};
-use crate::storage::{index_manager, item_index_manager, novel_io, wiki_page_io};
+use crate::models::wiki_page::{
+    CreateWikiPageOptions, UpdateWikiPageData, WikiPage, WikiPageSyncResult,
+};
+use crate::repositories::snapshot_repository::SnapshotRepository;
+use crate::storage::{
+    attachment_io, index_resolver, item_index_manager, novel_io, snapshot_io, wiki_page_io,
+};
+use crate::utils::delta_block_utils;
 
 pub struct WikiPageRepository<'a> {
     app_handle: &'a AppHandle,
@@ -16,42 +25,13 @@ impl<'a> WikiPageRepository<'a> {
         Self { app_handle }
     }
 
-    fn get_novel_root_path_and_id(
-        &self,
-        id_param: &str,
-        is_novel_id_param: bool,
-    ) -> Result<(PathBuf, String), String> {
-        let novel_id = if is_novel_id_param {
-            id_param.to_string()
-        } else {
-            item_index_manager::get_item_entry(self.app_handle, id_param)?
-                .ok_or_else(|| {
-                    format!(
-                        "아이템 인덱스에서 위키 페이지 ID {} 의 부모 소설 정보를 찾을 수 없습니다.",
-                        id_param
-                    )
-                })?
-                .novel_id
-        };
-
-        let novel_entry = index_manager::get_novel_entry(self.app_handle, &novel_id)?
-            .ok_or_else(|| format!("소설 ID {} 를 인덱스에서 찾을 수 없습니다.", novel_id))?;
-        let novel_root_path_str = novel_entry.path.ok_or_else(|| {
-            format!(
-                "소설 ID {} 에 대한 경로 정보가 인덱스에 없습니다.",
-                novel_id
-            )
-        })?;
-
-        Ok((PathBuf::from(novel_root_path_str), novel_id))
-    }
-
     pub fn create_wiki_page(
         &self,
         novel_id_str: &str,
         options: CreateWikiPageOptions,
-    ) -> Result<WikiPage, String> {
-        let (novel_root_path, novel_id) = self.get_novel_root_path_and_id(novel_id_str, true)?;
+    ) -> MuvelResult<WikiPage> {
+        let novel_root_path = index_resolver::resolve_novel_root(self.app_handle, novel_id_str)?;
+        let novel_id = novel_id_str.to_string();
         wiki_page_io::ensure_wiki_pages_directory_exists(&novel_root_path)?;
 
         let page_id = Uuid::new_v4().to_string();
@@ -86,8 +66,9 @@ impl<'a> WikiPageRepository<'a> {
         Ok(wiki_page_data)
     }
 
-    pub fn get_wiki_page(&self, page_id: &str) -> Result<WikiPage, String> {
-        let (novel_root_path, _novel_id) = self.get_novel_root_path_and_id(page_id, false)?;
+    pub fn get_wiki_page(&self, page_id: &str) -> MuvelResult<WikiPage> {
+        let (novel_root_path, _novel_id) =
+            index_resolver::resolve_item_novel(self.app_handle, page_id)?;
         wiki_page_io::read_wiki_page_content(&novel_root_path, page_id)
     }
 
@@ -95,8 +76,9 @@ impl<'a> WikiPageRepository<'a> {
         &self,
         page_id: &str,
         update_data: UpdateWikiPageData,
-    ) -> Result<WikiPage, String> {
-        let (novel_root_path, _novel_id) = self.get_novel_root_path_and_id(page_id, false)?;
+    ) -> MuvelResult<WikiPage> {
+        let (novel_root_path, _novel_id) =
+            index_resolver::resolve_item_novel(self.app_handle, page_id)?;
         let mut page_data = wiki_page_io::read_wiki_page_content(&novel_root_path, page_id)?;
         let mut changed = false;
 
@@ -132,6 +114,16 @@ impl<'a> WikiPageRepository<'a> {
         }
 
         if changed {
+            // 덮어쓰기 전에 이전 상태를 스냅샷으로 보관 (실패해도 수정은 진행)
+            let snapshot_repo = SnapshotRepository::new(self.app_handle);
+            if let Err(e) = snapshot_repo.create_wiki_page_snapshot(page_id, SnapshotReason::Update)
+            {
+                eprintln!(
+                    "위키 페이지 스냅샷 생성 실패 (페이지 ID: {}): {}",
+                    page_id, e
+                );
+            }
+
             page_data.updated_at = Utc::now().to_rfc3339();
             wiki_page_io::write_wiki_page_content(&novel_root_path, page_id, &page_data)?;
             // novel_io::read_novel_metadata_with_path 사용
@@ -143,10 +135,15 @@ impl<'a> WikiPageRepository<'a> {
         Ok(page_data)
     }
 
-    pub fn delete_wiki_page(&self, page_id: &str) -> Result<(), String> {
-        let (novel_root_path, _novel_id) = self.get_novel_root_path_and_id(page_id, false)?; // novel_id는 현재 사용 안함
-        wiki_page_io::delete_wiki_page_file(&novel_root_path, page_id)?;
-        item_index_manager::remove_item_novel_mapping(self.app_handle, page_id)?;
+    pub fn delete_wiki_page(&self, page_id: &str) -> MuvelResult<()> {
+        let (novel_root_path, _novel_id) =
+            index_resolver::resolve_item_novel(self.app_handle, page_id)?;
+        self.remove_wiki_page_files(&novel_root_path, page_id)?;
+        if let Err(e) =
+            SnapshotRepository::new(self.app_handle).collect_unreferenced_blocks(&novel_root_path)
+        {
+            eprintln!("블록 저장소 정리 실패 (페이지 ID: {}): {}", page_id, e);
+        }
         // novel_io::read_novel_metadata_with_path 사용
         let (mut novel_data, _) = novel_io::read_novel_metadata_with_path(&novel_root_path)?;
         novel_data.updated_at = Utc::now().to_rfc3339();
@@ -154,11 +151,53 @@ impl<'a> WikiPageRepository<'a> {
         Ok(())
     }
 
+    /// 위키 페이지 파일과 스냅샷 폴더를 삭제하고 첨부 파일의 연결과 아이템 인덱스에서 제거합니다.
+    /// 소설 메타데이터 갱신과 블록 저장소 정리는 호출하는 쪽에서 합니다.
+    pub fn remove_wiki_page_files(&self, novel_root_path: &Path, page_id: &str) -> MuvelResult<()> {
+        wiki_page_io::delete_wiki_page_file(novel_root_path, page_id)?;
+        snapshot_io::delete_wiki_page_snapshots_dir(novel_root_path, page_id)?;
+        attachment_io::unlink_item_from_attachments(novel_root_path, page_id)?;
+        item_index_manager::remove_item_novel_mapping(self.app_handle, page_id)
+    }
+
     pub fn list_wiki_page_summaries_for_novel(
         &self,
         novel_id_str: &str,
-    ) -> Result<Vec<WikiPageSummaryData>, String> {
-        let (novel_root_path, _novel_id) = self.get_novel_root_path_and_id(novel_id_str, true)?;
+    ) -> MuvelResult<Vec<WikiPageSummaryData>> {
+        let novel_root_path = index_resolver::resolve_novel_root(self.app_handle, novel_id_str)?;
         wiki_page_io::list_wiki_page_summaries_from_files(&novel_root_path)
     }
+
+    /// 위키 페이지 본문 블록에 변경분(DeltaBlock)을 반영합니다.
+    /// 에피소드의 `sync_delta_blocks`와 같은 병합 로직을 사용하며,
+    /// 페이지와 부모 소설의 `updated_at`을 같은 시각으로 갱신합니다.
+    /// 적용하지 않은 변경분은 결과로 돌려주므로, 클라이언트는 블록을 다시 불러와야 합니다.
+    pub fn sync_delta_blocks(
+        &self,
+        page_id: &str,
+        delta_blocks: Vec<DeltaBlock>,
+    ) -> MuvelResult<WikiPageSyncResult> {
+        let (novel_root_path, _novel_id) =
+            index_resolver::resolve_item_novel(self.app_handle, page_id)?;
+        let mut page_data = wiki_page_io::read_wiki_page_content(&novel_root_path, page_id)?;
+
+        // 위키 페이지에는 리비전이 없으므로 항상 변경분 시각을 기준으로 오래된 변경분을 거부
+        let outcome = delta_block_utils::merge_delta_blocks(page_data.blocks, delta_blocks, true)?;
+        let result = WikiPageSyncResult {
+            applied_count: outcome.applied_count,
+            rejected: outcome.rejected,
+            rebalanced: outcome.rebalanced,
+        };
+        if result.applied_count == 0 && !result.rebalanced {
+            return Ok(result);
+        }
+        page_data.blocks = outcome.blocks;
+        page_data.updated_at = Utc::now().to_rfc3339();
+        wiki_page_io::write_wiki_page_content(&novel_root_path, page_id, &page_data)?;
+
+        let (mut novel_data, _) = novel_io::read_novel_metadata_with_path(&novel_root_path)?;
+        novel_data.updated_at = page_data.updated_at;
+        novel_io::update_existing_novel_metadata_file(&novel_root_path, &novel_data)?;
+        Ok(result)
+    }
 }
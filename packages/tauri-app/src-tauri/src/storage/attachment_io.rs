@@ -0,0 +1,250 @@
+use crate::i18n::{FileKind, Message};
+use crate::models::attachment::AttachmentMetadata;
+use crate::models::error::{MuvelError, MuvelResult};
+use crate::storage::novel_io::RESOURCES_DIRNAME;
+use crate::storage::{durable_io, format_migration, novel_watcher};
+use chrono::Utc;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// 첨부 파일 폴더 (resources 하위). 첨부 파일마다 `{id}` 폴더에 파일과 메타데이터를 함께 둡니다.
+pub const ATTACHMENTS_SUBDIR_IN_RESOURCES: &str = "attachments";
+// 첨부 폴더 안의 메타데이터 파일 이름
+pub const ATTACHMENT_METADATA_FILENAME: &str = "attachment.json";
+// 파일 이름에 쓸 수 없는 문자 (Windows 기준이 가장 엄격하므로 모든 OS에 적용)
+const FORBIDDEN_FILE_NAME_CHARS: &[char] = &['/', '\\', ':', '*', '?', '"', '<', '>', '|'];
+
+fn attachments_dir_path(novel_root_path: &Path) -> PathBuf {
+    novel_root_path
+        .join(RESOURCES_DIRNAME)
+        .join(ATTACHMENTS_SUBDIR_IN_RESOURCES)
+}
+
+fn attachment_dir_path(novel_root_path: &Path, attachment_id: &str) -> PathBuf {
+    attachments_dir_path(novel_root_path).join(attachment_id)
+}
+
+fn metadata_file_path(novel_root_path: &Path, attachment_id: &str) -> PathBuf {
+    attachment_dir_path(novel_root_path, attachment_id).join(ATTACHMENT_METADATA_FILENAME)
+}
+
+/// 첨부 파일의 `resources` 기준 상대 경로 (`attachments/{id}/{파일 이름}`)
+pub fn attachment_relative_path(attachment_id: &str, file_name: &str) -> PathBuf {
+    Path::new(ATTACHMENTS_SUBDIR_IN_RESOURCES)
+        .join(attachment_id)
+        .join(file_name)
+}
+
+/// 사용자가 정한 이름을 모든 OS에서 쓸 수 있는 파일 이름으로 바꿉니다.
+/// 쓸 수 없는 문자는 `_`로 바꾸고, 남는 이름이 없으면 None을 반환합니다.
+pub fn sanitize_file_name(name: &str) -> Option<String> {
+    let replaced: String = name
+        .chars()
+        .map(|c| {
+            if c.is_control() || FORBIDDEN_FILE_NAME_CHARS.contains(&c) {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect();
+    // Windows는 끝의 점과 공백을 지우므로 미리 제거
+    let trimmed = replaced.trim().trim_end_matches(['.', ' ']);
+    if trimmed.is_empty() || trimmed.chars().all(|c| c == '.') {
+        return None;
+    }
+    // 메타데이터 파일과 겹치지 않도록 함
+    if trimmed.eq_ignore_ascii_case(ATTACHMENT_METADATA_FILENAME) {
+        return Some(format!("_{}", trimmed));
+    }
+    Some(trimmed.to_string())
+}
+
+/// 원본 파일을 `resources/attachments/{id}/{파일 이름}`에 복사하고 파일 크기를 반환합니다.
+pub fn copy_attachment_file(
+    novel_root_path: &Path,
+    attachment_id: &str,
+    file_name: &str,
+    source_path: &Path,
+) -> MuvelResult<u64> {
+    if !source_path.is_file() {
+        return Err(
+            MuvelError::not_found(Message::FileNotFound(FileKind::Attachment))
+                .with_path(source_path),
+        );
+    }
+    let attachment_dir = attachment_dir_path(novel_root_path, attachment_id);
+    fs::create_dir_all(&attachment_dir).map_err(|e| {
+        MuvelError::io(Message::DirCreateFailed(FileKind::Attachment), &e)
+            .with_path(&attachment_dir)
+    })?;
+    durable_io::copy_file_atomically(
+        source_path,
+        &attachment_dir.join(file_name),
+        FileKind::Attachment,
+    )
+    .map_err(|e| e.context(Message::FileWriteFailed(FileKind::Attachment)))
+}
+
+/// 첨부 파일 메타데이터를 읽습니다.
+pub fn read_attachment_metadata(
+    novel_root_path: &Path,
+    attachment_id: &str,
+) -> MuvelResult<AttachmentMetadata> {
+    let metadata_path = metadata_file_path(novel_root_path, attachment_id);
+    if !metadata_path.is_file() {
+        return Err(
+            MuvelError::not_found(Message::FileNotFound(FileKind::Attachment))
+                .with_path(&metadata_path)
+                .with_id(attachment_id),
+        );
+    }
+    format_migration::read_versioned_file(&metadata_path, FileKind::Attachment)
+        .map_err(|e| e.with_id(attachment_id))
+}
+
+/// 첨부 파일 메타데이터를 저장합니다. 첨부 폴더가 이미 있어야 합니다.
+pub fn write_attachment_metadata(
+    novel_root_path: &Path,
+    metadata: &AttachmentMetadata,
+) -> MuvelResult<()> {
+    let metadata_path = metadata_file_path(novel_root_path, &metadata.id);
+    let json_string = format_migration::to_versioned_json(metadata, FileKind::Attachment)?;
+    durable_io::write_file_atomically(&metadata_path, json_string.as_bytes())
+        .map_err(|e| e.context(Message::FileWriteFailed(FileKind::Attachment)))
+}
+
+/// 소설의 모든 첨부 파일 메타데이터를 생성 시각 순으로 읽습니다.
+/// 메타데이터를 읽을 수 없는 폴더(동기화 중인 폴더 등)는 건너뜁니다.
+pub fn list_attachment_metadata(novel_root_path: &Path) -> MuvelResult<Vec<AttachmentMetadata>> {
+    let attachments_dir = attachments_dir_path(novel_root_path);
+    let mut attachments = Vec::new();
+    if !attachments_dir.is_dir() {
+        return Ok(attachments);
+    }
+
+    for entry in fs::read_dir(&attachments_dir).map_err(|e| {
+        MuvelError::io(Message::DirReadFailed(FileKind::Attachment), &e).with_path(&attachments_dir)
+    })? {
+        let path = entry
+            .map_err(|e| MuvelError::io(Message::DirEntryReadFailed, &e))?
+            .path();
+        let Some(attachment_id) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if !path.join(ATTACHMENT_METADATA_FILENAME).is_file() {
+            continue;
+        }
+        match read_attachment_metadata(novel_root_path, attachment_id) {
+            // 폴더 이름과 ID가 다르면 (폴더를 복사한 경우 등) 경로를 찾을 수 없으므로 제외
+            Ok(metadata) if metadata.id == attachment_id => attachments.push(metadata),
+            Ok(metadata) => eprintln!(
+                "첨부 폴더 이름과 ID가 다릅니다 ({:?}, ID {})",
+                path, metadata.id
+            ),
+            Err(e) => eprintln!("첨부 파일 메타데이터 읽기 실패 ({:?}): {}", path, e),
+        }
+    }
+    attachments.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    Ok(attachments)
+}
+
+/// 삭제한 에피소드나 위키 페이지의 ID를 모든 첨부 파일의 연결 목록에서 지웁니다.
+pub fn unlink_item_from_attachments(novel_root_path: &Path, item_id: &str) -> MuvelResult<()> {
+    for mut metadata in list_attachment_metadata(novel_root_path)? {
+        let link_count = metadata.linked_episode_ids.len() + metadata.linked_wiki_page_ids.len();
+        metadata.linked_episode_ids.retain(|id| id != item_id);
+        metadata.linked_wiki_page_ids.retain(|id| id != item_id);
+        if metadata.linked_episode_ids.len() + metadata.linked_wiki_page_ids.len() == link_count {
+            continue;
+        }
+        metadata.updated_at = Utc::now().to_rfc3339();
+        write_attachment_metadata(novel_root_path, &metadata)?;
+    }
+    Ok(())
+}
+
+/// 첨부 폴더 안의 파일 이름을 바꿉니다.
+pub fn rename_attachment_file(
+    novel_root_path: &Path,
+    attachment_id: &str,
+    old_file_name: &str,
+    new_file_name: &str,
+) -> MuvelResult<()> {
+    if old_file_name == new_file_name {
+        return Ok(());
+    }
+    let attachment_dir = attachment_dir_path(novel_root_path, attachment_id);
+    let old_path = attachment_dir.join(old_file_name);
+    if !old_path.is_file() {
+        return Err(
+            MuvelError::not_found(Message::FileNotFound(FileKind::Attachment))
+                .with_path(&old_path)
+                .with_id(attachment_id),
+        );
+    }
+    novel_watcher::mark_internal_change(&old_path);
+    durable_io::rename_durably(&old_path, &attachment_dir.join(new_file_name))
+}
+
+/// 첨부 폴더(파일과 메타데이터)를 삭제합니다.
+pub fn delete_attachment_dir(novel_root_path: &Path, attachment_id: &str) -> MuvelResult<()> {
+    let attachment_dir = attachment_dir_path(novel_root_path, attachment_id);
+    if !attachment_dir.is_dir() {
+        eprintln!(
+            "삭제할 첨부 폴더가 존재하지 않거나 폴더가 아닙니다: {:?}",
+            attachment_dir
+        );
+        return Ok(());
+    }
+    if let Ok(entries) = fs::read_dir(&attachment_dir) {
+        for entry in entries.flatten() {
+            novel_watcher::mark_internal_change(&entry.path());
+        }
+    }
+    novel_watcher::mark_internal_change(&attachment_dir);
+    fs::remove_dir_all(&attachment_dir).map_err(|e| {
+        MuvelError::io(Message::DirDeleteFailed(FileKind::Attachment), &e)
+            .with_path(&attachment_dir)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanitize_file_name_replaces_forbidden_chars() {
+        assert_eq!(
+            sanitize_file_name("a/b\\c:d*e?f\"g<h>i|j\u{7}.txt").as_deref(),
+            Some("a_b_c_d_e_f_g_h_i_j_.txt")
+        );
+        assert_eq!(
+            sanitize_file_name("../secret.txt").as_deref(),
+            Some(".._secret.txt")
+        );
+    }
+
+    #[test]
+    fn sanitize_file_name_trims_trailing_dots_and_spaces() {
+        assert_eq!(
+            sanitize_file_name("  지도.png . ").as_deref(),
+            Some("지도.png")
+        );
+    }
+
+    #[test]
+    fn sanitize_file_name_rejects_empty_names() {
+        for name in ["", "   ", ".", "..", ". . ."] {
+            assert_eq!(sanitize_file_name(name), None, "{:?}", name);
+        }
+    }
+
+    #[test]
+    fn sanitize_file_name_avoids_metadata_file_name() {
+        assert_eq!(
+            sanitize_file_name("Attachment.JSON").as_deref(),
+            Some("_Attachment.JSON")
+        );
+    }
+}
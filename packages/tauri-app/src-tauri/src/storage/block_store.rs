@@ -0,0 +1,204 @@
+use crate::i18n::{FileKind, Message};
+use crate::models::block::Block;
+use crate::models::error::{MuvelError, MuvelResult};
+use crate::storage::durable_io;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+// 블록 저장소 폴더 이름 (소설 루트 하위)
+pub const BLOCK_STORE_DIRNAME: &str = "blockstore";
+const BLOCK_FILE_EXTENSION: &str = "json";
+const COMPRESSED_BLOCK_FILE_EXTENSION: &str = "json.gz";
+
+/// 스냅샷 등에서 블록 저장소의 블록을 참조할 때 사용하는 구조체
+/// 블록 순서(order)는 해시에 포함하지 않고 참조 쪽에 보관하여,
+/// 순서만 바뀐 블록도 같은 저장소 객체를 공유하도록 합니다.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockRef {
+    pub hash: String,
+    pub order: f64,
+}
+
+fn get_block_store_dir_path(novel_root_path: &Path) -> PathBuf {
+    novel_root_path.join(BLOCK_STORE_DIRNAME)
+}
+
+/// 해시 앞 두 글자를 하위 폴더로 사용하여 한 폴더에 파일이 몰리지 않도록 합니다.
+/// 예: {novel_root_path}/blockstore/ab/abcdef....json.gz
+fn get_block_file_path(novel_root_path: &Path, hash: &str, compressed: bool) -> PathBuf {
+    let extension = if compressed {
+        COMPRESSED_BLOCK_FILE_EXTENSION
+    } else {
+        BLOCK_FILE_EXTENSION
+    };
+    get_block_store_dir_path(novel_root_path)
+        .join(&hash[..2.min(hash.len())])
+        .join(format!("{}.{}", hash, extension))
+}
+
+/// order를 제외한 블록 JSON의 SHA-256 해시와 직렬화된 바이트를 반환합니다.
+fn serialize_block_for_store(block: &Block) -> MuvelResult<(String, Vec<u8>)> {
+    let mut normalized = block.clone();
+    normalized.order = 0.0;
+    let bytes = serde_json::to_vec(&normalized).map_err(|e| {
+        MuvelError::internal(Message::SerializeFailed(FileKind::Block))
+            .caused_by(e)
+            .with_id(block.id.clone())
+    })?;
+    let hash = Sha256::digest(&bytes)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    Ok((hash, bytes))
+}
+
+/// 블록을 저장소에 저장하고 참조를 반환합니다. 같은 내용의 블록이 이미 있으면 다시 쓰지 않습니다.
+pub fn put_block(novel_root_path: &Path, block: &Block, compress: bool) -> MuvelResult<BlockRef> {
+    let (hash, bytes) = serialize_block_for_store(block)?;
+    let block_ref = BlockRef {
+        hash,
+        order: block.order,
+    };
+
+    if get_block_file_path(novel_root_path, &block_ref.hash, true).exists()
+        || get_block_file_path(novel_root_path, &block_ref.hash, false).exists()
+    {
+        return Ok(block_ref);
+    }
+
+    let block_file_path = get_block_file_path(novel_root_path, &block_ref.hash, compress);
+    if let Some(parent_dir) = block_file_path.parent() {
+        fs::create_dir_all(parent_dir).map_err(|e| {
+            MuvelError::io(Message::DirCreateFailed(FileKind::Block), &e).with_path(parent_dir)
+        })?;
+    }
+
+    let file_bytes = if compress {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(&bytes)
+            .and_then(|_| encoder.finish())
+            .map_err(|e| MuvelError::io(Message::BlockCompressFailed, &e))?
+    } else {
+        bytes
+    };
+
+    durable_io::write_file_atomically(&block_file_path, &file_bytes)
+        .map_err(|e| e.context(Message::FileWriteFailed(FileKind::Block)))?;
+
+    Ok(block_ref)
+}
+
+/// 참조가 가리키는 블록을 저장소에서 읽어 반환합니다. (압축/비압축 모두 지원)
+pub fn get_block(novel_root_path: &Path, block_ref: &BlockRef) -> MuvelResult<Block> {
+    let compressed_path = get_block_file_path(novel_root_path, &block_ref.hash, true);
+    let plain_path = get_block_file_path(novel_root_path, &block_ref.hash, false);
+
+    let mut file_content = String::new();
+    if compressed_path.exists() {
+        let file = fs::File::open(&compressed_path).map_err(|e| {
+            MuvelError::io(Message::FileOpenFailed(FileKind::Block), &e).with_path(&compressed_path)
+        })?;
+        GzDecoder::new(file)
+            .read_to_string(&mut file_content)
+            .map_err(|e| {
+                MuvelError::io(Message::BlockDecompressFailed, &e).with_id(block_ref.hash.clone())
+            })?;
+    } else if plain_path.exists() {
+        fs::File::open(&plain_path)
+            .and_then(|mut f| f.read_to_string(&mut file_content))
+            .map_err(|e| {
+                MuvelError::io(Message::FileReadFailed(FileKind::Block), &e).with_path(&plain_path)
+            })?;
+    } else {
+        return Err(MuvelError::not_found(Message::BlockNotFoundInStore {
+            hash: block_ref.hash.clone(),
+        })
+        .with_id(&block_ref.hash));
+    }
+
+    let mut block: Block = serde_json::from_str(&file_content).map_err(|e| {
+        MuvelError::parse(Message::FileParseFailed(FileKind::Block))
+            .caused_by(e)
+            .with_id(block_ref.hash.clone())
+    })?;
+    block.order = block_ref.order;
+    Ok(block)
+}
+
+/// 블록 목록을 저장소에 저장하고 참조 목록을 반환합니다.
+pub fn put_blocks(
+    novel_root_path: &Path,
+    blocks: &[Block],
+    compress: bool,
+) -> MuvelResult<Vec<BlockRef>> {
+    blocks
+        .iter()
+        .map(|block| put_block(novel_root_path, block, compress))
+        .collect()
+}
+
+/// 참조 목록이 가리키는 블록들을 저장소에서 읽어 반환합니다.
+pub fn get_blocks(novel_root_path: &Path, block_refs: &[BlockRef]) -> MuvelResult<Vec<Block>> {
+    block_refs
+        .iter()
+        .map(|block_ref| get_block(novel_root_path, block_ref))
+        .collect()
+}
+
+/// 어떤 참조에도 포함되지 않은 블록 파일을 삭제하고, 삭제한 파일 수를 반환합니다.
+pub fn remove_unreferenced_blocks(
+    novel_root_path: &Path,
+    referenced_hashes: &HashSet<String>,
+) -> MuvelResult<usize> {
+    let store_dir = get_block_store_dir_path(novel_root_path);
+    if !store_dir.is_dir() {
+        return Ok(0);
+    }
+
+    let mut removed_count = 0;
+    for prefix_entry in fs::read_dir(&store_dir).map_err(|e| {
+        MuvelError::io(Message::DirReadFailed(FileKind::Block), &e).with_path(&store_dir)
+    })? {
+        let prefix_path = prefix_entry
+            .map_err(|e| MuvelError::io(Message::DirEntryReadFailed, &e))?
+            .path();
+        if !prefix_path.is_dir() {
+            continue;
+        }
+
+        for entry in fs::read_dir(&prefix_path).map_err(|e| {
+            MuvelError::io(Message::DirReadFailed(FileKind::Block), &e).with_path(&prefix_path)
+        })? {
+            let path = entry
+                .map_err(|e| MuvelError::io(Message::DirEntryReadFailed, &e))?
+                .path();
+            let file_name = match path.file_name().and_then(|n| n.to_str()) {
+                Some(name) => name,
+                None => continue,
+            };
+            let hash = file_name
+                .strip_suffix(&format!(".{}", COMPRESSED_BLOCK_FILE_EXTENSION))
+                .or_else(|| file_name.strip_suffix(&format!(".{}", BLOCK_FILE_EXTENSION)));
+
+            if let Some(hash) = hash {
+                if !referenced_hashes.contains(hash) {
+                    fs::remove_file(&path).map_err(|e| {
+                        MuvelError::io(Message::FileDeleteFailed(FileKind::Block), &e)
+                            .with_path(&path)
+                    })?;
+                    removed_count += 1;
+                }
+            }
+        }
+    }
+    Ok(removed_count)
+}
@@ -0,0 +1,97 @@
+use crate::i18n::{FileKind, Message};
+use crate::models::error::{MuvelError, MuvelResult};
+use crate::storage::novel_watcher;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// 원자적 쓰기 중 사용하는 임시 파일 접미사. 예: `episode.mvle` → `episode.mvle.tmp`
+pub const TEMP_FILE_SUFFIX: &str = ".tmp";
+
+/// 대상 파일에 대응하는 임시 파일 경로를 반환합니다.
+pub fn temp_file_path_for(path: &Path) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(TEMP_FILE_SUFFIX);
+    path.with_file_name(file_name)
+}
+
+/// 디렉토리 항목 변경(생성, 이름 변경)을 디스크에 반영합니다.
+/// 디렉토리를 열 수 없는 플랫폼(Windows)에서는 아무것도 하지 않습니다.
+pub fn sync_directory(dir_path: &Path) -> MuvelResult<()> {
+    #[cfg(unix)]
+    {
+        fs::File::open(dir_path)
+            .and_then(|dir| dir.sync_all())
+            .map_err(|e| MuvelError::io(Message::DirSyncFailed, &e).with_path(dir_path))?;
+    }
+    #[cfg(not(unix))]
+    let _ = dir_path;
+    Ok(())
+}
+
+/// 임시 파일의 이름을 대상 파일로 바꾸고, 바뀐 디렉토리 항목까지 디스크에 반영합니다.
+pub fn rename_durably(from: &Path, to: &Path) -> MuvelResult<()> {
+    novel_watcher::mark_internal_change(to);
+    fs::rename(from, to)
+        .map_err(|e| MuvelError::io(Message::AtomicReplaceFailed, &e).with_path(to))?;
+    match to.parent() {
+        Some(parent) => sync_directory(parent),
+        None => Ok(()),
+    }
+}
+
+/// 파일을 원자적이고 안전하게 씁니다.
+/// 임시 파일에 쓴 뒤 디스크에 동기화(fsync)하고 대상 파일과 교체하므로,
+/// 쓰는 도중 앱이 종료되거나 전원이 꺼져도 대상 파일은 이전 내용 또는 새 내용 중 하나로 남습니다.
+pub fn write_file_atomically(path: &Path, contents: &[u8]) -> MuvelResult<()> {
+    let temp_file_path = temp_file_path_for(path);
+
+    let mut temp_file = fs::File::create(&temp_file_path).map_err(|e| {
+        MuvelError::io(Message::FileWriteFailed(FileKind::TempFile), &e).with_path(&temp_file_path)
+    })?;
+    temp_file
+        .write_all(contents)
+        .and_then(|_| temp_file.sync_all())
+        .map_err(|e| {
+            MuvelError::io(Message::FileWriteFailed(FileKind::TempFile), &e)
+                .with_path(&temp_file_path)
+        })?;
+    drop(temp_file);
+
+    rename_durably(&temp_file_path, path)
+}
+
+/// 파일을 `write_file_atomically`와 같은 방식으로 복사하고, 복사한 크기를 반환합니다.
+/// 원본을 메모리에 한꺼번에 올리지 않으므로 큰 파일에도 쓸 수 있습니다.
+/// 복사에 실패하면 임시 파일을 지웁니다. (`source_kind`는 원본을 읽지 못했을 때의 오류 메시지에 사용)
+pub fn copy_file_atomically(
+    source_path: &Path,
+    path: &Path,
+    source_kind: FileKind,
+) -> MuvelResult<u64> {
+    let mut source_file = fs::File::open(source_path).map_err(|e| {
+        MuvelError::io(Message::FileReadFailed(source_kind), &e).with_path(source_path)
+    })?;
+    let temp_file_path = temp_file_path_for(path);
+    let mut temp_file = fs::File::create(&temp_file_path).map_err(|e| {
+        MuvelError::io(Message::FileWriteFailed(FileKind::TempFile), &e).with_path(&temp_file_path)
+    })?;
+    let copied = io::copy(&mut source_file, &mut temp_file).and_then(|size| {
+        temp_file.sync_all()?;
+        Ok(size)
+    });
+    drop(temp_file);
+    let size = match copied {
+        Ok(size) => size,
+        Err(e) => {
+            let _ = fs::remove_file(&temp_file_path);
+            return Err(
+                MuvelError::io(Message::FileWriteFailed(FileKind::TempFile), &e)
+                    .with_path(&temp_file_path),
+            );
+        }
+    };
+
+    rename_durably(&temp_file_path, path)?;
+    Ok(size)
+}
@@ -0,0 +1,219 @@
+use crate::i18n::{FileKind, Message};
+use crate::models::block::DeltaBlock;
+use crate::models::error::{MuvelError, MuvelResult};
+use crate::storage::durable_io;
+use crate::storage::episode_io::EPISODES_DIRNAME;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+// 에피소드 편집 기록 파일의 확장자 (에피소드 파일과 같은 폴더에 저장)
+pub const EDIT_HISTORY_FILE_EXTENSION: &str = "mvleh"; // Muvel Episode History
+/// 실행 취소 기록으로 보관할 최대 편집 수
+pub const MAX_EDIT_HISTORY_ENTRIES: usize = 100;
+
+/// 한 번에 적용된 변경분 묶음과 이를 되돌리는 변경분
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EditHistoryEntry {
+    pub created_at: String,
+    pub deltas: Vec<DeltaBlock>,
+    pub inverse_deltas: Vec<DeltaBlock>,
+}
+
+/// 에피소드의 실행 취소/다시 실행 기록 (기록 파일을 처음부터 적용한 결과)
+#[derive(Debug, Clone, Default)]
+pub struct EpisodeEditHistory {
+    /// 기록이 마지막으로 반영된 에피소드 리비전.
+    /// 에피소드 리비전과 다르면 기록 밖에서 내용이 바뀐 것이므로(스냅샷 복원 등) 기록을 버립니다.
+    pub revision: u64,
+    pub undo_stack: Vec<EditHistoryEntry>,
+    pub redo_stack: Vec<EditHistoryEntry>,
+}
+
+/// 편집 기록 파일에 한 줄(JSON)씩 추가되는 항목. 저널처럼 파일 끝에만 추가합니다.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum EditHistoryRecord {
+    /// 압축된 기록 전체. 파일을 새로 쓸 때 첫 줄에만 옵니다.
+    #[serde(rename_all = "camelCase")]
+    Checkpoint {
+        revision: u64,
+        undo_stack: Vec<EditHistoryEntry>,
+        redo_stack: Vec<EditHistoryEntry>,
+    },
+    /// 새 편집이 적용됨 (다시 실행 기록은 비워짐)
+    Edit {
+        revision: u64,
+        entry: EditHistoryEntry,
+    },
+    /// order를 다시 매김. 이전 기록의 변경분은 다시 매기기 전 order 기준이므로 모두 버립니다.
+    Rebalance {
+        revision: u64,
+    },
+    Undo {
+        revision: u64,
+    },
+    Redo {
+        revision: u64,
+    },
+}
+
+impl EpisodeEditHistory {
+    /// 기록 항목 하나를 적용합니다. 실행 취소 기록은 최대 `MAX_EDIT_HISTORY_ENTRIES`개까지 유지합니다.
+    pub fn apply(&mut self, record: EditHistoryRecord) {
+        match record {
+            EditHistoryRecord::Checkpoint {
+                revision,
+                undo_stack,
+                redo_stack,
+            } => {
+                self.undo_stack = undo_stack;
+                self.redo_stack = redo_stack;
+                self.revision = revision;
+            }
+            EditHistoryRecord::Edit { revision, entry } => {
+                self.undo_stack.push(entry);
+                if self.undo_stack.len() > MAX_EDIT_HISTORY_ENTRIES {
+                    let overflow = self.undo_stack.len() - MAX_EDIT_HISTORY_ENTRIES;
+                    self.undo_stack.drain(..overflow);
+                }
+                self.redo_stack.clear();
+                self.revision = revision;
+            }
+            EditHistoryRecord::Rebalance { revision } => {
+                self.undo_stack.clear();
+                self.redo_stack.clear();
+                self.revision = revision;
+            }
+            EditHistoryRecord::Undo { revision } => {
+                if let Some(entry) = self.undo_stack.pop() {
+                    self.redo_stack.push(entry);
+                }
+                self.revision = revision;
+            }
+            EditHistoryRecord::Redo { revision } => {
+                if let Some(entry) = self.redo_stack.pop() {
+                    self.undo_stack.push(entry);
+                }
+                self.revision = revision;
+            }
+        }
+    }
+
+    /// 현재 기록 전체를 한 항목으로 나타냅니다. (파일 압축용)
+    fn to_checkpoint(&self) -> EditHistoryRecord {
+        EditHistoryRecord::Checkpoint {
+            revision: self.revision,
+            undo_stack: self.undo_stack.clone(),
+            redo_stack: self.redo_stack.clone(),
+        }
+    }
+}
+
+/// 편집 기록 파일의 전체 경로를 구성합니다.
+/// 예: {novel_root_path}/episodes/{episode_id}.mvleh
+fn get_edit_history_file_path(novel_root_path: &Path, episode_id: &str) -> PathBuf {
+    novel_root_path
+        .join(EPISODES_DIRNAME)
+        .join(format!("{}.{}", episode_id, EDIT_HISTORY_FILE_EXTENSION))
+}
+
+/// 편집 기록 파일의 항목을 처음부터 적용해 기록을 읽습니다.
+/// 파일이 없거나 읽을 수 없는 줄이 있으면 빈 기록을 반환합니다.
+pub fn read_edit_history(novel_root_path: &Path, episode_id: &str) -> EpisodeEditHistory {
+    let history_file_path = get_edit_history_file_path(novel_root_path, episode_id);
+    if !history_file_path.is_file() {
+        return EpisodeEditHistory::default();
+    }
+    match replay_edit_history_file(&history_file_path) {
+        Ok(history) => history,
+        Err(e) => {
+            // 편집 기록은 없어도 되는 데이터이므로 오류 대신 비우고 계속 진행
+            // (쓰는 도중 중단되어 잘린 줄이 있으면 이후 항목의 순서를 믿을 수 없음)
+            eprintln!(
+                "에피소드 {}의 편집 기록을 읽을 수 없어 초기화합니다: {}",
+                episode_id, e
+            );
+            EpisodeEditHistory::default()
+        }
+    }
+}
+
+fn replay_edit_history_file(history_file_path: &Path) -> MuvelResult<EpisodeEditHistory> {
+    let file = fs::File::open(history_file_path).map_err(|e| {
+        MuvelError::io(Message::FileOpenFailed(FileKind::EditHistory), &e)
+            .with_path(history_file_path)
+    })?;
+    let mut history = EpisodeEditHistory::default();
+    for line in BufReader::new(file).lines() {
+        let line = line.map_err(|e| {
+            MuvelError::io(Message::FileReadFailed(FileKind::EditHistory), &e)
+                .with_path(history_file_path)
+        })?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record: EditHistoryRecord = serde_json::from_str(&line).map_err(|e| {
+            MuvelError::parse(Message::FileParseFailed(FileKind::EditHistory))
+                .caused_by(e)
+                .with_path(history_file_path)
+        })?;
+        history.apply(record);
+    }
+    Ok(history)
+}
+
+/// 편집 기록 파일 끝에 항목을 하나 추가합니다. 파일이 없으면 새로 만듭니다.
+pub fn append_edit_history_record(
+    novel_root_path: &Path,
+    episode_id: &str,
+    record: &EditHistoryRecord,
+) -> MuvelResult<()> {
+    let history_file_path = get_edit_history_file_path(novel_root_path, episode_id);
+    let line = serde_json::to_string(record).map_err(|e| {
+        MuvelError::internal(Message::SerializeFailed(FileKind::EditHistory)).caused_by(e)
+    })?;
+
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&history_file_path)
+        .map_err(|e| {
+            MuvelError::io(Message::FileOpenFailed(FileKind::EditHistory), &e)
+                .with_path(&history_file_path)
+        })?;
+    file.write_all(format!("{}\n", line).as_bytes())
+        .and_then(|_| file.sync_data())
+        .map_err(|e| MuvelError::io(Message::FileWriteFailed(FileKind::EditHistory), &e))?;
+    Ok(())
+}
+
+/// 편집 기록 전체를 한 항목으로 압축해 파일을 새로 씁니다.
+/// (저널을 압축할 때, 또는 기록 밖에서 에피소드가 바뀌어 기록을 새로 시작할 때)
+pub fn write_edit_history(
+    novel_root_path: &Path,
+    episode_id: &str,
+    history: &EpisodeEditHistory,
+) -> MuvelResult<()> {
+    let history_file_path = get_edit_history_file_path(novel_root_path, episode_id);
+    let line = serde_json::to_string(&history.to_checkpoint()).map_err(|e| {
+        MuvelError::internal(Message::SerializeFailed(FileKind::EditHistory)).caused_by(e)
+    })?;
+    durable_io::write_file_atomically(&history_file_path, format!("{}\n", line).as_bytes())
+        .map_err(|e| e.context(Message::FileWriteFailed(FileKind::EditHistory)))?;
+    Ok(())
+}
+
+/// 편집 기록 파일을 삭제합니다. (에피소드 삭제 시)
+pub fn delete_edit_history_file(novel_root_path: &Path, episode_id: &str) -> MuvelResult<()> {
+    let history_file_path = get_edit_history_file_path(novel_root_path, episode_id);
+    if history_file_path.is_file() {
+        fs::remove_file(&history_file_path).map_err(|e| {
+            MuvelError::io(Message::FileDeleteFailed(FileKind::EditHistory), &e)
+                .with_path(&history_file_path)
+        })?;
+    }
+    Ok(())
+}
@@ -1,15 +1,96 @@
+use crate::i18n::{FileKind, Message};
 use crate::models::enums::episode_type::EpisodeType; // 기본값을 위해 필요
 use crate::models::episode::LocalEpisodeData;
+use crate::models::error::{MuvelError, MuvelResult};
 use crate::models::novel::EpisodeSummaryData; // EpisodeSummaryData 사용
+use crate::storage::durable_io;
+use crate::storage::episode_journal_io;
+use crate::storage::episode_manifest_io::{self, EpisodeManifestEntry, FileStamp};
+use crate::storage::format_migration;
+use crate::storage::novel_watcher;
+use crate::storage::sync_conflict_io;
+use crate::utils::{block_order_utils, delta_block_utils};
 use serde::Deserialize; // 부분 역직렬화를 위해 필요
+use std::collections::HashMap;
 use std::fs;
-use std::io::{Read, Write};
+use std::io::Read;
 use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
 
 // 에피소드 파일들을 저장할 폴더 이름 (novel_io.rs와 일관성 유지)
 pub const EPISODES_DIRNAME: &str = "episodes";
 // 에피소드 파일의 확장자
-const EPISODE_FILE_EXTENSION: &str = "mvle";
+pub const EPISODE_FILE_EXTENSION: &str = "mvle";
+// 저널을 적용한 에피소드 내용을 기억해 둘 최대 에피소드 수 (동시에 편집하는 에피소드 수 정도면 충분)
+const EPISODE_CACHE_CAPACITY: usize = 8;
+
+/// 저널까지 적용해 읽은 에피소드 내용과, 그때의 에피소드/저널 파일 상태
+struct CachedEpisode {
+    stamps: (FileStamp, Option<FileStamp>),
+    data: LocalEpisodeData,
+    journal_len: usize,
+}
+
+/// 최근에 읽거나 쓴 에피소드 내용 (키: 에피소드 파일 경로)
+/// 동기화할 때마다 에피소드 파일 전체를 읽고 저널을 다시 적용하지 않도록,
+/// 파일 상태가 기록과 같으면 재사용합니다. 앱 밖에서 파일이 바뀌면 상태가 달라지므로 다시 읽습니다.
+static EPISODE_CACHE: Mutex<Option<HashMap<PathBuf, CachedEpisode>>> = Mutex::new(None);
+
+fn cached_episode_content(
+    episode_file_path: &Path,
+    stamps: (FileStamp, Option<FileStamp>),
+) -> Option<(LocalEpisodeData, usize)> {
+    let guard = EPISODE_CACHE.lock().unwrap_or_else(PoisonError::into_inner);
+    let cached = guard.as_ref()?.get(episode_file_path)?;
+    (cached.stamps == stamps).then(|| (cached.data.clone(), cached.journal_len))
+}
+
+fn store_episode_content(
+    episode_file_path: &Path,
+    stamps: Option<(FileStamp, Option<FileStamp>)>,
+    data: &LocalEpisodeData,
+    journal_len: usize,
+) {
+    let mut guard = EPISODE_CACHE.lock().unwrap_or_else(PoisonError::into_inner);
+    let cache = guard.get_or_insert_with(HashMap::new);
+    // 파일 상태를 읽을 수 없으면 기억해 둔 내용도 믿을 수 없으므로 지움
+    let Some(stamps) = stamps else {
+        cache.remove(episode_file_path);
+        return;
+    };
+    if cache.len() >= EPISODE_CACHE_CAPACITY && !cache.contains_key(episode_file_path) {
+        cache.clear();
+    }
+    cache.insert(
+        episode_file_path.to_path_buf(),
+        CachedEpisode {
+            stamps,
+            data: data.clone(),
+            journal_len,
+        },
+    );
+}
+
+fn forget_episode_content(episode_file_path: &Path) {
+    let mut guard = EPISODE_CACHE.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(cache) = guard.as_mut() {
+        cache.remove(episode_file_path);
+    }
+}
+
+/// 저널에 항목을 추가한 뒤의 에피소드 내용을 기억해, 다음 동기화 때 파일을 다시 읽지 않도록 합니다.
+pub fn remember_episode_content(
+    novel_root_path: &Path,
+    data: &LocalEpisodeData,
+    journal_len: usize,
+) {
+    store_episode_content(
+        &get_episode_file_path(novel_root_path, &data.id),
+        read_episode_stamps(novel_root_path, &data.id),
+        data,
+        journal_len,
+    );
+}
 
 /// 주어진 소설 루트 경로와 에피소드 ID를 사용하여 에피소드 파일의 전체 경로를 구성합니다.
 fn get_episode_file_path(novel_root_path: &Path, episode_id: &str) -> PathBuf {
@@ -23,99 +104,120 @@ fn get_episode_file_path(novel_root_path: &Path, episode_id: &str) -> PathBuf {
 pub fn read_episode_content(
     novel_root_path: &Path,
     episode_id: &str,
-) -> Result<LocalEpisodeData, String> {
+) -> MuvelResult<LocalEpisodeData> {
+    read_episode_content_with_journal_len(novel_root_path, episode_id).map(|(data, _)| data)
+}
+
+/// 에피소드를 읽고, 아직 에피소드 파일에 합쳐지지 않은 저널 항목 수를 함께 반환합니다.
+/// (저널 압축 시점을 정할 때 저널을 다시 읽지 않기 위해 사용)
+/// 에피소드/저널 파일이 마지막으로 읽거나 쓴 뒤 그대로이면 기억해 둔 내용을 반환합니다.
+pub fn read_episode_content_with_journal_len(
+    novel_root_path: &Path,
+    episode_id: &str,
+) -> MuvelResult<(LocalEpisodeData, usize)> {
     let episode_file_path = get_episode_file_path(novel_root_path, episode_id);
 
-    if !episode_file_path.exists() {
-        return Err(format!(
-            "에피소드 파일을 찾을 수 없습니다: {:?}",
-            episode_file_path
-        ));
+    // 읽기 전의 파일 상태를 기록하므로, 읽는 도중 파일이 바뀌면 다음에는 다시 읽음
+    let Some(stamps) = read_episode_stamps(novel_root_path, episode_id) else {
+        return Err(
+            MuvelError::not_found(Message::FileNotFound(FileKind::Episode))
+                .with_path(&episode_file_path),
+        );
+    };
+    if let Some(cached) = cached_episode_content(&episode_file_path, stamps) {
+        return Ok(cached);
     }
 
-    let mut file_content = String::new();
-    fs::File::open(&episode_file_path)
-        .map_err(|e| {
-            format!(
-                "에피소드 파일을 열 수 없습니다 (경로: {:?}): {}",
-                episode_file_path, e
-            )
-        })?
-        .read_to_string(&mut file_content)
-        .map_err(|e| format!("에피소드 파일 내용을 읽을 수 없습니다: {}", e))?;
-
-    serde_json::from_str(&file_content).map_err(|e| {
-        format!(
-            "에피소드 파일 JSON 파싱에 실패했습니다 (ID: {}): {}",
-            episode_id, e
-        )
-    })
+    let mut data: LocalEpisodeData =
+        format_migration::read_versioned_file(&episode_file_path, FileKind::Episode)
+            .map_err(|e| e.with_id(episode_id))?;
+
+    let journal_len = replay_journal(novel_root_path, episode_id, &mut data)?;
+
+    // 이전 형식의 order 정리는 형식 마이그레이션에서 한 번만 하므로, 여기서는 메모리에서 정렬만 함
+    block_order_utils::sort_blocks_by_order(&mut data.blocks);
+    store_episode_content(&episode_file_path, Some(stamps), &data, journal_len);
+    Ok((data, journal_len))
+}
+
+/// 아직 에피소드 파일에 압축되지 않은 저널 항목을 순서대로 적용하고, 적용한 항목 수를 반환합니다.
+/// 파일의 리비전 이하인 항목은 이미 반영된 것이므로 건너뜁니다. (압축 도중 중단된 경우)
+fn replay_journal(
+    novel_root_path: &Path,
+    episode_id: &str,
+    data: &mut LocalEpisodeData,
+) -> MuvelResult<usize> {
+    let entries = episode_journal_io::read_journal_entries(novel_root_path, episode_id)?;
+    let mut replayed = 0;
+    for entry in entries {
+        if entry.revision <= data.revision {
+            continue;
+        }
+        let blocks = std::mem::take(&mut data.blocks);
+        // 저널에는 이미 적용이 확정된 변경분만 있으므로 시각 비교 없이 그대로 적용
+        data.blocks = delta_block_utils::merge_delta_blocks(blocks, entry.deltas, false)?.blocks;
+        data.revision = entry.revision;
+        data.updated_at = entry.updated_at;
+        replayed += 1;
+    }
+    if replayed > 0 {
+        data.content_length = delta_block_utils::calculate_content_length(&data.blocks);
+    }
+    Ok(replayed)
 }
 
 /// LocalEpisodeData 객체를 에피소드 파일(.mvle)에 저장(업데이트)합니다.
+/// 전체 내용이 파일에 기록되므로 남아 있던 저널은 삭제됩니다.
 pub fn write_episode_content(
     novel_root_path: &Path,
     episode_id: &str,
     data: &LocalEpisodeData,
-) -> Result<(), String> {
+) -> MuvelResult<()> {
     let episode_file_path = get_episode_file_path(novel_root_path, episode_id);
     let parent_dir = episode_file_path.parent().ok_or_else(|| {
-        format!(
-            "에피소드 파일의 부모 디렉토리를 찾을 수 없습니다: {:?}",
-            episode_file_path
-        )
+        MuvelError::not_found(Message::ParentDirNotFound(FileKind::Episode))
+            .with_path(&episode_file_path)
     })?;
 
     if !parent_dir.exists() {
         fs::create_dir_all(parent_dir).map_err(|e| {
-            format!(
-                "에피소드 저장 디렉토리 생성에 실패했습니다 (경로: {:?}): {}",
-                parent_dir, e
-            )
+            MuvelError::io(Message::DirCreateFailed(FileKind::Episode), &e).with_path(parent_dir)
         })?;
     }
 
-    let temp_file_path =
-        episode_file_path.with_extension(&format!("{}.tmp", EPISODE_FILE_EXTENSION));
+    let json_string = format_migration::to_versioned_json(data, FileKind::Episode)?;
 
-    let mut temp_file = fs::File::create(&temp_file_path).map_err(|e| {
-        format!(
-            "임시 에피소드 파일을 생성할 수 없습니다 (경로: {:?}): {}",
-            temp_file_path, e
-        )
-    })?;
+    durable_io::write_file_atomically(&episode_file_path, json_string.as_bytes())
+        .map_err(|e| e.context(Message::FileWriteFailed(FileKind::Episode)))?;
 
-    let json_string = serde_json::to_string_pretty(data).map_err(|e| {
-        format!(
-            "에피소드 데이터를 JSON으로 직렬화하는 데 실패했습니다: {}",
-            e
-        )
-    })?;
-
-    temp_file
-        .write_all(json_string.as_bytes())
-        .map_err(|e| format!("임시 에피소드 파일에 쓰는 데 실패했습니다: {}", e))?;
-
-    fs::rename(&temp_file_path, &episode_file_path).map_err(|e| {
-        format!(
-            "에피소드 파일을 원자적으로 교체하는 데 실패했습니다 (원본: {:?}, 임시: {:?}): {}",
-            episode_file_path, temp_file_path, e
-        )
-    })?;
+    episode_journal_io::delete_journal_file(novel_root_path, episode_id)?;
+    store_episode_content(
+        &episode_file_path,
+        read_episode_stamps(novel_root_path, episode_id),
+        data,
+        0,
+    );
 
+    episode_manifest_io::update_entry(
+        novel_root_path,
+        episode_id,
+        manifest_entry_for(novel_root_path, episode_id, summary_from_episode_data(data)),
+    );
     Ok(())
 }
 
 /// 특정 로컬 에피소드 파일(.mvle)을 삭제합니다.
-pub fn delete_episode_file(novel_root_path: &Path, episode_id: &str) -> Result<(), String> {
+pub fn delete_episode_file(novel_root_path: &Path, episode_id: &str) -> MuvelResult<()> {
     let episode_file_path = get_episode_file_path(novel_root_path, episode_id);
+    episode_journal_io::delete_journal_file(novel_root_path, episode_id)?;
+    episode_manifest_io::update_entry(novel_root_path, episode_id, None);
+    forget_episode_content(&episode_file_path);
 
     if episode_file_path.exists() && episode_file_path.is_file() {
+        novel_watcher::mark_internal_change(&episode_file_path);
         fs::remove_file(&episode_file_path).map_err(|e| {
-            format!(
-                "에피소드 파일 삭제에 실패했습니다 (경로: {:?}): {}",
-                episode_file_path, e
-            )
+            MuvelError::io(Message::FileDeleteFailed(FileKind::Episode), &e)
+                .with_path(&episode_file_path)
         })?;
         Ok(())
     } else {
@@ -143,11 +245,97 @@ struct PartialEpisodeDataForSummary {
     // blocks, flowDoc 등 용량이 큰 필드는 여기에 포함하지 않음
 }
 
-/// 특정 소설의 `episodes` 디렉토리 내 모든 에피소드 파일에서 요약 정보만 읽어 목록으로 반환합니다.
-/// 용량이 큰 `blocks`, `flowDoc` 필드는 읽지 않습니다 (부분 역직렬화).
+fn summary_from_episode_data(data: &LocalEpisodeData) -> EpisodeSummaryData {
+    EpisodeSummaryData {
+        id: data.id.clone(),
+        title: data.title.clone(),
+        order: data.order,
+        episode_type: data.episode_type.clone(),
+        content_length: Some(data.content_length),
+        created_at: data.created_at.clone(),
+        updated_at: data.updated_at.clone(),
+    }
+}
+
+/// 에피소드 파일과 저널 파일의 현재 상태(수정 시각, 크기)를 읽습니다.
+fn read_episode_stamps(
+    novel_root_path: &Path,
+    episode_id: &str,
+) -> Option<(FileStamp, Option<FileStamp>)> {
+    let episode_stamp = FileStamp::read(&get_episode_file_path(novel_root_path, episode_id))?;
+    let journal_stamp = FileStamp::read(&episode_journal_io::get_journal_file_path(
+        novel_root_path,
+        episode_id,
+    ));
+    Some((episode_stamp, journal_stamp))
+}
+
+/// 현재 파일 상태와 함께 매니페스트에 기록할 항목을 만듭니다. 에피소드 파일이 없으면 None을 반환합니다.
+fn manifest_entry_for(
+    novel_root_path: &Path,
+    episode_id: &str,
+    summary: EpisodeSummaryData,
+) -> Option<EpisodeManifestEntry> {
+    let (episode_stamp, journal_stamp) = read_episode_stamps(novel_root_path, episode_id)?;
+    Some(EpisodeManifestEntry {
+        episode_stamp,
+        journal_stamp,
+        summary,
+    })
+}
+
+/// 에피소드 파일에서 요약 정보를 읽습니다. 읽을 수 없는 파일은 경고를 남기고 None을 반환합니다.
+fn read_episode_summary(
+    novel_root_path: &Path,
+    episode_id: &str,
+    path: &Path,
+) -> Option<EpisodeSummaryData> {
+    // 저널이 남아 있으면 글자 수와 수정 시각이 파일과 다르므로 전체를 읽어 요약 생성
+    if episode_journal_io::journal_exists(novel_root_path, episode_id) {
+        return match read_episode_content(novel_root_path, episode_id) {
+            Ok(data) => Some(summary_from_episode_data(&data)),
+            Err(e) => {
+                eprintln!("에피소드 요약 읽기 실패: {}. 건너뜁니다.", e);
+                None
+            }
+        };
+    }
+
+    let mut file_content = String::new();
+    if fs::File::open(path)
+        .and_then(|mut f| f.read_to_string(&mut file_content))
+        .is_err()
+    {
+        eprintln!("에피소드 요약 읽기 실패 (파일 열기/읽기 오류): {:?}", path);
+        return None;
+    }
+
+    match serde_json::from_str::<PartialEpisodeDataForSummary>(&file_content) {
+        Ok(partial_data) => Some(EpisodeSummaryData {
+            id: partial_data.id,
+            title: partial_data.title,
+            order: partial_data.order,
+            episode_type: partial_data.episode_type,
+            content_length: partial_data.content_length.or(Some(0)), // 파일에 없으면 0으로
+            created_at: partial_data.created_at,
+            updated_at: partial_data.updated_at,
+        }),
+        Err(e) => {
+            eprintln!(
+                "에피소드 요약 정보 JSON 파싱 실패 (파일: {:?}): {}. 건너뜁니다.",
+                path, e
+            );
+            None
+        }
+    }
+}
+
+/// 특정 소설의 `episodes` 디렉토리 내 모든 에피소드의 요약 정보를 목록으로 반환합니다.
+/// 에피소드 요약 매니페스트에 기록된 파일 상태(수정 시각, 크기)가 그대로인 에피소드는 파일을 읽지 않고,
+/// 바뀌었거나 새로 생긴 에피소드만 읽어 매니페스트를 갱신합니다.
 pub fn list_episode_summaries_from_files(
     novel_root_path: &Path,
-) -> Result<Vec<EpisodeSummaryData>, String> {
+) -> MuvelResult<Vec<EpisodeSummaryData>> {
     let episodes_dir_path = novel_root_path.join(EPISODES_DIRNAME);
     let mut episode_summaries = Vec::new();
 
@@ -155,51 +343,61 @@ pub fn list_episode_summaries_from_files(
         return Ok(episode_summaries); // 에피소드 폴더가 없으면 빈 목록 반환
     }
 
+    let mut manifest = episode_manifest_io::read_manifest(novel_root_path);
+    let mut fresh_entries = HashMap::with_capacity(manifest.entries.len());
+    let mut manifest_changed = false;
+
     for entry in fs::read_dir(&episodes_dir_path).map_err(|e| {
-        format!(
-            "에피소드 디렉토리를 읽을 수 없습니다 (경로: {:?}): {}",
-            episodes_dir_path, e
-        )
+        MuvelError::io(Message::DirReadFailed(FileKind::Episode), &e).with_path(&episodes_dir_path)
     })? {
-        let entry = entry.map_err(|e| format!("디렉토리 항목 읽기 실패: {}", e))?;
+        let entry = entry.map_err(|e| MuvelError::io(Message::DirEntryReadFailed, &e))?;
         let path = entry.path();
 
-        if path.is_file()
-            && path
+        if !path.is_file()
+            || path
                 .extension()
-                .map_or(false, |ext| ext == EPISODE_FILE_EXTENSION)
+                .map_or(true, |ext| ext != EPISODE_FILE_EXTENSION)
+            // 동기화 충돌 사본은 원본과 같은 에피소드이므로 목록에 넣지 않음 (sync_conflict_io에서 따로 다룸)
+            || sync_conflict_io::is_conflict_copy(&path)
         {
-            let mut file_content = String::new();
-            if fs::File::open(&path)
-                .and_then(|mut f| f.read_to_string(&mut file_content))
-                .is_err()
-            {
-                eprintln!("에피소드 요약 읽기 실패 (파일 열기/읽기 오류): {:?}", path);
-                continue; // 다음 파일로 넘어감
-            }
+            continue;
+        }
+        let Some(episode_id) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
 
-            match serde_json::from_str::<PartialEpisodeDataForSummary>(&file_content) {
-                Ok(partial_data) => {
-                    episode_summaries.push(EpisodeSummaryData {
-                        id: partial_data.id,
-                        title: partial_data.title,
-                        order: partial_data.order,
-                        episode_type: partial_data.episode_type,
-                        content_length: partial_data.content_length.or(Some(0)), // 파일에 없으면 0으로
-                        created_at: partial_data.created_at,
-                        updated_at: partial_data.updated_at,
-                    });
-                }
-                Err(e) => {
-                    eprintln!(
-                        "에피소드 요약 정보 JSON 파싱 실패 (파일: {:?}): {}. 건너<0xEB><0><0x88>니다.",
-                        path, e
-                    );
-                    // 파싱 실패 시 해당 파일은 건너<0xEB><0><0x88>니다.
+        let stamps = read_episode_stamps(novel_root_path, episode_id);
+        let cached = manifest
+            .entries
+            .remove(episode_id)
+            .filter(|cached| stamps == Some((cached.episode_stamp, cached.journal_stamp)));
+        let manifest_entry = match cached {
+            Some(cached) => cached,
+            None => {
+                manifest_changed = true;
+                let Some(summary) = read_episode_summary(novel_root_path, episode_id, &path) else {
+                    continue; // 다음 파일로 넘어감
+                };
+                // 읽는 도중 파일이 다시 쓰였을 수 있으므로(order 정규화 등) 읽은 뒤의 상태를 기록
+                match manifest_entry_for(novel_root_path, episode_id, summary) {
+                    Some(manifest_entry) => manifest_entry,
+                    None => continue,
                 }
             }
+        };
+
+        episode_summaries.push(manifest_entry.summary.clone());
+        fresh_entries.insert(episode_id.to_string(), manifest_entry);
+    }
+
+    // 남은 항목은 파일이 사라진 에피소드
+    if manifest_changed || !manifest.entries.is_empty() {
+        manifest.entries = fresh_entries;
+        if let Err(e) = episode_manifest_io::write_manifest(novel_root_path, &manifest) {
+            eprintln!("에피소드 요약 매니페스트 저장 실패: {}", e);
         }
     }
+
     // 순서(order)에 따라 정렬
     episode_summaries.sort_by(|a, b| {
         a.order
@@ -0,0 +1,176 @@
+use crate::i18n::{FileKind, Message};
+use crate::models::block::DeltaBlock;
+use crate::models::error::{MuvelError, MuvelResult};
+use crate::storage::episode_io::EPISODES_DIRNAME;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+// 에피소드 편집 저널 파일의 확장자 (에피소드 파일과 같은 폴더에 저장)
+const JOURNAL_FILE_EXTENSION: &str = "mvlej"; // Muvel Episode Journal
+
+/// 저널에 한 줄(JSON)씩 추가되는 항목. 한 번의 블록 동기화 결과를 나타냅니다.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EpisodeJournalEntry {
+    /// 이 항목을 적용한 뒤의 에피소드 리비전
+    pub revision: u64,
+    pub updated_at: String,
+    /// 실제로 적용된 변경분만 저장 (거부된 변경분은 제외)
+    pub deltas: Vec<DeltaBlock>,
+}
+
+/// 에피소드 저널 파일의 전체 경로를 구성합니다.
+/// 예: {novel_root_path}/episodes/{episode_id}.mvlej
+pub fn get_journal_file_path(novel_root_path: &Path, episode_id: &str) -> PathBuf {
+    novel_root_path
+        .join(EPISODES_DIRNAME)
+        .join(format!("{}.{}", episode_id, JOURNAL_FILE_EXTENSION))
+}
+
+/// 에피소드에 아직 압축되지 않은 저널이 있는지 확인합니다.
+pub fn journal_exists(novel_root_path: &Path, episode_id: &str) -> bool {
+    get_journal_file_path(novel_root_path, episode_id).is_file()
+}
+
+/// 저널 파일 끝에 항목을 하나 추가합니다. 파일이 없으면 새로 만듭니다.
+pub fn append_journal_entry(
+    novel_root_path: &Path,
+    episode_id: &str,
+    entry: &EpisodeJournalEntry,
+) -> MuvelResult<()> {
+    let journal_file_path = get_journal_file_path(novel_root_path, episode_id);
+    let line = serde_json::to_string(entry).map_err(|e| {
+        MuvelError::internal(Message::SerializeFailed(FileKind::EpisodeJournal)).caused_by(e)
+    })?;
+
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&journal_file_path)
+        .map_err(|e| {
+            MuvelError::io(Message::FileOpenFailed(FileKind::EpisodeJournal), &e)
+                .with_path(&journal_file_path)
+        })?;
+    // 추가한 항목이 디스크에 기록된 뒤에 동기화 성공으로 응답
+    file.write_all(format!("{}\n", line).as_bytes())
+        .and_then(|_| file.sync_data())
+        .map_err(|e| MuvelError::io(Message::FileWriteFailed(FileKind::EpisodeJournal), &e))?;
+    Ok(())
+}
+
+/// 저널 파일의 모든 항목을 순서대로 읽습니다. 저널이 없으면 빈 목록을 반환합니다.
+/// 쓰는 도중 중단되어 잘린 마지막 줄은 건너뜁니다. 읽을 수 없는 줄 뒤에 항목이 더 있으면
+/// 중간 항목이 손상된 것이므로, 이후 변경분을 잘못된 기준에 적용하지 않도록 오류를 반환합니다.
+pub fn read_journal_entries(
+    novel_root_path: &Path,
+    episode_id: &str,
+) -> MuvelResult<Vec<EpisodeJournalEntry>> {
+    let journal_file_path = get_journal_file_path(novel_root_path, episode_id);
+    let mut entries = Vec::new();
+    if !journal_file_path.is_file() {
+        return Ok(entries);
+    }
+
+    let file = fs::File::open(&journal_file_path).map_err(|e| {
+        MuvelError::io(Message::FileOpenFailed(FileKind::EpisodeJournal), &e)
+            .with_path(&journal_file_path)
+    })?;
+    let mut torn_line = None;
+    for (line_number, line) in BufReader::new(file).lines().enumerate() {
+        let line = line
+            .map_err(|e| MuvelError::io(Message::FileReadFailed(FileKind::EpisodeJournal), &e))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some((line, e)) = torn_line.take() {
+            return Err(MuvelError::parse(Message::JournalLineCorrupted {
+                episode_id: episode_id.to_string(),
+                line,
+            })
+            .caused_by(e)
+            .with_path(&journal_file_path));
+        }
+        match serde_json::from_str::<EpisodeJournalEntry>(&line) {
+            Ok(entry) => entries.push(entry),
+            Err(e) => torn_line = Some((line_number + 1, e)),
+        }
+    }
+    if let Some((line, e)) = torn_line {
+        eprintln!(
+            "에피소드 {} 저널의 마지막 줄({}번째 줄)이 잘려 있어 건너뜁니다: {}",
+            episode_id, line, e
+        );
+    }
+    Ok(entries)
+}
+
+/// 저널 파일을 삭제합니다. (압축 완료 또는 에피소드 삭제 시)
+pub fn delete_journal_file(novel_root_path: &Path, episode_id: &str) -> MuvelResult<()> {
+    let journal_file_path = get_journal_file_path(novel_root_path, episode_id);
+    if journal_file_path.is_file() {
+        fs::remove_file(&journal_file_path).map_err(|e| {
+            MuvelError::io(Message::FileDeleteFailed(FileKind::EpisodeJournal), &e)
+                .with_path(&journal_file_path)
+        })?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 테스트마다 따로 쓰는 임시 소설 폴더를 만듭니다.
+    fn temp_novel_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!(
+            "muvel-journal-test-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join(EPISODES_DIRNAME)).unwrap();
+        root
+    }
+
+    fn entry_line(revision: u64) -> String {
+        serde_json::to_string(&EpisodeJournalEntry {
+            revision,
+            updated_at: "2024-01-01T00:00:00+00:00".to_string(),
+            deltas: Vec::new(),
+        })
+        .unwrap()
+    }
+
+    fn read_with_content(name: &str, content: &str) -> MuvelResult<Vec<EpisodeJournalEntry>> {
+        let root = temp_novel_root(name);
+        fs::write(get_journal_file_path(&root, "episode"), content).unwrap();
+        let result = read_journal_entries(&root, "episode");
+        fs::remove_dir_all(&root).unwrap();
+        result
+    }
+
+    #[test]
+    fn read_journal_entries_ignores_torn_last_line() {
+        let content = format!(
+            "{}\n{}\n{{\"revision\":3,\"upd",
+            entry_line(1),
+            entry_line(2)
+        );
+        let entries = read_with_content("torn", &content).unwrap();
+        assert_eq!(
+            entries.iter().map(|e| e.revision).collect::<Vec<_>>(),
+            [1, 2]
+        );
+    }
+
+    #[test]
+    fn read_journal_entries_rejects_corrupt_middle_line() {
+        let content = format!("{}\nnot json\n{}\n", entry_line(1), entry_line(3));
+        assert!(matches!(
+            read_with_content("corrupt", &content),
+            Err(MuvelError::Parse(_))
+        ));
+    }
+}
@@ -0,0 +1,126 @@
+use crate::i18n::{FileKind, Message};
+use crate::models::error::{MuvelError, MuvelResult};
+use crate::models::novel::EpisodeSummaryData;
+use crate::storage::durable_io;
+use crate::storage::episode_io::EPISODES_DIRNAME;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+// 에피소드 요약 매니페스트 파일 이름 (에피소드 파일과 같은 폴더에 저장)
+pub const EPISODE_MANIFEST_FILENAME: &str = "episode_manifest.json";
+// 매니페스트 형식이 바뀌면 올립니다. 형식이 다른 매니페스트는 버리고 다시 만듭니다.
+const EPISODE_MANIFEST_VERSION: u32 = 1;
+
+/// 파일이 바뀌었는지 판단하기 위한 수정 시각과 크기
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct FileStamp {
+    pub modified_nanos: u64,
+    pub size: u64,
+}
+
+impl FileStamp {
+    /// 파일의 현재 수정 시각과 크기를 읽습니다. 파일이 없으면 None을 반환합니다.
+    pub fn read(path: &Path) -> Option<Self> {
+        let metadata = fs::metadata(path).ok()?;
+        let modified = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+        Some(Self {
+            modified_nanos: modified.as_nanos() as u64,
+            size: metadata.len(),
+        })
+    }
+}
+
+/// 에피소드 하나의 요약과, 요약을 만들 당시의 에피소드/저널 파일 상태
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EpisodeManifestEntry {
+    pub episode_stamp: FileStamp,
+    pub journal_stamp: Option<FileStamp>,
+    pub summary: EpisodeSummaryData,
+}
+
+/// 소설 하나의 에피소드 요약 매니페스트
+///
+/// 에피소드 파일에서 언제든 다시 만들 수 있는 캐시이므로, 읽기/쓰기에 실패해도 오류로 취급하지 않습니다.
+/// 파일 상태가 기록과 다른 항목은 무시되고 에피소드 파일에서 다시 만들어집니다.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EpisodeManifest {
+    pub version: u32,
+    pub entries: HashMap<String, EpisodeManifestEntry>, // 키: 에피소드 ID
+}
+
+impl Default for EpisodeManifest {
+    fn default() -> Self {
+        Self {
+            version: EPISODE_MANIFEST_VERSION,
+            entries: HashMap::new(),
+        }
+    }
+}
+
+/// 매니페스트 파일의 전체 경로를 구성합니다.
+/// 예: {novel_root_path}/episodes/episode_manifest.json
+fn get_manifest_file_path(novel_root_path: &Path) -> PathBuf {
+    novel_root_path
+        .join(EPISODES_DIRNAME)
+        .join(EPISODE_MANIFEST_FILENAME)
+}
+
+/// 매니페스트를 읽습니다. 파일이 없거나 손상되었거나 형식 버전이 다르면 빈 매니페스트를 반환합니다.
+pub fn read_manifest(novel_root_path: &Path) -> EpisodeManifest {
+    let manifest_path = get_manifest_file_path(novel_root_path);
+    let Ok(content) = fs::read_to_string(&manifest_path) else {
+        return EpisodeManifest::default();
+    };
+
+    match serde_json::from_str::<EpisodeManifest>(&content) {
+        Ok(manifest) if manifest.version == EPISODE_MANIFEST_VERSION => manifest,
+        Ok(_) => EpisodeManifest::default(),
+        Err(e) => {
+            eprintln!(
+                "에피소드 요약 매니페스트가 손상되어 다시 만듭니다 ({:?}): {}",
+                manifest_path, e
+            );
+            EpisodeManifest::default()
+        }
+    }
+}
+
+/// 매니페스트를 파일에 저장합니다. (원자적 쓰기 방식)
+pub fn write_manifest(novel_root_path: &Path, manifest: &EpisodeManifest) -> MuvelResult<()> {
+    let manifest_path = get_manifest_file_path(novel_root_path);
+    let json_string = serde_json::to_string(manifest).map_err(|e| {
+        MuvelError::internal(Message::SerializeFailed(FileKind::EpisodeManifest)).caused_by(e)
+    })?;
+    durable_io::write_file_atomically(&manifest_path, json_string.as_bytes())
+        .map_err(|e| e.context(Message::FileWriteFailed(FileKind::EpisodeManifest)))
+}
+
+/// 에피소드 하나의 항목을 바꾸거나(Some) 제거(None)하고 매니페스트를 저장합니다.
+/// 매니페스트가 아직 없으면 만들지 않습니다. (다음 목록 조회 때 전체가 만들어집니다)
+pub fn update_entry(novel_root_path: &Path, episode_id: &str, entry: Option<EpisodeManifestEntry>) {
+    if !get_manifest_file_path(novel_root_path).is_file() {
+        return;
+    }
+
+    let mut manifest = read_manifest(novel_root_path);
+    match entry {
+        Some(entry) => {
+            manifest.entries.insert(episode_id.to_string(), entry);
+        }
+        None => {
+            if manifest.entries.remove(episode_id).is_none() {
+                return;
+            }
+        }
+    }
+
+    if let Err(e) = write_manifest(novel_root_path, &manifest) {
+        eprintln!("에피소드 요약 매니페스트 저장 실패: {}", e);
+    }
+}
@@ -0,0 +1,282 @@
+use crate::i18n::{FileKind, Message};
+use crate::models::block::Block;
+use crate::models::error::{MuvelError, MuvelResult};
+use crate::storage::durable_io;
+use crate::utils::block_order_utils;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 파일 형식 버전이 기록되는 필드 이름. 이 필드가 없는 파일은 버전 0으로 취급합니다.
+pub const FORMAT_VERSION_FIELD: &str = "formatVersion";
+
+/// 파일 내용(JSON 객체)을 한 버전 올리는 마이그레이션
+type MigrationStep = fn(&mut Map<String, Value>);
+
+// 파일 종류별 마이그레이션 목록. `i`번째 항목은 버전 `i`를 `i + 1`로 올리며,
+// 목록의 길이가 현재 형식 버전입니다. 형식을 바꿀 때는 목록 끝에 항목을 추가합니다.
+const NOVEL_MIGRATIONS: &[MigrationStep] = &[novel_v0_to_v1];
+const EPISODE_MIGRATIONS: &[MigrationStep] = &[episode_v0_to_v1];
+const WIKI_PAGE_MIGRATIONS: &[MigrationStep] = &[wiki_page_v0_to_v1];
+const EPISODE_SNAPSHOT_MIGRATIONS: &[MigrationStep] = &[episode_snapshot_v0_to_v1];
+const WIKI_PAGE_SNAPSHOT_MIGRATIONS: &[MigrationStep] = &[wiki_page_snapshot_v0_to_v1];
+const NOVEL_SNAPSHOT_MIGRATIONS: &[MigrationStep] = &[novel_snapshot_v0_to_v1];
+
+fn migrations_for(kind: FileKind) -> &'static [MigrationStep] {
+    match kind {
+        FileKind::NovelMetadata => NOVEL_MIGRATIONS,
+        FileKind::Episode => EPISODE_MIGRATIONS,
+        FileKind::WikiPage => WIKI_PAGE_MIGRATIONS,
+        FileKind::EpisodeSnapshot => EPISODE_SNAPSHOT_MIGRATIONS,
+        FileKind::WikiPageSnapshot => WIKI_PAGE_SNAPSHOT_MIGRATIONS,
+        FileKind::NovelSnapshot => NOVEL_SNAPSHOT_MIGRATIONS,
+        _ => &[],
+    }
+}
+
+/// 이 앱이 쓰는 파일 형식 버전
+pub fn current_format_version(kind: FileKind) -> u32 {
+    migrations_for(kind).len() as u32
+}
+
+fn insert_default(object: &mut Map<String, Value>, key: &str, value: Value) {
+    object.entry(key).or_insert(value);
+}
+
+/// `blocks` 배열을 order 순으로 정렬하고, 정수 order 중복 등 이전 형식의 order를 다시 매깁니다.
+/// 블록을 읽을 수 없으면 그대로 두며, 이후 역직렬화에서 오류로 처리됩니다.
+fn normalize_blocks_field(object: &mut Map<String, Value>) {
+    let Some(blocks_value) = object.get_mut("blocks") else {
+        return;
+    };
+    let Ok(mut blocks) = serde_json::from_value::<Vec<Block>>(blocks_value.clone()) else {
+        return;
+    };
+    block_order_utils::normalize_block_orders(&mut blocks);
+    if let Ok(normalized) = serde_json::to_value(&blocks) {
+        *blocks_value = normalized;
+    }
+}
+
+// --- 버전 0 → 1: 형식 버전이 없던 파일의 누락 필드를 채우고 블록 order를 정리합니다 ---
+
+fn novel_v0_to_v1(object: &mut Map<String, Value>) {
+    // localPath는 소설을 열 때 실제 폴더 경로로 다시 맞춰짐
+    insert_default(object, "localPath", json!(""));
+}
+
+fn episode_v0_to_v1(object: &mut Map<String, Value>) {
+    insert_default(object, "description", json!(""));
+    insert_default(object, "contentLength", json!(0));
+    insert_default(object, "blocks", json!([]));
+    insert_default(object, "revision", json!(0));
+    // 아직 합쳐지지 않은 저널이 있을 수 있으므로 리비전은 올리지 않음
+    normalize_blocks_field(object);
+}
+
+fn wiki_page_v0_to_v1(object: &mut Map<String, Value>) {
+    insert_default(object, "tags", json!([]));
+    insert_default(object, "attributes", json!({}));
+    insert_default(object, "blocks", json!([]));
+    normalize_blocks_field(object);
+}
+
+fn episode_snapshot_v0_to_v1(object: &mut Map<String, Value>) {
+    // 블록 전체를 담은 이전 형식(`blocks`)의 블록 저장소 변환은 snapshot_io에서 읽을 때 처리
+    insert_default(object, "pinned", json!(false));
+}
+
+fn wiki_page_snapshot_v0_to_v1(object: &mut Map<String, Value>) {
+    // 페이지의 블록은 블록 저장소에 있으므로 `page`는 그대로 둠
+    insert_default(object, "pinned", json!(false));
+}
+
+fn novel_snapshot_v0_to_v1(object: &mut Map<String, Value>) {
+    insert_default(object, "pinned", json!(false));
+}
+
+/// 파일 내용을 현재 형식으로 마이그레이션하고, 원래 형식 버전과 함께 반환합니다.
+/// 현재보다 새로운 형식의 파일이면 내용을 잃지 않도록 거부합니다.
+/// `formatVersion`이 있지만 0 이상의 정수가 아니면 해석 오류를 반환합니다.
+pub fn migrate_json(content: &str, kind: FileKind) -> MuvelResult<(Value, u32)> {
+    let mut value: Value = serde_json::from_str(content)
+        .map_err(|e| MuvelError::parse(Message::FileParseFailed(kind)).caused_by(e))?;
+    let Some(object) = value.as_object_mut() else {
+        return Err(MuvelError::parse(Message::FileParseFailed(kind)));
+    };
+
+    // 버전 필드가 없는 파일만 버전 관리 이전(0) 형식으로 보고, 값이 잘못되었으면 거부
+    let version = match object.get(FORMAT_VERSION_FIELD) {
+        None => 0,
+        Some(value) => value
+            .as_u64()
+            .ok_or_else(|| MuvelError::parse(Message::FileParseFailed(kind)))?,
+    };
+    let migrations = migrations_for(kind);
+    let supported = current_format_version(kind);
+    if version > supported as u64 {
+        return Err(MuvelError::unsupported_version(Message::FileFormatTooNew {
+            kind,
+            version,
+            supported,
+        }));
+    }
+
+    for migrate in &migrations[version as usize..] {
+        migrate(object);
+    }
+    object.insert(FORMAT_VERSION_FIELD.to_string(), json!(supported));
+    Ok((value, version as u32))
+}
+
+/// 파일 내용을 현재 형식으로 마이그레이션한 뒤 역직렬화합니다. 파일에 다시 쓰지는 않습니다.
+pub fn from_versioned_str<T: DeserializeOwned>(content: &str, kind: FileKind) -> MuvelResult<T> {
+    let (value, _) = migrate_json(content, kind)?;
+    serde_json::from_value(value)
+        .map_err(|e| MuvelError::parse(Message::FileParseFailed(kind)).caused_by(e))
+}
+
+/// 마이그레이션 전 파일을 보관할 백업 경로 (예: `abc.mvle.v0.bak`)
+fn backup_path_for(path: &Path, version: u32) -> Option<PathBuf> {
+    let file_name = path.file_name()?.to_string_lossy();
+    Some(path.with_file_name(format!("{}.v{}.bak", file_name, version)))
+}
+
+/// 파일을 읽어 현재 형식으로 마이그레이션한 뒤 역직렬화합니다.
+/// 마이그레이션이 일어났으면 원래 파일을 백업한 뒤 현재 형식으로 다시 저장합니다.
+/// (백업이나 저장에 실패해도 읽기는 성공으로 처리하며, 다음에 읽을 때 다시 시도합니다)
+pub fn read_versioned_file<T: DeserializeOwned + Serialize>(
+    path: &Path,
+    kind: FileKind,
+) -> MuvelResult<T> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| MuvelError::io(Message::FileReadFailed(kind), &e).with_path(path))?;
+    let (value, from_version) = migrate_json(&content, kind).map_err(|e| e.with_path(path))?;
+    let data: T = serde_json::from_value(value).map_err(|e| {
+        MuvelError::parse(Message::FileParseFailed(kind))
+            .caused_by(e)
+            .with_path(path)
+    })?;
+
+    let current_version = current_format_version(kind);
+    if from_version < current_version {
+        if let Err(e) = back_up_and_rewrite(path, &content, &data, kind, from_version) {
+            eprintln!(
+                "파일 형식 마이그레이션 저장 실패 ({:?}, 버전 {} → {}): {}",
+                path, from_version, current_version, e
+            );
+        } else {
+            eprintln!(
+                "파일 형식을 마이그레이션했습니다 ({:?}, 버전 {} → {})",
+                path, from_version, current_version
+            );
+        }
+    }
+    Ok(data)
+}
+
+fn back_up_and_rewrite<T: Serialize>(
+    path: &Path,
+    original_content: &str,
+    data: &T,
+    kind: FileKind,
+    from_version: u32,
+) -> MuvelResult<()> {
+    // 같은 버전의 백업이 이미 있으면 (이전에 저장만 실패한 경우) 처음 백업을 유지
+    if let Some(backup_path) = backup_path_for(path, from_version) {
+        if !backup_path.exists() {
+            durable_io::write_file_atomically(&backup_path, original_content.as_bytes())?;
+        }
+    }
+    let json_string = to_versioned_json(data, kind)?;
+    durable_io::write_file_atomically(path, json_string.as_bytes())
+        .map_err(|e| e.context(Message::FileWriteFailed(kind)))
+}
+
+/// 직렬화할 때 현재 형식 버전을 맨 앞에 붙이기 위한 래퍼
+#[derive(Serialize)]
+struct Versioned<'a, T> {
+    #[serde(rename = "formatVersion")]
+    format_version: u32,
+    #[serde(flatten)]
+    data: &'a T,
+}
+
+/// 데이터를 현재 형식 버전이 기록된 JSON 문자열로 직렬화합니다.
+pub fn to_versioned_json<T: Serialize>(data: &T, kind: FileKind) -> MuvelResult<String> {
+    let versioned = Versioned {
+        format_version: current_format_version(kind),
+        data,
+    };
+    serde_json::to_string_pretty(&versioned)
+        .map_err(|e| MuvelError::internal(Message::SerializeFailed(kind)).caused_by(e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migrate_json_fills_defaults_for_unversioned_files() {
+        let (value, version) = migrate_json(r#"{"id":"a"}"#, FileKind::Episode).unwrap();
+        assert_eq!(version, 0);
+        assert_eq!(
+            value[FORMAT_VERSION_FIELD],
+            json!(current_format_version(FileKind::Episode))
+        );
+        assert_eq!(value["blocks"], json!([]));
+        assert_eq!(value["revision"], json!(0));
+    }
+
+    #[test]
+    fn migrate_json_keeps_current_files() {
+        let supported = current_format_version(FileKind::WikiPage);
+        let content = json!({ "id": "a", "formatVersion": supported }).to_string();
+        let (value, version) = migrate_json(&content, FileKind::WikiPage).unwrap();
+        assert_eq!(version, supported);
+        assert!(value.get("tags").is_none());
+    }
+
+    #[test]
+    fn migrate_json_rejects_newer_files() {
+        let content = json!({
+            "formatVersion": current_format_version(FileKind::NovelMetadata) + 1
+        })
+        .to_string();
+        assert!(matches!(
+            migrate_json(&content, FileKind::NovelMetadata),
+            Err(MuvelError::UnsupportedVersion(_))
+        ));
+    }
+
+    #[test]
+    fn migrate_json_rejects_invalid_format_versions() {
+        for content in [
+            r#"{"formatVersion":null}"#,
+            r#"{"formatVersion":"1"}"#,
+            r#"{"formatVersion":-1}"#,
+            r#"{"formatVersion":1.5}"#,
+        ] {
+            assert!(
+                matches!(
+                    migrate_json(content, FileKind::Episode),
+                    Err(MuvelError::Parse(_))
+                ),
+                "{:?}",
+                content
+            );
+        }
+    }
+
+    #[test]
+    fn migrate_json_rejects_non_objects() {
+        for content in ["[]", "1", "not json"] {
+            assert!(matches!(
+                migrate_json(content, FileKind::Episode),
+                Err(MuvelError::Parse(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use crate::i18n::{FileKind, Message};
+use crate::models::error::{MuvelError, MuvelResult};
+use crate::models::settings::ImageResourceSettings;
+use crate::storage::durable_io;
+use crate::storage::novel_io::{IMAGES_SUBDIR_IN_RESOURCES, RESOURCES_DIRNAME};
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageDecoder, ImageFormat, ImageReader, ImageResult};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::Cursor;
+use std::path::{Component, Path, PathBuf};
+
+// 소설 표지, 위키 썸네일 등에 쓰이는 축소 이미지 폴더 (resources 하위, 원본과 같은 파일 이름)
+pub const THUMBNAILS_SUBDIR_IN_RESOURCES: &str = "thumbnails";
+// 썸네일의 가로/세로 최대 크기(px)
+const THUMBNAIL_MAX_DIMENSION: u32 = 320;
+
+fn images_dir_path(novel_root_path: &Path) -> PathBuf {
+    novel_root_path
+        .join(RESOURCES_DIRNAME)
+        .join(IMAGES_SUBDIR_IN_RESOURCES)
+}
+
+fn content_hash(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// 이미지 폴더에서 이름(확장자 제외)이 같은 파일을 찾습니다.
+fn find_image_by_stem(images_dir: &Path, stem: &str) -> Option<String> {
+    fs::read_dir(images_dir)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .find(|path| path.is_file() && path.file_stem().is_some_and(|s| s == stem))
+        .and_then(|path| Some(path.file_name()?.to_str()?.to_string()))
+}
+
+/// 저장할 파일의 확장자. 원래 파일 이름에 없으면 내용으로 형식을 추측합니다.
+fn extension_for(original_file_name: &str, bytes: &[u8]) -> String {
+    Path::new(original_file_name)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_ascii_lowercase)
+        .or_else(|| {
+            image::guess_format(bytes)
+                .ok()
+                .and_then(|format| format.extensions_str().first())
+                .map(|ext| ext.to_string())
+        })
+        .unwrap_or_else(|| "png".to_string())
+}
+
+/// 이미지를 디코딩하고 EXIF 방향 정보를 적용합니다. (다시 인코딩하면 EXIF가 사라지므로)
+fn decode_oriented(bytes: &[u8], format: ImageFormat) -> ImageResult<DynamicImage> {
+    let mut decoder = ImageReader::with_format(Cursor::new(bytes), format).into_decoder()?;
+    let orientation = decoder.orientation()?;
+    let mut image = DynamicImage::from_decoder(decoder)?;
+    image.apply_orientation(orientation);
+    Ok(image)
+}
+
+/// 설정된 최대 크기보다 큰 이미지를 줄여 다시 인코딩하고, 인코딩 결과와 확장자를 반환합니다.
+/// 투명도가 있으면 PNG, 없으면(사진 등) JPEG로 인코딩합니다.
+/// 줄일 필요가 없거나 처리할 수 없는 이미지(SVG, 애니메이션이 사라지는 GIF 등)는 None을 반환합니다.
+fn downscale_image(
+    bytes: &[u8],
+    settings: &ImageResourceSettings,
+) -> ImageResult<Option<(Vec<u8>, &'static str)>> {
+    let Some(max_dimension) = settings.max_dimension else {
+        return Ok(None);
+    };
+    let Ok(format) = image::guess_format(bytes) else {
+        return Ok(None);
+    };
+    if format == ImageFormat::Gif {
+        return Ok(None);
+    }
+    let Ok(decoder) = ImageReader::with_format(Cursor::new(bytes), format).into_decoder() else {
+        return Ok(None);
+    };
+    let (width, height) = decoder.dimensions();
+    if width <= max_dimension && height <= max_dimension {
+        return Ok(None);
+    }
+
+    let resized =
+        decode_oriented(bytes, format)?.resize(max_dimension, max_dimension, FilterType::Lanczos3);
+    let mut encoded = Vec::new();
+    if resized.color().has_alpha() {
+        resized.write_to(&mut Cursor::new(&mut encoded), ImageFormat::Png)?;
+        Ok(Some((encoded, "png")))
+    } else {
+        let quality = settings.jpeg_quality.clamp(1, 100);
+        DynamicImage::ImageRgb8(resized.to_rgb8())
+            .write_with_encoder(JpegEncoder::new_with_quality(&mut encoded, quality))?;
+        Ok(Some((encoded, "jpg")))
+    }
+}
+
+/// 이미지를 `resources/images`에 저장하고, `resources` 기준 상대 경로를 반환합니다.
+/// 파일 이름은 원본 내용의 SHA-256 해시이므로, 같은 이미지를 다시 저장하면 기존 파일을 그대로 사용합니다.
+/// 설정된 최대 크기보다 큰 이미지는 줄여서 저장합니다.
+pub fn save_image(
+    novel_root_path: &Path,
+    original_file_name: &str,
+    file_bytes: Vec<u8>,
+    settings: &ImageResourceSettings,
+) -> MuvelResult<PathBuf> {
+    let images_dir = images_dir_path(novel_root_path);
+    fs::create_dir_all(&images_dir).map_err(|e| {
+        MuvelError::io(Message::DirCreateFailed(FileKind::Image), &e).with_path(&images_dir)
+    })?;
+
+    let hash = content_hash(&file_bytes);
+    if let Some(existing_file_name) = find_image_by_stem(&images_dir, &hash) {
+        return Ok(Path::new(IMAGES_SUBDIR_IN_RESOURCES).join(existing_file_name));
+    }
+
+    let (bytes, extension) = match downscale_image(&file_bytes, settings) {
+        Ok(Some((encoded, extension))) => (encoded, extension.to_string()),
+        Ok(None) => {
+            let extension = extension_for(original_file_name, &file_bytes);
+            (file_bytes, extension)
+        }
+        Err(e) => {
+            // 줄이지 못해도 원본은 저장
+            eprintln!(
+                "이미지를 줄이지 못해 원본을 저장합니다 ({}): {}",
+                original_file_name, e
+            );
+            let extension = extension_for(original_file_name, &file_bytes);
+            (file_bytes, extension)
+        }
+    };
+
+    let file_name = format!("{}.{}", hash, extension);
+    let target_file_path = images_dir.join(&file_name);
+    durable_io::write_file_atomically(&target_file_path, &bytes)
+        .map_err(|e| e.context(Message::FileWriteFailed(FileKind::Image)))?;
+    Ok(Path::new(IMAGES_SUBDIR_IN_RESOURCES).join(file_name))
+}
+
+/// 이미지(`images/...`)의 썸네일 상대 경로(`thumbnails/...`). 이미지 경로가 아니면 None을 반환합니다.
+pub fn thumbnail_relative_path_for(image_relative_path: &Path) -> Option<PathBuf> {
+    let mut components = image_relative_path.components();
+    match components.next() {
+        Some(Component::Normal(dir)) if dir == IMAGES_SUBDIR_IN_RESOURCES => {}
+        _ => return None,
+    }
+    let rest = components.as_path();
+    (!rest.as_os_str().is_empty()).then(|| Path::new(THUMBNAILS_SUBDIR_IN_RESOURCES).join(rest))
+}
+
+/// 썸네일(`thumbnails/...`)의 원본 이미지 상대 경로(`images/...`). 썸네일 경로가 아니면 None을 반환합니다.
+pub fn image_relative_path_for(thumbnail_relative_path: &Path) -> Option<PathBuf> {
+    let rest = thumbnail_relative_path
+        .strip_prefix(THUMBNAILS_SUBDIR_IN_RESOURCES)
+        .ok()?;
+    (!rest.as_os_str().is_empty()).then(|| Path::new(IMAGES_SUBDIR_IN_RESOURCES).join(rest))
+}
+
+/// 이미지의 썸네일을 만들어 저장하고, 썸네일의 `resources` 기준 상대 경로를 반환합니다.
+/// 썸네일은 원본과 같은 형식으로 저장하며, 원본이 충분히 작으면 그대로 복사합니다.
+pub fn create_thumbnail(
+    novel_root_path: &Path,
+    image_relative_path: &Path,
+) -> MuvelResult<PathBuf> {
+    let thumbnail_relative_path = thumbnail_relative_path_for(image_relative_path)
+        .ok_or_else(|| MuvelError::invalid_input(Message::NotAnImageResource))?;
+    let resources_dir = novel_root_path.join(RESOURCES_DIRNAME);
+    let image_path = resources_dir.join(image_relative_path);
+    let thumbnail_path = resources_dir.join(&thumbnail_relative_path);
+
+    let bytes = fs::read(&image_path).map_err(|e| {
+        MuvelError::io(Message::FileReadFailed(FileKind::Image), &e).with_path(&image_path)
+    })?;
+    let process_failed = |e: image::ImageError| {
+        MuvelError::parse(Message::ImageProcessFailed)
+            .caused_by(e)
+            .with_path(&image_path)
+    };
+    let format = image::guess_format(&bytes).map_err(process_failed)?;
+    let image = decode_oriented(&bytes, format).map_err(process_failed)?;
+
+    let thumbnail_bytes =
+        if image.width() <= THUMBNAIL_MAX_DIMENSION && image.height() <= THUMBNAIL_MAX_DIMENSION {
+            bytes
+        } else {
+            let mut encoded = Vec::new();
+            image
+                .thumbnail(THUMBNAIL_MAX_DIMENSION, THUMBNAIL_MAX_DIMENSION)
+                .write_to(&mut Cursor::new(&mut encoded), format)
+                .map_err(process_failed)?;
+            encoded
+        };
+
+    if let Some(parent) = thumbnail_path.parent() {
+        fs::create_dir_all(parent).map_err(|e| {
+            MuvelError::io(Message::DirCreateFailed(FileKind::Image), &e).with_path(parent)
+        })?;
+    }
+    durable_io::write_file_atomically(&thumbnail_path, &thumbnail_bytes)
+        .map_err(|e| e.context(Message::FileWriteFailed(FileKind::Image)))?;
+    Ok(thumbnail_relative_path)
+}
+
+/// 이미지와 썸네일 폴더의 파일 목록을 `resources` 기준 상대 경로로 반환합니다. (임시 파일 제외)
+pub fn list_image_resource_files(novel_root_path: &Path) -> MuvelResult<Vec<PathBuf>> {
+    let resources_dir = novel_root_path.join(RESOURCES_DIRNAME);
+    let mut files = Vec::new();
+    for subdir in [IMAGES_SUBDIR_IN_RESOURCES, THUMBNAILS_SUBDIR_IN_RESOURCES] {
+        let dir_path = resources_dir.join(subdir);
+        if !dir_path.is_dir() {
+            continue;
+        }
+        for entry in fs::read_dir(&dir_path).map_err(|e| {
+            MuvelError::io(Message::DirReadFailed(FileKind::Image), &e).with_path(&dir_path)
+        })? {
+            let path = entry
+                .map_err(|e| MuvelError::io(Message::DirEntryReadFailed, &e))?
+                .path();
+            let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            if path.is_file() && !file_name.ends_with(durable_io::TEMP_FILE_SUFFIX) {
+                files.push(Path::new(subdir).join(file_name));
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
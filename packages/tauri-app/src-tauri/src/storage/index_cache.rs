@@ -0,0 +1,61 @@
+use crate::models::error::MuvelResult;
+use crate::models::index::LocalNovelIndexEntry;
+use crate::storage::item_index_manager::ItemEntry;
+use std::collections::HashMap;
+use std::sync::{PoisonError, RwLock};
+
+pub type LocalNovelIndex = HashMap<String, LocalNovelIndexEntry>;
+pub type ItemToNovelMap = HashMap<String, ItemEntry>;
+
+/// 저장소에서 한 번만 읽어 메모리에 보관하는 인덱스
+///
+/// 처음 접근할 때 `load`로 전체를 읽고, 이후에는 메모리의 값을 사용합니다.
+/// 변경은 먼저 `persist`로 저장소에 반영한 뒤 메모리에 적용하므로(write-through),
+/// 저장에 실패하면 메모리의 값도 바뀌지 않습니다.
+#[derive(Default)]
+pub struct CachedIndex<T>(RwLock<Option<T>>);
+
+impl<T> CachedIndex<T> {
+    /// 인덱스를 읽기 전용으로 사용합니다.
+    pub fn read<R>(
+        &self,
+        load: impl FnOnce() -> MuvelResult<T>,
+        f: impl FnOnce(&T) -> R,
+    ) -> MuvelResult<R> {
+        {
+            let guard = self.0.read().unwrap_or_else(PoisonError::into_inner);
+            if let Some(index) = guard.as_ref() {
+                return Ok(f(index));
+            }
+        }
+
+        let mut guard = self.0.write().unwrap_or_else(PoisonError::into_inner);
+        if guard.is_none() {
+            *guard = Some(load()?);
+        }
+        Ok(f(guard.as_ref().expect("인덱스가 방금 로드되었습니다")))
+    }
+
+    /// 변경 내용을 `persist`로 저장한 뒤, 성공하면 메모리의 인덱스에 `apply`로 반영합니다.
+    pub fn update<R>(
+        &self,
+        load: impl FnOnce() -> MuvelResult<T>,
+        persist: impl FnOnce() -> MuvelResult<()>,
+        apply: impl FnOnce(&mut T) -> R,
+    ) -> MuvelResult<R> {
+        let mut guard = self.0.write().unwrap_or_else(PoisonError::into_inner);
+        if guard.is_none() {
+            *guard = Some(load()?);
+        }
+
+        persist()?;
+        Ok(apply(guard.as_mut().expect("인덱스가 방금 로드되었습니다")))
+    }
+}
+
+/// Tauri State – 소설 인덱스와 아이템 인덱스의 메모리 캐시
+#[derive(Default)]
+pub struct IndexCache {
+    pub novels: CachedIndex<LocalNovelIndex>,
+    pub items: CachedIndex<ItemToNovelMap>,
+}
@@ -1,96 +1,28 @@
+use crate::models::error::MuvelResult;
 use crate::models::index::LocalNovelIndexEntry;
-use std::collections::HashMap;
-use std::fs;
-use std::io::{Read, Write};
-use std::path::PathBuf;
+use crate::storage::index_cache::{IndexCache, LocalNovelIndex};
+use crate::storage::{library_db, novel_watcher};
 use tauri::{AppHandle, Manager};
 
-type LocalNovelIndex = HashMap<String, LocalNovelIndexEntry>;
-
-const NOVEL_INDEX_FILENAME: &str = "novel_index.json";
-
-/// 로컬 소설 인덱스 파일의 전체 절대 경로를 생성하여 반환합니다.
-/// Tauri 2.x 방식: AppHandle의 path() 메서드를 통해 PathResolver를 얻고, app_local_data_dir() 사용
-fn get_novel_index_file_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
-    // AppHandle의 path() 메서드를 사용하여 PathResolver 인스턴스를 가져옵니다.
-    let mut path = app_handle.path().app_local_data_dir().map_err(|e| {
-        format!(
-            "애플리케이션 로컬 데이터 디렉토리를 찾을 수 없습니다: {:?}",
-            e
-        )
-    })?;
-
-    // 해당 디렉토리 경로에 인덱스 파일 이름을 추가
-    path.push(NOVEL_INDEX_FILENAME);
-    Ok(path)
+/// 캐시된 로컬 소설 인덱스를 읽습니다. 처음 호출될 때만 데이터베이스에서 읽습니다.
+fn with_index<R>(app_handle: &AppHandle, f: impl FnOnce(&LocalNovelIndex) -> R) -> MuvelResult<R> {
+    app_handle
+        .state::<IndexCache>()
+        .novels
+        .read(|| library_db::load_novel_index(app_handle), f)
 }
 
-/// 로컬 소설 인덱스를 파일에서 읽어옵니다.
-pub fn load_index(app_handle: &AppHandle) -> Result<LocalNovelIndex, String> {
-    let index_path = get_novel_index_file_path(app_handle)?;
-
-    if !index_path.exists() {
-        return Ok(HashMap::new());
-    }
-
-    let mut file_content = String::new();
-    fs::File::open(&index_path)
-        .map_err(|e| {
-            format!(
-                "인덱스 파일을 열 수 없습니다 (경로: {:?}): {}",
-                index_path, e
-            )
-        })?
-        .read_to_string(&mut file_content)
-        .map_err(|e| format!("인덱스 파일 내용을 읽을 수 없습니다: {}", e))?;
-
-    serde_json::from_str(&file_content)
-        .map_err(|e| format!("인덱스 파일 JSON 파싱에 실패했습니다: {}", e))
-}
-
-/// 로컬 소설 인덱스 데이터를 파일에 저장합니다. (원자적 쓰기 방식)
-pub fn save_index(app_handle: &AppHandle, index_data: &LocalNovelIndex) -> Result<(), String> {
-    let index_path = get_novel_index_file_path(app_handle)?;
-    let parent_dir = index_path.parent().ok_or_else(|| {
-        format!(
-            "인덱스 파일의 부모 디렉토리를 찾을 수 없습니다: {:?}",
-            index_path
-        )
-    })?;
-
-    if !parent_dir.exists() {
-        fs::create_dir_all(parent_dir).map_err(|e| {
-            format!(
-                "인덱스 파일 저장 디렉토리 생성에 실패했습니다 (경로: {:?}): {}",
-                parent_dir, e
-            )
-        })?;
-    }
-
-    let temp_file_path = index_path.with_extension("json.tmp");
-
-    let mut temp_file = fs::File::create(&temp_file_path).map_err(|e| {
-        format!(
-            "임시 인덱스 파일을 생성할 수 없습니다 (경로: {:?}): {}",
-            temp_file_path, e
-        )
-    })?;
-
-    let json_string = serde_json::to_string_pretty(index_data)
-        .map_err(|e| format!("인덱스 데이터를 JSON으로 직렬화하는 데 실패했습니다: {}", e))?;
-
-    temp_file
-        .write_all(json_string.as_bytes())
-        .map_err(|e| format!("임시 인덱스 파일에 쓰는 데 실패했습니다: {}", e))?;
-
-    fs::rename(&temp_file_path, &index_path).map_err(|e| {
-        format!(
-            "인덱스 파일을 원자적으로 교체하는 데 실패했습니다 (원본: {:?}, 임시: {:?}): {}",
-            index_path, temp_file_path, e
-        )
-    })?;
-
-    Ok(())
+/// 변경 내용을 데이터베이스에 저장한 뒤 캐시된 로컬 소설 인덱스에 반영합니다.
+fn update_index<R>(
+    app_handle: &AppHandle,
+    persist: impl FnOnce() -> MuvelResult<()>,
+    apply: impl FnOnce(&mut LocalNovelIndex) -> R,
+) -> MuvelResult<R> {
+    app_handle.state::<IndexCache>().novels.update(
+        || library_db::load_novel_index(app_handle),
+        persist,
+        apply,
+    )
 }
 
 /// 로컬 소설 인덱스에 특정 소설 항목을 추가하거나 기존 항목을 업데이트합니다.
@@ -98,33 +30,44 @@ pub fn upsert_novel_entry(
     app_handle: &AppHandle,
     novel_id: String,
     entry: LocalNovelIndexEntry,
-) -> Result<(), String> {
-    let mut index = load_index(app_handle)?;
-    index.insert(novel_id, entry);
-    save_index(app_handle, &index)
+) -> MuvelResult<()> {
+    let path_changed = update_index(
+        app_handle,
+        || library_db::upsert_novel(app_handle, &novel_id, &entry),
+        |index| {
+            let previous = index.insert(novel_id.clone(), entry.clone());
+            previous.map_or(true, |previous| previous.path != entry.path)
+        },
+    )?;
+    // 소설 폴더가 새로 등록되거나 옮겨졌으면 감시 대상도 갱신
+    if path_changed {
+        novel_watcher::sync_watched_roots(app_handle);
+    }
+    Ok(())
 }
 
 /// 로컬 소설 인덱스에서 특정 소설 항목을 제거합니다.
-pub fn remove_novel_entry(app_handle: &AppHandle, novel_id: &str) -> Result<(), String> {
-    let mut index = load_index(app_handle)?;
-    if index.remove(novel_id).is_some() {
-        save_index(app_handle, &index)
-    } else {
-        Ok(())
+pub fn remove_novel_entry(app_handle: &AppHandle, novel_id: &str) -> MuvelResult<()> {
+    let removed = update_index(
+        app_handle,
+        || library_db::delete_novel(app_handle, novel_id),
+        |index| index.remove(novel_id).is_some(),
+    )?;
+    if removed {
+        novel_watcher::sync_watched_roots(app_handle);
     }
+    Ok(())
 }
 
 /// 로컬 소설 인덱스에서 특정 소설 항목을 조회합니다.
 pub fn get_novel_entry(
     app_handle: &AppHandle,
     novel_id: &str,
-) -> Result<Option<LocalNovelIndexEntry>, String> {
-    let index = load_index(app_handle)?;
-    Ok(index.get(novel_id).cloned())
+) -> MuvelResult<Option<LocalNovelIndexEntry>> {
+    with_index(app_handle, |index| index.get(novel_id).cloned())
 }
 
 /// 로컬 소설 인덱스에 있는 모든 소설 항목의 목록을 반환합니다.
-pub fn get_all_novel_entries(app_handle: &AppHandle) -> Result<Vec<LocalNovelIndexEntry>, String> {
-    let index = load_index(app_handle)?;
-    Ok(index.values().cloned().collect())
+pub fn get_all_novel_entries(app_handle: &AppHandle) -> MuvelResult<Vec<LocalNovelIndexEntry>> {
+    with_index(app_handle, |index| index.values().cloned().collect())
 }
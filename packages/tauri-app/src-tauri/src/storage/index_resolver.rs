@@ -0,0 +1,39 @@
+use crate::i18n::Message;
+use crate::models::error::{MuvelError, MuvelResult};
+use crate::storage::{index_manager, item_index_manager};
+use std::path::PathBuf;
+use tauri::AppHandle;
+
+/// 소설 ID로 인덱스에 등록된 소설 루트 경로를 찾습니다.
+/// 경로가 실제로 존재하는지는 확인하지 않습니다.
+pub fn resolve_novel_root(app_handle: &AppHandle, novel_id: &str) -> MuvelResult<PathBuf> {
+    let novel_entry = index_manager::get_novel_entry(app_handle, novel_id)?.ok_or_else(|| {
+        MuvelError::not_found(Message::NovelNotInIndex {
+            novel_id: novel_id.to_string(),
+        })
+        .with_id(novel_id)
+    })?;
+    let novel_root_path_str = novel_entry.path.ok_or_else(|| {
+        MuvelError::not_found(Message::NovelPathMissing {
+            novel_id: novel_id.to_string(),
+        })
+        .with_id(novel_id)
+    })?;
+
+    Ok(PathBuf::from(novel_root_path_str))
+}
+
+/// 아이템(에피소드/위키 페이지) ID로 부모 소설의 루트 경로와 소설 ID를 찾습니다.
+pub fn resolve_item_novel(app_handle: &AppHandle, item_id: &str) -> MuvelResult<(PathBuf, String)> {
+    let novel_id = item_index_manager::get_item_entry(app_handle, item_id)?
+        .ok_or_else(|| {
+            MuvelError::not_found(Message::ParentNovelNotFound {
+                item_id: item_id.to_string(),
+            })
+            .with_id(item_id)
+        })?
+        .novel_id;
+    let novel_root_path = resolve_novel_root(app_handle, &novel_id)?;
+
+    Ok((novel_root_path, novel_id))
+}
@@ -0,0 +1,312 @@
+use crate::i18n::{FileKind, Message};
+use crate::models::error::{MuvelError, MuvelResult};
+use crate::models::index::LocalNovelIndexEntry;
+use crate::storage::index_cache::{ItemToNovelMap, LocalNovelIndex};
+use crate::storage::item_index_manager::ItemEntry;
+use rusqlite::{params, Connection, Transaction};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+use tauri::{AppHandle, Manager};
+
+const LIBRARY_DB_FILENAME: &str = "library.sqlite3";
+
+/// 데이터베이스 스키마 버전 (`PRAGMA user_version`)
+/// 스키마를 바꿀 때는 이 값을 올리고 `migrate_schema`에 단계를 추가합니다.
+const SCHEMA_VERSION: i32 = 1;
+
+// 이전 버전에서 사용하던 JSON 인덱스 파일
+const LEGACY_NOVEL_INDEX_FILENAME: &str = "novel_index.json";
+const LEGACY_ITEM_INDEX_FILENAME: &str = "novel_item_index.json";
+const LEGACY_IMPORTED_SUFFIX: &str = ".imported";
+const LEGACY_DEFAULT_ITEM_TYPE: &str = "episode";
+
+/// Tauri State – 소설/아이템 인덱스를 저장하는 SQLite 데이터베이스 연결
+/// 처음 사용할 때 열고, 이후에는 같은 연결을 재사용합니다.
+#[derive(Default)]
+pub struct LibraryDb(Mutex<Option<Connection>>);
+
+fn get_app_local_data_dir(app_handle: &AppHandle) -> MuvelResult<PathBuf> {
+    app_handle
+        .path()
+        .app_local_data_dir()
+        .map_err(|e| MuvelError::not_found(Message::AppDataDirNotFound).caused_by(e))
+}
+
+fn query_error(e: rusqlite::Error) -> MuvelError {
+    MuvelError::internal(Message::LibraryDbQueryFailed).caused_by(e)
+}
+
+/// 데이터베이스를 열고, 스키마가 오래되었으면 최신으로 올립니다.
+/// 데이터베이스를 처음 만드는 경우 기존 JSON 인덱스 파일을 가져옵니다.
+fn open_connection(app_handle: &AppHandle) -> MuvelResult<Connection> {
+    let data_dir = get_app_local_data_dir(app_handle)?;
+    fs::create_dir_all(&data_dir).map_err(|e| {
+        MuvelError::io(Message::DirCreateFailed(FileKind::LibraryDb), &e).with_path(&data_dir)
+    })?;
+
+    let db_path = data_dir.join(LIBRARY_DB_FILENAME);
+    let mut conn = Connection::open(&db_path).map_err(|e| {
+        MuvelError::internal(Message::FileOpenFailed(FileKind::LibraryDb))
+            .caused_by(e)
+            .with_path(&db_path)
+    })?;
+    conn.execute_batch("PRAGMA journal_mode = WAL; PRAGMA synchronous = NORMAL;")
+        .map_err(query_error)?;
+
+    let version: i32 = conn
+        .query_row("PRAGMA user_version", [], |row| row.get(0))
+        .map_err(query_error)?;
+    if version >= SCHEMA_VERSION {
+        return Ok(conn);
+    }
+
+    let tx = conn.transaction().map_err(query_error)?;
+    migrate_schema(&tx, version).map_err(query_error)?;
+    let imported_files = if version == 0 {
+        import_legacy_json_indexes(&tx, &data_dir)
+            .map_err(|e| e.context(Message::LibraryImportFailed))?
+    } else {
+        Vec::new()
+    };
+    tx.pragma_update(None, "user_version", SCHEMA_VERSION)
+        .map_err(query_error)?;
+    tx.commit().map_err(query_error)?;
+
+    // 가져온 JSON 파일은 다시 가져오지 않도록 이름을 바꿔 백업으로 남겨 둡니다.
+    for path in imported_files {
+        retire_legacy_file(&path);
+    }
+
+    Ok(conn)
+}
+
+fn migrate_schema(tx: &Transaction, from_version: i32) -> rusqlite::Result<()> {
+    if from_version < 1 {
+        tx.execute_batch(
+            "CREATE TABLE IF NOT EXISTS novels (
+                id TEXT PRIMARY KEY NOT NULL,
+                title TEXT NOT NULL,
+                episode_count INTEGER,
+                thumbnail TEXT,
+                last_opened TEXT,
+                path TEXT
+            );
+            CREATE TABLE IF NOT EXISTS items (
+                item_id TEXT PRIMARY KEY NOT NULL,
+                novel_id TEXT NOT NULL,
+                item_type TEXT NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS idx_items_novel ON items (novel_id, item_type);",
+        )?;
+    }
+    Ok(())
+}
+
+/// 연결을 잠근 채로 작업을 실행합니다. 연결이 아직 없으면 먼저 엽니다.
+fn with_connection<R>(
+    app_handle: &AppHandle,
+    f: impl FnOnce(&mut Connection) -> rusqlite::Result<R>,
+) -> MuvelResult<R> {
+    let db = app_handle.state::<LibraryDb>();
+    let mut guard = db.0.lock().unwrap_or_else(PoisonError::into_inner);
+    if guard.is_none() {
+        *guard = Some(open_connection(app_handle)?);
+    }
+    let conn = guard.as_mut().expect("연결이 방금 열렸습니다");
+    f(conn).map_err(query_error)
+}
+
+// --- 소설 인덱스 ---
+
+fn insert_novel(
+    conn: &Connection,
+    novel_id: &str,
+    entry: &LocalNovelIndexEntry,
+) -> rusqlite::Result<()> {
+    conn.prepare_cached(
+        "INSERT INTO novels (id, title, episode_count, thumbnail, last_opened, path)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+         ON CONFLICT(id) DO UPDATE SET
+            title = excluded.title,
+            episode_count = excluded.episode_count,
+            thumbnail = excluded.thumbnail,
+            last_opened = excluded.last_opened,
+            path = excluded.path",
+    )?
+    .execute(params![
+        novel_id,
+        entry.title,
+        entry.episode_count,
+        entry.thumbnail,
+        entry.last_opened,
+        entry.path
+    ])?;
+    Ok(())
+}
+
+/// 소설 인덱스 전체를 읽어옵니다.
+pub fn load_novel_index(app_handle: &AppHandle) -> MuvelResult<LocalNovelIndex> {
+    with_connection(app_handle, |conn| {
+        let mut stmt = conn
+            .prepare("SELECT id, title, episode_count, thumbnail, last_opened, path FROM novels")?;
+        let rows = stmt.query_map([], |row| {
+            Ok(LocalNovelIndexEntry {
+                id: row.get(0)?,
+                title: row.get(1)?,
+                episode_count: row.get(2)?,
+                thumbnail: row.get(3)?,
+                last_opened: row.get(4)?,
+                path: row.get(5)?,
+            })
+        })?;
+        rows.map(|entry| entry.map(|entry| (entry.id.clone(), entry)))
+            .collect()
+    })
+}
+
+pub fn upsert_novel(
+    app_handle: &AppHandle,
+    novel_id: &str,
+    entry: &LocalNovelIndexEntry,
+) -> MuvelResult<()> {
+    with_connection(app_handle, |conn| insert_novel(conn, novel_id, entry))
+}
+
+pub fn delete_novel(app_handle: &AppHandle, novel_id: &str) -> MuvelResult<()> {
+    with_connection(app_handle, |conn| {
+        conn.prepare_cached("DELETE FROM novels WHERE id = ?1")?
+            .execute([novel_id])?;
+        Ok(())
+    })
+}
+
+// --- 아이템 인덱스 ---
+
+fn insert_item(conn: &Connection, item_id: &str, entry: &ItemEntry) -> rusqlite::Result<()> {
+    conn.prepare_cached(
+        "INSERT INTO items (item_id, novel_id, item_type) VALUES (?1, ?2, ?3)
+         ON CONFLICT(item_id) DO UPDATE SET
+            novel_id = excluded.novel_id,
+            item_type = excluded.item_type",
+    )?
+    .execute(params![item_id, entry.novel_id, entry.item_type])?;
+    Ok(())
+}
+
+/// 아이템 인덱스 전체를 읽어옵니다.
+pub fn load_item_map(app_handle: &AppHandle) -> MuvelResult<ItemToNovelMap> {
+    with_connection(app_handle, |conn| {
+        let mut stmt = conn.prepare("SELECT item_id, novel_id, item_type FROM items")?;
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get(0)?,
+                ItemEntry {
+                    novel_id: row.get(1)?,
+                    item_type: row.get(2)?,
+                },
+            ))
+        })?;
+        rows.collect()
+    })
+}
+
+pub fn upsert_item(app_handle: &AppHandle, item_id: &str, entry: &ItemEntry) -> MuvelResult<()> {
+    with_connection(app_handle, |conn| insert_item(conn, item_id, entry))
+}
+
+pub fn delete_item(app_handle: &AppHandle, item_id: &str) -> MuvelResult<()> {
+    with_connection(app_handle, |conn| {
+        conn.prepare_cached("DELETE FROM items WHERE item_id = ?1")?
+            .execute([item_id])?;
+        Ok(())
+    })
+}
+
+pub fn delete_items_for_novel(app_handle: &AppHandle, novel_id: &str) -> MuvelResult<()> {
+    with_connection(app_handle, |conn| {
+        conn.prepare_cached("DELETE FROM items WHERE novel_id = ?1")?
+            .execute([novel_id])?;
+        Ok(())
+    })
+}
+
+// --- 기존 JSON 인덱스 가져오기 ---
+
+fn read_legacy_file(path: &Path, kind: FileKind) -> MuvelResult<Option<String>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    fs::read_to_string(path)
+        .map(Some)
+        .map_err(|e| MuvelError::io(Message::FileReadFailed(kind), &e).with_path(path))
+}
+
+/// `novel_item_index.json`을 해석합니다.
+/// 아이템 종류가 없던 이전 형식(`아이템 ID → 소설 ID`)은 에피소드로 간주합니다.
+fn parse_legacy_item_map(content: &str, path: &Path) -> MuvelResult<ItemToNovelMap> {
+    if let Ok(map) = serde_json::from_str::<ItemToNovelMap>(content) {
+        return Ok(map);
+    }
+    let old_map = serde_json::from_str::<HashMap<String, String>>(content).map_err(|e| {
+        MuvelError::parse(Message::FileParseFailed(FileKind::ItemIndex))
+            .caused_by(e)
+            .with_path(path)
+    })?;
+    eprintln!("이전 버전의 아이템 인덱스 파일을 새 형식으로 변환하여 가져옵니다.");
+    Ok(old_map
+        .into_iter()
+        .map(|(item_id, novel_id)| {
+            (
+                item_id,
+                ItemEntry {
+                    novel_id,
+                    item_type: LEGACY_DEFAULT_ITEM_TYPE.to_string(),
+                },
+            )
+        })
+        .collect())
+}
+
+/// 기존 JSON 인덱스 파일의 내용을 데이터베이스에 넣고, 가져온 파일 경로를 반환합니다.
+fn import_legacy_json_indexes(tx: &Transaction, data_dir: &Path) -> MuvelResult<Vec<PathBuf>> {
+    let mut imported_files = Vec::new();
+
+    let novel_index_path = data_dir.join(LEGACY_NOVEL_INDEX_FILENAME);
+    if let Some(content) = read_legacy_file(&novel_index_path, FileKind::NovelIndex)? {
+        let index: LocalNovelIndex = serde_json::from_str(&content).map_err(|e| {
+            MuvelError::parse(Message::FileParseFailed(FileKind::NovelIndex))
+                .caused_by(e)
+                .with_path(&novel_index_path)
+        })?;
+        for (novel_id, entry) in &index {
+            insert_novel(tx, novel_id, entry).map_err(query_error)?;
+        }
+        eprintln!("소설 인덱스 {}개 항목을 가져왔습니다.", index.len());
+        imported_files.push(novel_index_path);
+    }
+
+    let item_index_path = data_dir.join(LEGACY_ITEM_INDEX_FILENAME);
+    if let Some(content) = read_legacy_file(&item_index_path, FileKind::ItemIndex)? {
+        let map = parse_legacy_item_map(&content, &item_index_path)?;
+        for (item_id, entry) in &map {
+            insert_item(tx, item_id, entry).map_err(query_error)?;
+        }
+        eprintln!("아이템 인덱스 {}개 항목을 가져왔습니다.", map.len());
+        imported_files.push(item_index_path);
+    }
+
+    Ok(imported_files)
+}
+
+fn retire_legacy_file(path: &Path) {
+    let mut retired_name = path.file_name().unwrap_or_default().to_os_string();
+    retired_name.push(LEGACY_IMPORTED_SUFFIX);
+    if let Err(e) = fs::rename(path, path.with_file_name(retired_name)) {
+        eprintln!(
+            "경고: 가져온 인덱스 파일의 이름을 바꾸지 못했습니다 ({:?}): {}",
+            path, e
+        );
+    }
+}
@@ -1,6 +1,24 @@
+pub mod attachment_io;
+pub mod block_store;
+pub mod durable_io;
+pub mod edit_history_io;
 pub mod episode_io;
+pub mod episode_journal_io;
+pub mod episode_manifest_io;
+pub mod format_migration;
+pub mod image_resource_io;
+pub mod index_cache;
 pub mod index_manager;
+pub mod index_resolver;
 pub mod item_index_manager;
+pub mod library_db;
+pub mod novel_archive_io;
 pub mod novel_io;
+pub mod novel_snapshot_io;
+pub mod novel_watcher;
+pub mod resource_uri;
+pub mod settings_manager;
 pub mod snapshot_io;
+pub mod sync_conflict_io;
+pub mod temp_file_recovery;
 pub mod wiki_page_io;
@@ -0,0 +1,453 @@
+use crate::i18n::{FileKind, Message};
+use crate::models::error::{MuvelError, MuvelResult};
+use crate::models::novel::Novel;
+use crate::storage::attachment_io::ATTACHMENTS_SUBDIR_IN_RESOURCES;
+use crate::storage::block_store::BLOCK_STORE_DIRNAME;
+use crate::storage::edit_history_io::EDIT_HISTORY_FILE_EXTENSION;
+use crate::storage::episode_io::EPISODES_DIRNAME;
+use crate::storage::episode_manifest_io::EPISODE_MANIFEST_FILENAME;
+use crate::storage::novel_io::{
+    IMAGES_SUBDIR_IN_RESOURCES, NOVEL_METADATA_EXTENSION, RESOURCES_DIRNAME,
+};
+use crate::storage::novel_snapshot_io::NOVEL_SNAPSHOTS_DIRNAME;
+use crate::storage::wiki_page_io::WIKI_PAGES_DIRNAME;
+use crate::storage::{durable_io, format_migration, sync_conflict_io};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Component, Path, PathBuf};
+use zip::result::ZipError;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+pub const NOVEL_ARCHIVE_EXTENSION: &str = "muvlz";
+// 아카이브 안의 매니페스트와 소설 메타데이터 항목 이름
+const ARCHIVE_MANIFEST_ENTRY: &str = "manifest.json";
+const ARCHIVE_METADATA_ENTRY: &str = "novel.muvl";
+// 아카이브 형식이 바뀌면 올립니다. 더 새로운 형식의 아카이브는 가져오지 않습니다.
+const ARCHIVE_FORMAT_VERSION: u32 = 1;
+// 손상되었거나 악의적인 아카이브의 매니페스트를 끝없이 읽지 않도록 제한
+const MAX_MANIFEST_SIZE: u64 = 64 * 1024 * 1024;
+
+/// 아카이브에 담긴 파일 하나의 경로(아카이브 기준, `/` 구분)와 크기, SHA-256 체크섬
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NovelArchiveEntry {
+    pub path: String,
+    pub size: u64,
+    pub sha256: String,
+}
+
+/// 아카이브의 `manifest.json`. 가져오기 전에 이 내용으로 소설과 파일을 확인합니다.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NovelArchiveManifest {
+    pub format_version: u32,
+    pub novel_id: String,
+    pub title: String,
+    pub exported_at: String,
+    pub entries: Vec<NovelArchiveEntry>,
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// zip 오류를 입출력 오류로 바꿉니다.
+fn zip_error(message: Message, error: ZipError, path: &Path) -> MuvelError {
+    MuvelError::io(message, &io::Error::from(error)).with_path(path)
+}
+
+/// 아카이브에 담을 폴더 (소설 루트 기준)
+fn archived_dirs(novel_root_path: &Path) -> [PathBuf; 6] {
+    [
+        novel_root_path.join(EPISODES_DIRNAME),
+        novel_root_path.join(WIKI_PAGES_DIRNAME),
+        novel_root_path.join(NOVEL_SNAPSHOTS_DIRNAME),
+        novel_root_path.join(BLOCK_STORE_DIRNAME),
+        novel_root_path
+            .join(RESOURCES_DIRNAME)
+            .join(IMAGES_SUBDIR_IN_RESOURCES),
+        novel_root_path
+            .join(RESOURCES_DIRNAME)
+            .join(ATTACHMENTS_SUBDIR_IN_RESOURCES),
+    ]
+}
+
+/// 다른 기기에서 의미가 없거나 다시 만들 수 있는 파일은 아카이브에서 제외합니다.
+/// (임시 파일, 마이그레이션 백업, 동기화 충돌 사본, 에피소드 요약 매니페스트, 편집 기록)
+fn is_archivable(path: &Path) -> bool {
+    let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    !(file_name.ends_with(durable_io::TEMP_FILE_SUFFIX)
+        || file_name.ends_with(".bak")
+        || file_name == EPISODE_MANIFEST_FILENAME
+        || path
+            .extension()
+            .is_some_and(|ext| ext == EDIT_HISTORY_FILE_EXTENSION)
+        || sync_conflict_io::is_conflict_copy(path))
+}
+
+/// 폴더 하위의 아카이브 대상 파일을 모두 수집합니다.
+fn collect_files(dir_path: &Path, found: &mut Vec<PathBuf>) -> MuvelResult<()> {
+    if !dir_path.is_dir() {
+        return Ok(());
+    }
+    for entry in fs::read_dir(dir_path).map_err(|e| {
+        MuvelError::io(Message::DirReadFailed(FileKind::NovelRoot), &e).with_path(dir_path)
+    })? {
+        let path = entry
+            .map_err(|e| MuvelError::io(Message::DirEntryReadFailed, &e))?
+            .path();
+        if path.is_dir() {
+            collect_files(&path, found)?;
+        } else if path.is_file() && is_archivable(&path) {
+            found.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// 소설 루트 기준 상대 경로를 아카이브 항목 이름(`/` 구분)으로 바꿉니다.
+fn entry_name_for(novel_root_path: &Path, path: &Path) -> Option<String> {
+    let relative = path.strip_prefix(novel_root_path).ok()?;
+    let parts = relative
+        .components()
+        .map(|component| match component {
+            Component::Normal(part) => part.to_str(),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(parts.join("/"))
+}
+
+/// 아카이브 항목 이름을 안전한 상대 경로로 바꿉니다. 폴더 밖을 가리키는 이름은 거부합니다.
+fn relative_path_for(entry_name: &str) -> MuvelResult<PathBuf> {
+    let invalid = || {
+        MuvelError::invalid_input(Message::ArchiveEntryInvalid {
+            entry: entry_name.to_string(),
+        })
+    };
+    let mut relative = PathBuf::new();
+    for part in entry_name.split('/') {
+        // Windows에서 경로 구분자나 드라이브 접두사로 해석되는 이름은 OS와 관계없이 거부
+        if part.contains(['\\', ':']) {
+            return Err(invalid());
+        }
+        match Path::new(part).components().collect::<Vec<_>>().as_slice() {
+            [Component::Normal(_)] => relative.push(part),
+            _ => return Err(invalid()),
+        }
+    }
+    if relative.as_os_str().is_empty() {
+        return Err(invalid());
+    }
+    Ok(relative)
+}
+
+fn entry_for(path: String, bytes: &[u8]) -> NovelArchiveEntry {
+    NovelArchiveEntry {
+        path,
+        size: bytes.len() as u64,
+        sha256: sha256_hex(bytes),
+    }
+}
+
+/// 아카이브에 항목 하나를 압축해 씁니다.
+fn write_entry(
+    writer: &mut ZipWriter<fs::File>,
+    temp_archive_path: &Path,
+    name: &str,
+    bytes: &[u8],
+) -> MuvelResult<()> {
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    writer.start_file(name, options).map_err(|e| {
+        zip_error(
+            Message::FileWriteFailed(FileKind::NovelArchive),
+            e,
+            temp_archive_path,
+        )
+    })?;
+    writer.write_all(bytes).map_err(|e| {
+        MuvelError::io(Message::FileWriteFailed(FileKind::NovelArchive), &e)
+            .with_path(temp_archive_path)
+    })
+}
+
+/// 소설 폴더 전체를 `.muvlz` 아카이브로 내보냅니다.
+/// 메타데이터의 `localPath`는 가져오는 기기에서 다시 정해지므로 비워서 저장합니다.
+pub fn write_novel_archive(
+    novel_root_path: &Path,
+    novel: &Novel,
+    archive_path: &Path,
+) -> MuvelResult<()> {
+    let temp_archive_path = durable_io::temp_file_path_for(archive_path);
+    let result = write_archive_file(novel_root_path, novel, &temp_archive_path)
+        .and_then(|_| durable_io::rename_durably(&temp_archive_path, archive_path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_archive_path);
+    }
+    result
+}
+
+fn write_archive_file(
+    novel_root_path: &Path,
+    novel: &Novel,
+    temp_archive_path: &Path,
+) -> MuvelResult<()> {
+    let file = fs::File::create(temp_archive_path).map_err(|e| {
+        MuvelError::io(Message::FileWriteFailed(FileKind::NovelArchive), &e)
+            .with_path(temp_archive_path)
+    })?;
+    let mut writer = ZipWriter::new(file);
+    let mut entries = Vec::new();
+
+    let mut portable_novel = novel.clone();
+    portable_novel.local_path = String::new();
+    let metadata_json =
+        format_migration::to_versioned_json(&portable_novel, FileKind::NovelMetadata)?;
+    write_entry(
+        &mut writer,
+        temp_archive_path,
+        ARCHIVE_METADATA_ENTRY,
+        metadata_json.as_bytes(),
+    )?;
+    entries.push(entry_for(
+        ARCHIVE_METADATA_ENTRY.to_string(),
+        metadata_json.as_bytes(),
+    ));
+
+    let mut files = Vec::new();
+    for dir_path in archived_dirs(novel_root_path) {
+        collect_files(&dir_path, &mut files)?;
+    }
+    files.sort();
+    for path in files {
+        let Some(name) = entry_name_for(novel_root_path, &path) else {
+            eprintln!("아카이브에 넣을 수 없는 파일 경로입니다: {:?}", path);
+            continue;
+        };
+        let bytes = fs::read(&path).map_err(|e| {
+            MuvelError::io(Message::FileReadFailed(FileKind::NovelRoot), &e).with_path(&path)
+        })?;
+        write_entry(&mut writer, temp_archive_path, &name, &bytes)?;
+        entries.push(entry_for(name, &bytes));
+    }
+
+    let manifest = NovelArchiveManifest {
+        format_version: ARCHIVE_FORMAT_VERSION,
+        novel_id: novel.id.clone(),
+        title: novel.title.clone(),
+        exported_at: Utc::now().to_rfc3339(),
+        entries,
+    };
+    let manifest_json = serde_json::to_string_pretty(&manifest).map_err(|e| {
+        MuvelError::internal(Message::SerializeFailed(FileKind::NovelArchive)).caused_by(e)
+    })?;
+    write_entry(
+        &mut writer,
+        temp_archive_path,
+        ARCHIVE_MANIFEST_ENTRY,
+        manifest_json.as_bytes(),
+    )?;
+
+    let file = writer.finish().map_err(|e| {
+        zip_error(
+            Message::FileWriteFailed(FileKind::NovelArchive),
+            e,
+            temp_archive_path,
+        )
+    })?;
+    file.sync_all().map_err(|e| {
+        MuvelError::io(Message::FileWriteFailed(FileKind::NovelArchive), &e)
+            .with_path(temp_archive_path)
+    })
+}
+
+fn open_archive(archive_path: &Path) -> MuvelResult<ZipArchive<fs::File>> {
+    let file = fs::File::open(archive_path).map_err(|e| {
+        MuvelError::io(Message::FileOpenFailed(FileKind::NovelArchive), &e).with_path(archive_path)
+    })?;
+    ZipArchive::new(file).map_err(|e| {
+        MuvelError::parse(Message::FileParseFailed(FileKind::NovelArchive))
+            .caused_by(e)
+            .with_path(archive_path)
+    })
+}
+
+/// 아카이브 항목을 읽습니다. `max_size`보다 크면 읽기를 멈춥니다.
+fn read_entry(
+    archive: &mut ZipArchive<fs::File>,
+    archive_path: &Path,
+    entry_name: &str,
+    max_size: u64,
+) -> MuvelResult<Vec<u8>> {
+    let entry = archive.by_name(entry_name).map_err(|_| {
+        MuvelError::not_found(Message::ArchiveEntryMissing {
+            entry: entry_name.to_string(),
+        })
+        .with_path(archive_path)
+    })?;
+    let mut bytes = Vec::new();
+    entry
+        .take(max_size.saturating_add(1))
+        .read_to_end(&mut bytes)
+        .map_err(|e| {
+            MuvelError::io(Message::FileReadFailed(FileKind::NovelArchive), &e)
+                .with_path(archive_path)
+        })?;
+    Ok(bytes)
+}
+
+/// 아카이브의 매니페스트를 읽습니다. 더 새로운 형식의 아카이브면 거부합니다.
+pub fn read_archive_manifest(archive_path: &Path) -> MuvelResult<NovelArchiveManifest> {
+    let mut archive = open_archive(archive_path)?;
+    let bytes = read_entry(
+        &mut archive,
+        archive_path,
+        ARCHIVE_MANIFEST_ENTRY,
+        MAX_MANIFEST_SIZE,
+    )?;
+    let manifest: NovelArchiveManifest = serde_json::from_slice(&bytes).map_err(|e| {
+        MuvelError::parse(Message::FileParseFailed(FileKind::NovelArchive))
+            .caused_by(e)
+            .with_path(archive_path)
+    })?;
+    if manifest.format_version > ARCHIVE_FORMAT_VERSION {
+        return Err(MuvelError::unsupported_version(Message::FileFormatTooNew {
+            kind: FileKind::NovelArchive,
+            version: manifest.format_version as u64,
+            supported: ARCHIVE_FORMAT_VERSION,
+        })
+        .with_path(archive_path));
+    }
+    Ok(manifest)
+}
+
+/// 매니페스트의 모든 항목을 체크섬을 확인하며 새 소설 폴더에 풀어 넣습니다.
+/// 소설 메타데이터는 `{폴더 이름}.muvl`로 저장됩니다. 메타데이터의 소설 ID가 매니페스트와 다르거나
+/// 실패하면 만든 폴더를 지웁니다.
+pub fn extract_novel_archive(
+    archive_path: &Path,
+    manifest: &NovelArchiveManifest,
+    novel_root_path: &Path,
+) -> MuvelResult<PathBuf> {
+    if novel_root_path.exists() {
+        return Err(
+            MuvelError::already_exists(Message::FolderAlreadyExists).with_path(novel_root_path)
+        );
+    }
+    let result = extract_entries(archive_path, manifest, novel_root_path);
+    if result.is_err() {
+        if let Err(e) = fs::remove_dir_all(novel_root_path) {
+            eprintln!(
+                "가져오기에 실패한 소설 폴더를 지우지 못했습니다 ({:?}): {}",
+                novel_root_path, e
+            );
+        }
+    }
+    result
+}
+
+fn extract_entries(
+    archive_path: &Path,
+    manifest: &NovelArchiveManifest,
+    novel_root_path: &Path,
+) -> MuvelResult<PathBuf> {
+    let mut archive = open_archive(archive_path)?;
+    let folder_name = novel_root_path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| {
+            MuvelError::invalid_input(Message::FolderNameUnreadable).with_path(novel_root_path)
+        })?;
+    let metadata_path =
+        novel_root_path.join(format!("{}.{}", folder_name, NOVEL_METADATA_EXTENSION));
+
+    fs::create_dir_all(novel_root_path).map_err(|e| {
+        MuvelError::io(Message::DirCreateFailed(FileKind::NovelRoot), &e).with_path(novel_root_path)
+    })?;
+
+    for entry in &manifest.entries {
+        let target_path = if entry.path == ARCHIVE_METADATA_ENTRY {
+            metadata_path.clone()
+        } else {
+            novel_root_path.join(relative_path_for(&entry.path)?)
+        };
+
+        let bytes = read_entry(&mut archive, archive_path, &entry.path, entry.size)?;
+        if bytes.len() as u64 != entry.size || sha256_hex(&bytes) != entry.sha256 {
+            return Err(MuvelError::parse(Message::ArchiveChecksumMismatch {
+                entry: entry.path.clone(),
+            })
+            .with_path(archive_path));
+        }
+
+        if let Some(parent) = target_path.parent() {
+            fs::create_dir_all(parent).map_err(|e| {
+                MuvelError::io(Message::DirCreateFailed(FileKind::NovelRoot), &e).with_path(parent)
+            })?;
+        }
+        durable_io::write_file_atomically(&target_path, &bytes)?;
+    }
+
+    if !metadata_path.is_file() {
+        return Err(MuvelError::not_found(Message::ArchiveEntryMissing {
+            entry: ARCHIVE_METADATA_ENTRY.to_string(),
+        })
+        .with_path(archive_path));
+    }
+    // 매니페스트와 다른 소설의 메타데이터가 들어 있으면 인덱스가 어긋나므로 거부
+    let novel: Novel =
+        format_migration::read_versioned_file(&metadata_path, FileKind::NovelMetadata)?;
+    if novel.id != manifest.novel_id {
+        return Err(MuvelError::invalid_input(Message::ArchiveNovelIdMismatch {
+            expected: manifest.novel_id.clone(),
+            found: novel.id,
+        })
+        .with_path(archive_path));
+    }
+    Ok(metadata_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relative_path_for_accepts_nested_entries() {
+        assert_eq!(
+            relative_path_for("episodes/abc.mvle").unwrap(),
+            Path::new("episodes").join("abc.mvle")
+        );
+    }
+
+    #[test]
+    fn relative_path_for_rejects_paths_outside_novel_folder() {
+        for entry_name in [
+            "",
+            "..",
+            "../evil.txt",
+            "episodes/../../evil.txt",
+            "./episodes/abc.mvle",
+            "episodes//abc.mvle",
+            "/etc/passwd",
+            "C:/Windows/evil.txt",
+            "C:evil.txt",
+            "..\\evil.txt",
+            "episodes\\..\\..\\evil.txt",
+        ] {
+            assert!(
+                relative_path_for(entry_name).is_err(),
+                "{:?} should be rejected",
+                entry_name
+            );
+        }
+    }
+}
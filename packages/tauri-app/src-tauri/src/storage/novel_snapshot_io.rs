@@ -0,0 +1,301 @@
+use crate::i18n::{FileKind, Message};
+use crate::models::enums::snapshot_reason::SnapshotReason;
+use crate::models::episode::LocalEpisodeData;
+use crate::models::error::{MuvelError, MuvelResult};
+use crate::models::novel::Novel;
+use crate::models::snapshot::{NovelSnapshot, NovelSnapshotItemSummary, NovelSnapshotSummary};
+use crate::models::wiki_page::WikiPage;
+use crate::storage::block_store::{self, BlockRef};
+use crate::storage::{durable_io, format_migration};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// 소설 전체 스냅샷을 저장할 폴더 이름 (소설 루트 하위)
+pub const NOVEL_SNAPSHOTS_DIRNAME: &str = "snapshots";
+pub const NOVEL_SNAPSHOT_FILE_EXTENSION: &str = "mvlns"; // Muvel Novel Snapshot
+
+/// 스냅샷에 포함된 항목. 블록은 비워서 저장하고 블록 저장소 참조로 대신합니다.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct StoredSnapshotItem<T> {
+    data: T,
+    block_refs: Vec<BlockRef>,
+}
+
+/// 소설 스냅샷 파일(.mvlns)에 실제로 저장되는 형태입니다.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct StoredNovelSnapshot {
+    id: String,
+    novel_id: String,
+    reason: SnapshotReason,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+    #[serde(default)]
+    pinned: bool,
+    #[serde(rename = "createdAt")]
+    created_at: String,
+    novel: Novel,
+    episodes: Vec<StoredSnapshotItem<LocalEpisodeData>>,
+    wiki_pages: Vec<StoredSnapshotItem<WikiPage>>,
+}
+
+/// 소설 스냅샷 파일의 전체 경로를 구성합니다.
+/// 예: {novel_root_path}/snapshots/{snapshot_id}.mvlns
+fn get_novel_snapshot_file_path(novel_root_path: &Path, snapshot_id: &str) -> PathBuf {
+    novel_root_path
+        .join(NOVEL_SNAPSHOTS_DIRNAME)
+        .join(format!("{}.{}", snapshot_id, NOVEL_SNAPSHOT_FILE_EXTENSION))
+}
+
+fn read_stored_novel_snapshot(snapshot_file_path: &Path) -> MuvelResult<StoredNovelSnapshot> {
+    if !snapshot_file_path.exists() {
+        return Err(
+            MuvelError::not_found(Message::FileNotFound(FileKind::NovelSnapshot))
+                .with_path(snapshot_file_path),
+        );
+    }
+    format_migration::read_versioned_file(snapshot_file_path, FileKind::NovelSnapshot)
+}
+
+/// 소설 스냅샷을 파일에 저장합니다.
+/// 에피소드와 위키 페이지의 블록은 블록 저장소에 저장되어 에피소드 스냅샷과 공유됩니다.
+pub fn write_novel_snapshot_file(
+    novel_root_path: &Path,
+    snapshot: &NovelSnapshot,
+    compress_blocks: bool,
+) -> MuvelResult<()> {
+    let snapshots_dir = novel_root_path.join(NOVEL_SNAPSHOTS_DIRNAME);
+    fs::create_dir_all(&snapshots_dir).map_err(|e| {
+        MuvelError::io(Message::DirCreateFailed(FileKind::NovelSnapshot), &e)
+            .with_path(&snapshots_dir)
+    })?;
+
+    let mut episodes = Vec::with_capacity(snapshot.episodes.len());
+    for episode in &snapshot.episodes {
+        let block_refs =
+            block_store::put_blocks(novel_root_path, &episode.blocks, compress_blocks)?;
+        let mut data = episode.clone();
+        data.blocks = Vec::new();
+        episodes.push(StoredSnapshotItem { data, block_refs });
+    }
+    let mut wiki_pages = Vec::with_capacity(snapshot.wiki_pages.len());
+    for page in &snapshot.wiki_pages {
+        let block_refs = block_store::put_blocks(novel_root_path, &page.blocks, compress_blocks)?;
+        let mut data = page.clone();
+        data.blocks = Vec::new();
+        wiki_pages.push(StoredSnapshotItem { data, block_refs });
+    }
+
+    let stored = StoredNovelSnapshot {
+        id: snapshot.id.clone(),
+        novel_id: snapshot.novel_id.clone(),
+        reason: snapshot.reason.clone(),
+        label: snapshot.label.clone(),
+        note: snapshot.note.clone(),
+        pinned: snapshot.pinned,
+        created_at: snapshot.created_at.clone(),
+        novel: snapshot.novel.clone(),
+        episodes,
+        wiki_pages,
+    };
+
+    let snapshot_file_path = get_novel_snapshot_file_path(novel_root_path, &snapshot.id);
+    write_stored_novel_snapshot(&snapshot_file_path, &stored)
+}
+
+fn write_stored_novel_snapshot(
+    snapshot_file_path: &Path,
+    stored: &StoredNovelSnapshot,
+) -> MuvelResult<()> {
+    let json_string = format_migration::to_versioned_json(stored, FileKind::NovelSnapshot)?;
+    durable_io::write_file_atomically(snapshot_file_path, json_string.as_bytes())
+        .map_err(|e| e.context(Message::FileWriteFailed(FileKind::NovelSnapshot)))?;
+    Ok(())
+}
+
+/// 저장된 소설 스냅샷에서 목록 표시용 요약을 만듭니다.
+fn summary_of(stored: StoredNovelSnapshot) -> NovelSnapshotSummary {
+    NovelSnapshotSummary {
+        id: stored.id,
+        novel_id: stored.novel_id,
+        reason: stored.reason,
+        label: stored.label,
+        note: stored.note,
+        pinned: stored.pinned,
+        created_at: stored.created_at,
+        novel_title: stored.novel.title,
+        episodes: stored
+            .episodes
+            .into_iter()
+            .map(|item| NovelSnapshotItemSummary {
+                id: item.data.id,
+                title: item.data.title,
+            })
+            .collect(),
+        wiki_pages: stored
+            .wiki_pages
+            .into_iter()
+            .map(|item| NovelSnapshotItemSummary {
+                id: item.data.id,
+                title: item.data.title,
+            })
+            .collect(),
+    }
+}
+
+/// 소설 스냅샷의 요약을 읽습니다. (블록은 읽지 않음)
+pub fn read_novel_snapshot_summary(
+    novel_root_path: &Path,
+    snapshot_id: &str,
+) -> MuvelResult<NovelSnapshotSummary> {
+    let snapshot_file_path = get_novel_snapshot_file_path(novel_root_path, snapshot_id);
+    read_stored_novel_snapshot(&snapshot_file_path).map(summary_of)
+}
+
+/// 소설 스냅샷의 이름, 메모, 고정 여부를 바꿔 저장하고 바뀐 요약을 반환합니다.
+/// 블록 참조는 그대로 두므로 블록 저장소는 건드리지 않습니다.
+pub fn update_novel_snapshot_metadata(
+    novel_root_path: &Path,
+    snapshot_id: &str,
+    label: Option<String>,
+    note: Option<String>,
+    pinned: bool,
+) -> MuvelResult<NovelSnapshotSummary> {
+    let snapshot_file_path = get_novel_snapshot_file_path(novel_root_path, snapshot_id);
+    let mut stored = read_stored_novel_snapshot(&snapshot_file_path)?;
+    stored.label = label;
+    stored.note = note;
+    stored.pinned = pinned;
+    write_stored_novel_snapshot(&snapshot_file_path, &stored)?;
+    Ok(summary_of(stored))
+}
+
+/// 소설 스냅샷 파일을 읽고 블록까지 모두 채운 NovelSnapshot을 반환합니다.
+pub fn read_novel_snapshot_file(
+    novel_root_path: &Path,
+    snapshot_id: &str,
+) -> MuvelResult<NovelSnapshot> {
+    let snapshot_file_path = get_novel_snapshot_file_path(novel_root_path, snapshot_id);
+    let stored = read_stored_novel_snapshot(&snapshot_file_path)?;
+
+    let mut episodes = Vec::with_capacity(stored.episodes.len());
+    for item in stored.episodes {
+        let mut episode = item.data;
+        episode.blocks = block_store::get_blocks(novel_root_path, &item.block_refs)?;
+        episodes.push(episode);
+    }
+    let mut wiki_pages = Vec::with_capacity(stored.wiki_pages.len());
+    for item in stored.wiki_pages {
+        let mut page = item.data;
+        page.blocks = block_store::get_blocks(novel_root_path, &item.block_refs)?;
+        wiki_pages.push(page);
+    }
+
+    Ok(NovelSnapshot {
+        id: stored.id,
+        novel_id: stored.novel_id,
+        reason: stored.reason,
+        label: stored.label,
+        note: stored.note,
+        pinned: stored.pinned,
+        created_at: stored.created_at,
+        novel: stored.novel,
+        episodes,
+        wiki_pages,
+    })
+}
+
+/// 특정 소설 스냅샷 파일을 삭제합니다.
+pub fn delete_novel_snapshot_file(novel_root_path: &Path, snapshot_id: &str) -> MuvelResult<()> {
+    let snapshot_file_path = get_novel_snapshot_file_path(novel_root_path, snapshot_id);
+    if snapshot_file_path.is_file() {
+        fs::remove_file(&snapshot_file_path).map_err(|e| {
+            MuvelError::io(Message::FileDeleteFailed(FileKind::NovelSnapshot), &e)
+                .with_path(&snapshot_file_path)
+        })?;
+    } else {
+        eprintln!(
+            "삭제할 소설 스냅샷 파일이 존재하지 않습니다: {:?}",
+            snapshot_file_path
+        );
+    }
+    Ok(())
+}
+
+/// 소설 스냅샷 파일 경로 목록을 반환합니다.
+fn list_novel_snapshot_file_paths(novel_root_path: &Path) -> MuvelResult<Vec<PathBuf>> {
+    let snapshots_dir = novel_root_path.join(NOVEL_SNAPSHOTS_DIRNAME);
+    let mut paths = Vec::new();
+    if !snapshots_dir.is_dir() {
+        return Ok(paths);
+    }
+    for entry in fs::read_dir(&snapshots_dir).map_err(|e| {
+        MuvelError::io(Message::DirReadFailed(FileKind::NovelSnapshot), &e)
+            .with_path(&snapshots_dir)
+    })? {
+        let path = entry
+            .map_err(|e| MuvelError::io(Message::DirEntryReadFailed, &e))?
+            .path();
+        if path.is_file()
+            && path
+                .extension()
+                .is_some_and(|ext| ext == NOVEL_SNAPSHOT_FILE_EXTENSION)
+        {
+            paths.push(path);
+        }
+    }
+    Ok(paths)
+}
+
+/// 소설의 모든 스냅샷 요약 목록을 생성 시각 순으로 반환합니다. (블록은 읽지 않음)
+pub fn list_novel_snapshot_summaries(
+    novel_root_path: &Path,
+) -> MuvelResult<Vec<NovelSnapshotSummary>> {
+    let mut summaries = Vec::new();
+    for path in list_novel_snapshot_file_paths(novel_root_path)? {
+        match read_stored_novel_snapshot(&path) {
+            Ok(stored) => summaries.push(summary_of(stored)),
+            Err(e) => eprintln!("소설 스냅샷 요약 읽기 실패: {}. 건너뜁니다.", e),
+        }
+    }
+    summaries.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    Ok(summaries)
+}
+
+/// 모든 소설 스냅샷 파일이 참조하는 블록 해시 목록을 반환합니다.
+pub fn collect_referenced_block_hashes(novel_root_path: &Path) -> MuvelResult<HashSet<String>> {
+    let mut hashes = HashSet::new();
+    for path in list_novel_snapshot_file_paths(novel_root_path)? {
+        // 참조를 읽지 못한 파일이 있으면 블록이 잘못 삭제될 수 있으므로 오류로 처리
+        let stored = read_stored_novel_snapshot(&path)?;
+        let episode_refs = stored.episodes.into_iter().flat_map(|item| item.block_refs);
+        let wiki_refs = stored
+            .wiki_pages
+            .into_iter()
+            .flat_map(|item| item.block_refs);
+        hashes.extend(episode_refs.chain(wiki_refs).map(|r| r.hash));
+    }
+    Ok(hashes)
+}
+
+/// 모든 소설 스냅샷에 저장된 소설/위키 페이지 썸네일 참조 목록을 반환합니다.
+pub fn collect_referenced_thumbnails(novel_root_path: &Path) -> MuvelResult<Vec<String>> {
+    let mut thumbnails = Vec::new();
+    for path in list_novel_snapshot_file_paths(novel_root_path)? {
+        // 참조를 읽지 못한 파일이 있으면 사용 중인 이미지가 삭제될 수 있으므로 오류로 처리
+        let stored = read_stored_novel_snapshot(&path)?;
+        thumbnails.extend(stored.novel.thumbnail);
+        thumbnails.extend(
+            stored
+                .wiki_pages
+                .into_iter()
+                .filter_map(|item| item.data.thumbnail),
+        );
+    }
+    Ok(thumbnails)
+}
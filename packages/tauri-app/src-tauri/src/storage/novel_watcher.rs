@@ -0,0 +1,330 @@
+use crate::models::commons::{ExternalChangeEvent, ExternalChangeKind, SyncConflictDetectedEvent};
+use crate::storage::episode_io::{EPISODES_DIRNAME, EPISODE_FILE_EXTENSION};
+use crate::storage::novel_io::NOVEL_METADATA_EXTENSION;
+use crate::storage::wiki_page_io::{WIKI_PAGES_DIRNAME, WIKI_PAGE_FILE_EXTENSION};
+use crate::storage::{index_manager, item_index_manager, sync_conflict_io};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::{Mutex, PoisonError};
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+
+pub const NOVEL_CHANGED_EXTERNALLY_EVENT: &str = "novel-changed-externally";
+pub const EPISODE_CHANGED_EXTERNALLY_EVENT: &str = "episode-changed-externally";
+pub const WIKI_PAGE_CHANGED_EXTERNALLY_EVENT: &str = "wiki-page-changed-externally";
+pub const SYNC_CONFLICT_DETECTED_EVENT: &str = "sync-conflict-detected";
+
+// 동기화 서비스는 파일 하나를 여러 번 나눠 쓰므로, 이벤트가 잠잠해질 때까지 기다렸다가 한 번에 처리
+const EVENT_DEBOUNCE: Duration = Duration::from_millis(500);
+// 앱이 직접 쓴 파일의 이벤트를 무시하는 시간
+const INTERNAL_CHANGE_GRACE: Duration = Duration::from_secs(3);
+
+/// 앱이 직접 쓰거나 지운 파일과 그 시각. 감시 이벤트에서 외부 변경으로 취급하지 않습니다.
+static INTERNAL_CHANGES: Mutex<Option<HashMap<PathBuf, Instant>>> = Mutex::new(None);
+
+/// 감시 중인 소설 루트(정규화된 경로)와 소설 ID
+#[derive(Default)]
+struct WatcherState {
+    watcher: Option<RecommendedWatcher>,
+    roots: HashMap<PathBuf, String>,
+}
+
+/// Tauri State – 등록된 소설 폴더의 파일 시스템 감시
+#[derive(Default)]
+pub struct NovelWatcher(Mutex<WatcherState>);
+
+/// 이벤트 경로와 앱이 사용하는 경로를 비교할 수 있도록 정규화합니다.
+/// 파일이 이미 삭제되었을 수 있으므로 부모 디렉토리만 정규화합니다.
+fn normalize_path(path: &Path) -> PathBuf {
+    match (path.parent(), path.file_name()) {
+        (Some(parent), Some(file_name)) => parent
+            .canonicalize()
+            .map(|parent| parent.join(file_name))
+            .unwrap_or_else(|_| path.to_path_buf()),
+        _ => path.to_path_buf(),
+    }
+}
+
+/// 앱이 파일을 쓰거나 지웠음을 기록합니다. 잠시 동안 이 파일의 감시 이벤트는 무시됩니다.
+pub fn mark_internal_change(path: &Path) {
+    let now = Instant::now();
+    let mut guard = INTERNAL_CHANGES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    let changes = guard.get_or_insert_with(HashMap::new);
+    changes.retain(|_, changed_at| now.duration_since(*changed_at) < INTERNAL_CHANGE_GRACE);
+    changes.insert(normalize_path(path), now);
+}
+
+fn is_internal_change(path: &Path) -> bool {
+    let guard = INTERNAL_CHANGES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    guard
+        .as_ref()
+        .and_then(|changes| changes.get(path))
+        .is_some_and(|changed_at| changed_at.elapsed() < INTERNAL_CHANGE_GRACE)
+}
+
+/// 감시를 시작하고, 인덱스에 등록된 모든 소설 폴더를 감시 대상으로 등록합니다.
+pub fn start(app_handle: &AppHandle) -> notify::Result<()> {
+    let (tx, rx) = mpsc::channel();
+    let watcher = notify::recommended_watcher(move |res| {
+        let _ = tx.send(res);
+    })?;
+
+    let event_app_handle = app_handle.clone();
+    thread::spawn(move || run_event_loop(event_app_handle, rx));
+
+    app_handle
+        .state::<NovelWatcher>()
+        .0
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .watcher = Some(watcher);
+    sync_watched_roots(app_handle);
+    Ok(())
+}
+
+/// 감시 대상을 인덱스에 등록된 소설 폴더 목록과 맞춥니다. 감시가 시작되지 않았으면 아무것도 하지 않습니다.
+pub fn sync_watched_roots(app_handle: &AppHandle) {
+    let entries = match index_manager::get_all_novel_entries(app_handle) {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("감시할 소설 목록을 읽지 못했습니다: {}", e);
+            return;
+        }
+    };
+    let desired: HashMap<PathBuf, String> = entries
+        .into_iter()
+        .filter_map(|entry| {
+            let root = PathBuf::from(entry.path?).canonicalize().ok()?;
+            root.is_dir().then_some((root, entry.id))
+        })
+        .collect();
+
+    let state = app_handle.state::<NovelWatcher>();
+    let mut state = state.0.lock().unwrap_or_else(PoisonError::into_inner);
+    let WatcherState { watcher, roots } = &mut *state;
+    let Some(watcher) = watcher.as_mut() else {
+        return;
+    };
+
+    roots.retain(|root, _| {
+        if desired.contains_key(root) {
+            return true;
+        }
+        if let Err(e) = watcher.unwatch(root) {
+            eprintln!("소설 폴더 감시 해제 실패 ({:?}): {}", root, e);
+        }
+        false
+    });
+    for (root, novel_id) in desired {
+        if let Some(watched_novel_id) = roots.get_mut(&root) {
+            *watched_novel_id = novel_id;
+            continue;
+        }
+        match watcher.watch(&root, RecursiveMode::Recursive) {
+            Ok(()) => {
+                roots.insert(root, novel_id);
+            }
+            Err(e) => eprintln!("소설 폴더 감시 실패 ({:?}): {}", root, e),
+        }
+    }
+}
+
+fn run_event_loop(app_handle: AppHandle, rx: Receiver<notify::Result<Event>>) {
+    while let Ok(first) = rx.recv() {
+        let mut changed_paths = HashSet::new();
+        collect_changed_paths(first, &mut changed_paths);
+
+        let mut disconnected = false;
+        loop {
+            match rx.recv_timeout(EVENT_DEBOUNCE) {
+                Ok(res) => collect_changed_paths(res, &mut changed_paths),
+                Err(RecvTimeoutError::Timeout) => break,
+                Err(RecvTimeoutError::Disconnected) => {
+                    disconnected = true;
+                    break;
+                }
+            }
+        }
+
+        for path in changed_paths {
+            handle_changed_path(&app_handle, &path);
+        }
+        if disconnected {
+            break;
+        }
+    }
+}
+
+fn collect_changed_paths(res: notify::Result<Event>, changed_paths: &mut HashSet<PathBuf>) {
+    match res {
+        Ok(event) => {
+            if matches!(event.kind, EventKind::Access(_)) {
+                return;
+            }
+            changed_paths.extend(event.paths.iter().map(|path| normalize_path(path)));
+        }
+        Err(e) => eprintln!("파일 감시 오류: {}", e),
+    }
+}
+
+/// 경로가 속한 감시 중인 소설 루트와 소설 ID를 찾습니다.
+fn find_watched_novel(app_handle: &AppHandle, path: &Path) -> Option<(PathBuf, String)> {
+    let state = app_handle.state::<NovelWatcher>();
+    let state = state.0.lock().unwrap_or_else(PoisonError::into_inner);
+    state
+        .roots
+        .iter()
+        .find(|(root, _)| path.starts_with(root))
+        .map(|(root, novel_id)| (root.clone(), novel_id.clone()))
+}
+
+fn handle_changed_path(app_handle: &AppHandle, path: &Path) {
+    let Some(extension) = path.extension().and_then(|ext| ext.to_str()) else {
+        return;
+    };
+    if ![
+        NOVEL_METADATA_EXTENSION,
+        EPISODE_FILE_EXTENSION,
+        WIKI_PAGE_FILE_EXTENSION,
+    ]
+    .contains(&extension)
+        || is_internal_change(path)
+    {
+        return;
+    }
+    let Some((novel_root_path, novel_id)) = find_watched_novel(app_handle, path) else {
+        return;
+    };
+    let Some(parent) = path.parent() else {
+        return;
+    };
+    // 충돌 사본은 아이템 인덱스에 넣지 않고, 새로 생겼을 때 알리기만 함
+    if sync_conflict_io::is_conflict_copy(path) {
+        if path.is_file() {
+            emit_conflict_detected(app_handle, &novel_root_path, path, novel_id);
+        }
+        return;
+    }
+
+    if extension == NOVEL_METADATA_EXTENSION && parent == novel_root_path {
+        let change = if path.is_file() {
+            ExternalChangeKind::Modified
+        } else {
+            ExternalChangeKind::Removed
+        };
+        emit_change(
+            app_handle,
+            NOVEL_CHANGED_EXTERNALLY_EVENT,
+            novel_id,
+            None,
+            change,
+        );
+    } else if extension == EPISODE_FILE_EXTENSION
+        && parent == novel_root_path.join(EPISODES_DIRNAME)
+    {
+        handle_changed_item(
+            app_handle,
+            path,
+            novel_id,
+            "episode",
+            EPISODE_CHANGED_EXTERNALLY_EVENT,
+        );
+    } else if extension == WIKI_PAGE_FILE_EXTENSION
+        && parent == novel_root_path.join(WIKI_PAGES_DIRNAME)
+    {
+        handle_changed_item(
+            app_handle,
+            path,
+            novel_id,
+            "wiki_page",
+            WIKI_PAGE_CHANGED_EXTERNALLY_EVENT,
+        );
+    }
+}
+
+/// 에피소드/위키 페이지 파일의 변경을 아이템 인덱스에 반영하고 이벤트를 보냅니다.
+fn handle_changed_item(
+    app_handle: &AppHandle,
+    path: &Path,
+    novel_id: String,
+    item_type: &str,
+    event: &str,
+) {
+    let Some(item_id) = path.file_stem().and_then(|stem| stem.to_str()) else {
+        return;
+    };
+    let indexed = match item_index_manager::get_item_entry(app_handle, item_id) {
+        Ok(entry) => entry.is_some_and(|entry| entry.novel_id == novel_id),
+        Err(e) => {
+            eprintln!("아이템 인덱스 조회 실패 (ID: {}): {}", item_id, e);
+            return;
+        }
+    };
+
+    let result = match (path.is_file(), indexed) {
+        (true, true) => Ok(ExternalChangeKind::Modified),
+        (true, false) => item_index_manager::upsert_item_novel_mapping(
+            app_handle,
+            item_id.to_string(),
+            novel_id.clone(),
+            item_type.to_string(),
+        )
+        .map(|_| ExternalChangeKind::Created),
+        (false, true) => item_index_manager::remove_item_novel_mapping(app_handle, item_id)
+            .map(|_| ExternalChangeKind::Removed),
+        (false, false) => return,
+    };
+
+    match result {
+        Ok(change) => emit_change(
+            app_handle,
+            event,
+            novel_id,
+            Some(item_id.to_string()),
+            change,
+        ),
+        Err(e) => eprintln!("외부 변경을 아이템 인덱스에 반영하지 못했습니다: {}", e),
+    }
+}
+
+fn emit_conflict_detected(
+    app_handle: &AppHandle,
+    novel_root_path: &Path,
+    path: &Path,
+    novel_id: String,
+) {
+    let Ok(conflict) = sync_conflict_io::inspect_conflict_copy(novel_root_path, path) else {
+        return;
+    };
+    let payload = SyncConflictDetectedEvent { novel_id, conflict };
+    if let Err(e) = app_handle.emit(SYNC_CONFLICT_DETECTED_EVENT, payload) {
+        eprintln!(
+            "충돌 사본 이벤트 전송 실패 ({}): {}",
+            SYNC_CONFLICT_DETECTED_EVENT, e
+        );
+    }
+}
+
+fn emit_change(
+    app_handle: &AppHandle,
+    event: &str,
+    novel_id: String,
+    item_id: Option<String>,
+    change: ExternalChangeKind,
+) {
+    let payload = ExternalChangeEvent {
+        novel_id,
+        item_id,
+        change,
+    };
+    if let Err(e) = app_handle.emit(event, payload) {
+        eprintln!("외부 변경 이벤트 전송 실패 ({}): {}", event, e);
+    }
+}
@@ -0,0 +1,401 @@
+use crate::i18n::{FileKind, Message};
+use crate::models::block::Block;
+use crate::models::error::{MuvelError, MuvelResult};
+use crate::storage::novel_io::RESOURCES_DIRNAME;
+use crate::storage::{image_resource_io, index_resolver};
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use tauri::AppHandle;
+
+/// 소설 리소스를 가리키는 URI 스킴. 웹뷰가 이 스킴을 요청하면 소설 폴더 기준으로 파일을 찾아 응답합니다.
+pub const RESOURCE_URI_SCHEME: &str = "muvel-resource";
+// 파일에 저장되는 형식: `muvel-resource://localhost/{소설 ID}/{resources 기준 상대 경로}`
+// 소설 폴더의 위치가 아닌 ID를 담으므로, 폴더를 옮기거나 다른 기기로 동기화해도 그대로 유효합니다.
+const RESOURCE_URI_PREFIX: &str = "muvel-resource://localhost/";
+// Windows/Android 웹뷰는 사용자 정의 스킴을 `http://{스킴}.localhost/`로 요청함
+const RESOURCE_URL_PREFIXES_FOR_WEBVIEW: [&str; 2] = [
+    "http://muvel-resource.localhost/",
+    "https://muvel-resource.localhost/",
+];
+// 이전 버전이 저장한 asset 프로토콜(convertFileSrc) URL 접두사 (플랫폼별)
+const ASSET_URL_PREFIXES: [&str; 3] = [
+    "asset://localhost/",
+    "http://asset.localhost/",
+    "https://asset.localhost/",
+];
+const FILE_URL_PREFIX: &str = "file://";
+
+// URI 경로 세그먼트에서 그대로 두는 문자 (RFC 3986 unreserved)
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// 세그먼트 목록을 안전한 상대 경로로 합칩니다. 빈 경로나 `..` 등 폴더 밖을 가리키는 세그먼트가 있으면 None을 반환합니다.
+fn safe_relative_path<'s>(segments: impl IntoIterator<Item = &'s str>) -> Option<PathBuf> {
+    let mut relative = PathBuf::new();
+    for segment in segments {
+        // Windows에서 경로 구분자나 드라이브 접두사로 해석되는 세그먼트는 OS와 관계없이 거부
+        if segment.contains(['\\', ':']) {
+            return None;
+        }
+        match Path::new(segment)
+            .components()
+            .collect::<Vec<_>>()
+            .as_slice()
+        {
+            [Component::Normal(_)] => relative.push(segment),
+            _ => return None,
+        }
+    }
+    (!relative.as_os_str().is_empty()).then_some(relative)
+}
+
+/// `resources` 폴더 기준 상대 경로로 리소스 URI를 만듭니다.
+pub fn resource_uri_for(novel_id: &str, relative_path: &Path) -> Option<String> {
+    let segments = relative_path
+        .components()
+        .map(|component| match component {
+            Component::Normal(part) => part
+                .to_str()
+                .map(|part| utf8_percent_encode(part, PATH_SEGMENT).to_string()),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(format!(
+        "{}{}/{}",
+        RESOURCE_URI_PREFIX,
+        utf8_percent_encode(novel_id, PATH_SEGMENT),
+        segments.join("/")
+    ))
+}
+
+/// 리소스 URI의 경로 부분(`/{소설 ID}/{상대 경로}`)에서 소설 ID와 상대 경로를 구합니다.
+pub fn parse_resource_path(uri_path: &str) -> Option<(String, PathBuf)> {
+    let uri_path = uri_path.split(['?', '#']).next()?;
+    let decoded = uri_path
+        .trim_start_matches('/')
+        .split('/')
+        .map(|segment| {
+            percent_decode_str(segment)
+                .decode_utf8()
+                .ok()
+                .map(|s| s.into_owned())
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let (novel_id, rest) = decoded.split_first()?;
+    if novel_id.is_empty() {
+        return None;
+    }
+    let relative_path = safe_relative_path(rest.iter().map(String::as_str))?;
+    Some((novel_id.clone(), relative_path))
+}
+
+/// 리소스 URI(웹뷰용 `http://muvel-resource.localhost/` 형식 포함)에서 소설 ID와 상대 경로를 구합니다.
+pub fn parse_resource_uri(uri: &str) -> Option<(String, PathBuf)> {
+    std::iter::once(RESOURCE_URI_PREFIX)
+        .chain(RESOURCE_URL_PREFIXES_FOR_WEBVIEW)
+        .find_map(|prefix| uri.strip_prefix(prefix))
+        .and_then(parse_resource_path)
+}
+
+/// 상대 경로가 가리키는 리소스 파일의 실제 경로
+pub fn resolve_resource_path(novel_root_path: &Path, relative_path: &Path) -> PathBuf {
+    novel_root_path.join(RESOURCES_DIRNAME).join(relative_path)
+}
+
+/// 확장자로 리소스의 MIME 타입을 정합니다.
+pub fn resource_mime_type(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_default();
+    match extension.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "avif" => "image/avif",
+        "bmp" => "image/bmp",
+        "svg" => "image/svg+xml",
+        "ico" => "image/x-icon",
+        "pdf" => "application/pdf",
+        "mp3" => "audio/mpeg",
+        "ogg" | "oga" => "audio/ogg",
+        "wav" => "audio/wav",
+        "m4a" => "audio/mp4",
+        "flac" => "audio/flac",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        "txt" => "text/plain",
+        "md" => "text/markdown",
+        "html" | "htm" => "text/html",
+        "json" => "application/json",
+        _ => "application/octet-stream",
+    }
+}
+
+/// 웹뷰가 문서로 열면 스크립트를 실행할 수 있는 MIME 타입인지 확인합니다. (HTML, SVG)
+/// 리소스 프로토콜은 이런 리소스를 샌드박스에 가두고, 문서로 열지 않고 내려받도록 응답합니다.
+pub fn is_active_content(mime_type: &str) -> bool {
+    matches!(mime_type, "text/html" | "image/svg+xml")
+}
+
+/// 리소스 프로토콜 요청 경로를 소설 폴더 기준으로 해석해 파일 내용과 MIME 타입을 반환합니다.
+pub fn load_resource(
+    app_handle: &AppHandle,
+    uri_path: &str,
+) -> MuvelResult<(Vec<u8>, &'static str)> {
+    let (novel_id, relative_path) = parse_resource_path(uri_path).ok_or_else(|| {
+        MuvelError::invalid_input(Message::InvalidResourceUri {
+            uri: uri_path.to_string(),
+        })
+    })?;
+    let novel_root_path = index_resolver::resolve_novel_root(app_handle, &novel_id)?;
+    let mut resource_path = resolve_resource_path(&novel_root_path, &relative_path);
+    if !resource_path.is_file() {
+        if let Some(image_relative_path) =
+            image_resource_io::image_relative_path_for(&relative_path)
+        {
+            // 썸네일은 처음 요청될 때 만들어 두고, 만들 수 없으면 원본 이미지로 응답
+            resource_path =
+                match image_resource_io::create_thumbnail(&novel_root_path, &image_relative_path) {
+                    Ok(thumbnail_relative_path) => {
+                        resolve_resource_path(&novel_root_path, &thumbnail_relative_path)
+                    }
+                    Err(e) => {
+                        eprintln!("썸네일을 만들지 못했습니다 ({:?}): {}", relative_path, e);
+                        resolve_resource_path(&novel_root_path, &image_relative_path)
+                    }
+                };
+        }
+    }
+    let bytes = fs::read(&resource_path).map_err(|e| {
+        MuvelError::io(Message::FileReadFailed(FileKind::Resource), &e).with_path(&resource_path)
+    })?;
+    Ok((bytes, resource_mime_type(&resource_path)))
+}
+
+/// 이전 형식의 참조(절대 경로, asset/file URL)에서 로컬 파일 경로 문자열을 구합니다.
+fn local_path_of(reference: &str) -> Option<String> {
+    let encoded = ASSET_URL_PREFIXES
+        .iter()
+        .chain(std::iter::once(&FILE_URL_PREFIX))
+        .find_map(|prefix| reference.strip_prefix(prefix));
+    if let Some(encoded) = encoded {
+        let encoded = encoded.split(['?', '#']).next()?;
+        return percent_decode_str(encoded)
+            .decode_utf8()
+            .ok()
+            .map(|path| path.into_owned());
+    }
+    // Unix 절대 경로 또는 Windows 드라이브 경로 (다른 OS에서 저장된 경로일 수 있으므로 직접 확인)
+    let is_absolute =
+        reference.starts_with('/') || reference.get(1..3).is_some_and(|s| s == ":\\" || s == ":/");
+    is_absolute.then(|| reference.to_string())
+}
+
+/// 이전 형식의 참조가 이 소설의 리소스를 가리키면 `resources` 기준 상대 경로를 반환합니다.
+/// 폴더를 옮기기 전의 경로일 수 있으므로, 마지막 `resources` 폴더 이후 경로의 파일이 현재 소설 폴더에 있으면 같은 리소스로 봅니다.
+fn legacy_resource_relative_path(reference: &str, novel_root_path: &Path) -> Option<PathBuf> {
+    let local_path = local_path_of(reference)?;
+    let segments: Vec<&str> = local_path
+        .split(['/', '\\'])
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    let resources_index = segments
+        .iter()
+        .rposition(|segment| *segment == RESOURCES_DIRNAME)?;
+    let relative_path = safe_relative_path(segments[resources_index + 1..].iter().copied())?;
+
+    let resources_dir = novel_root_path.join(RESOURCES_DIRNAME);
+    let is_current_root = Path::new(&local_path).starts_with(&resources_dir);
+    (is_current_root || resources_dir.join(&relative_path).is_file()).then_some(relative_path)
+}
+
+/// 참조 문자열을 리소스 URI로 바꿔야 하면 바꾼 값을 반환합니다.
+/// 웹뷰용 형식으로 저장된 리소스 URI는 저장 형식으로 되돌립니다.
+pub fn relativize_resource_ref(
+    reference: &str,
+    novel_id: &str,
+    novel_root_path: &Path,
+) -> Option<String> {
+    if reference.starts_with(RESOURCE_URI_PREFIX) {
+        return None;
+    }
+    if let Some((uri_novel_id, relative_path)) = parse_resource_uri(reference) {
+        return resource_uri_for(&uri_novel_id, &relative_path);
+    }
+    let relative_path = legacy_resource_relative_path(reference, novel_root_path)?;
+    resource_uri_for(novel_id, &relative_path)
+}
+
+/// JSON 값(블록 content, attr 등) 안의 모든 문자열에서 이전 형식의 리소스 참조를 리소스 URI로 바꿉니다.
+/// 바꾼 참조 수를 반환합니다.
+pub fn relativize_resource_refs(
+    value: &mut Value,
+    novel_id: &str,
+    novel_root_path: &Path,
+) -> usize {
+    match value {
+        Value::String(reference) => {
+            match relativize_resource_ref(reference, novel_id, novel_root_path) {
+                Some(uri) => {
+                    *reference = uri;
+                    1
+                }
+                None => 0,
+            }
+        }
+        Value::Array(items) => items
+            .iter_mut()
+            .map(|item| relativize_resource_refs(item, novel_id, novel_root_path))
+            .sum(),
+        Value::Object(object) => object
+            .values_mut()
+            .map(|item| relativize_resource_refs(item, novel_id, novel_root_path))
+            .sum(),
+        _ => 0,
+    }
+}
+
+/// 문자열 필드(썸네일 등)의 이전 형식 리소스 참조를 리소스 URI로 바꿉니다.
+pub fn relativize_optional_ref(
+    reference: &mut Option<String>,
+    novel_id: &str,
+    novel_root_path: &Path,
+) -> usize {
+    let relativized = reference
+        .as_deref()
+        .and_then(|r| relativize_resource_ref(r, novel_id, novel_root_path));
+    match relativized {
+        Some(uri) => {
+            *reference = Some(uri);
+            1
+        }
+        None => 0,
+    }
+}
+
+/// 블록의 content와 attr에 담긴 이전 형식 리소스 참조를 리소스 URI로 바꿉니다.
+pub fn relativize_block_refs(
+    blocks: &mut [Block],
+    novel_id: &str,
+    novel_root_path: &Path,
+) -> usize {
+    blocks
+        .iter_mut()
+        .map(|block| {
+            let content_count: usize = block
+                .content
+                .iter_mut()
+                .map(|node| relativize_resource_refs(node, novel_id, novel_root_path))
+                .sum();
+            let attr_count = block.attr.as_mut().map_or(0, |attr| {
+                relativize_resource_refs(attr, novel_id, novel_root_path)
+            });
+            content_count + attr_count
+        })
+        .sum()
+}
+
+/// 참조 문자열이 이 소설의 리소스(리소스 URI 또는 이전 형식의 절대 경로)를 가리키면 `resources` 기준 상대 경로를 반환합니다.
+pub fn referenced_resource_path(
+    reference: &str,
+    novel_id: &str,
+    novel_root_path: &Path,
+) -> Option<PathBuf> {
+    match parse_resource_uri(reference) {
+        Some((uri_novel_id, relative_path)) => (uri_novel_id == novel_id).then_some(relative_path),
+        None => legacy_resource_relative_path(reference, novel_root_path),
+    }
+}
+
+/// JSON 값 안의 모든 문자열에서 이 소설의 리소스 참조를 찾아 상대 경로를 모읍니다.
+pub fn collect_resource_refs(
+    value: &Value,
+    novel_id: &str,
+    novel_root_path: &Path,
+    found: &mut HashSet<PathBuf>,
+) {
+    match value {
+        Value::String(reference) => {
+            found.extend(referenced_resource_path(
+                reference,
+                novel_id,
+                novel_root_path,
+            ));
+        }
+        Value::Array(items) => items
+            .iter()
+            .for_each(|item| collect_resource_refs(item, novel_id, novel_root_path, found)),
+        Value::Object(object) => object
+            .values()
+            .for_each(|item| collect_resource_refs(item, novel_id, novel_root_path, found)),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_resource_path_decodes_segments() {
+        assert_eq!(
+            parse_resource_path("/novel-id/attachments/abc/%EC%A7%80%EB%8F%84%20v2.png?t=1"),
+            Some((
+                "novel-id".to_string(),
+                Path::new("attachments").join("abc").join("지도 v2.png")
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_resource_path_rejects_unsafe_paths() {
+        for uri_path in [
+            "",
+            "/",
+            "/novel-id",
+            "/novel-id/",
+            "/novel-id/../secret.txt",
+            "/novel-id/images/%2E%2E/%2E%2E/secret.txt",
+            "/novel-id/%2Fetc%2Fpasswd",
+            "/novel-id/C%3A/secret.txt",
+            "/novel-id/..%5Csecret.txt",
+            "/novel-id/%FF.png",
+        ] {
+            assert_eq!(
+                parse_resource_path(uri_path),
+                None,
+                "{:?} should be rejected",
+                uri_path
+            );
+        }
+    }
+
+    #[test]
+    fn active_content_is_detected_by_mime_type() {
+        for file_name in ["page.html", "PAGE.HTM", "map.svg"] {
+            assert!(is_active_content(resource_mime_type(Path::new(file_name))));
+        }
+        for file_name in ["map.png", "notes.txt", "doc.pdf", "unknown.bin"] {
+            assert!(!is_active_content(resource_mime_type(Path::new(file_name))));
+        }
+    }
+
+    #[test]
+    fn resource_uri_round_trips() {
+        let relative_path = Path::new("attachments").join("abc").join("지도 #1.png");
+        let uri = resource_uri_for("novel-id", &relative_path).unwrap();
+        assert_eq!(
+            parse_resource_uri(&uri),
+            Some(("novel-id".to_string(), relative_path))
+        );
+    }
+}
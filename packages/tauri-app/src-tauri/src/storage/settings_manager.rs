@@ -0,0 +1,63 @@
+use crate::i18n::{FileKind, Message};
+use crate::models::error::{MuvelError, MuvelResult};
+use crate::models::settings::AppSettings;
+use crate::storage::durable_io;
+use std::fs;
+use std::io::Read;
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager};
+
+const APP_SETTINGS_FILENAME: &str = "app_settings.json";
+
+/// 앱 설정 파일의 전체 절대 경로를 생성하여 반환합니다.
+fn get_app_settings_file_path(app_handle: &AppHandle) -> MuvelResult<PathBuf> {
+    let path = app_handle
+        .path()
+        .app_local_data_dir()
+        .map_err(|e| MuvelError::not_found(Message::AppDataDirNotFound).caused_by(e))?
+        .join(APP_SETTINGS_FILENAME);
+    Ok(path)
+}
+
+/// 앱 설정을 파일에서 읽어옵니다. 파일이 없으면 기본값을 반환합니다.
+pub fn load_settings(app_handle: &AppHandle) -> MuvelResult<AppSettings> {
+    let settings_path = get_app_settings_file_path(app_handle)?;
+
+    if !settings_path.exists() {
+        return Ok(AppSettings::default());
+    }
+
+    let mut file_content = String::new();
+    fs::File::open(&settings_path)
+        .map_err(|e| {
+            MuvelError::io(Message::FileOpenFailed(FileKind::AppSettings), &e)
+                .with_path(&settings_path)
+        })?
+        .read_to_string(&mut file_content)
+        .map_err(|e| MuvelError::io(Message::FileReadFailed(FileKind::AppSettings), &e))?;
+
+    serde_json::from_str(&file_content).map_err(|e| {
+        MuvelError::parse(Message::FileParseFailed(FileKind::AppSettings)).caused_by(e)
+    })
+}
+
+/// 앱 설정을 파일에 저장합니다. (원자적 쓰기 방식)
+pub fn save_settings(app_handle: &AppHandle, settings: &AppSettings) -> MuvelResult<()> {
+    let settings_path = get_app_settings_file_path(app_handle)?;
+    let parent_dir = settings_path.parent().ok_or_else(|| {
+        MuvelError::not_found(Message::ParentDirNotFound(FileKind::AppSettings))
+            .with_path(&settings_path)
+    })?;
+
+    if !parent_dir.exists() {
+        fs::create_dir_all(parent_dir)
+            .map_err(|e| MuvelError::io(Message::DirCreateFailed(FileKind::AppSettings), &e))?;
+    }
+
+    let json_string = serde_json::to_string_pretty(settings).map_err(|e| {
+        MuvelError::internal(Message::SerializeFailed(FileKind::AppSettings)).caused_by(e)
+    })?;
+    durable_io::write_file_atomically(&settings_path, json_string.as_bytes())
+        .map_err(|e| e.context(Message::FileWriteFailed(FileKind::AppSettings)))?;
+    Ok(())
+}
@@ -1,11 +1,89 @@
-use crate::models::snapshot::EpisodeSnapshot;
+use crate::i18n::{FileKind, Message};
+use crate::models::block::Block;
+use crate::models::enums::snapshot_reason::SnapshotReason;
+use crate::models::error::{MuvelError, MuvelResult};
+use crate::models::snapshot::{EpisodeSnapshot, EpisodeSnapshotHeader, WikiPageSnapshot};
+use crate::models::wiki_page::WikiPage;
+use crate::storage::block_store::{self, BlockRef};
 use crate::storage::episode_io::EPISODES_DIRNAME;
+use crate::storage::wiki_page_io::WIKI_PAGES_DIRNAME;
+use crate::storage::{durable_io, format_migration};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::fs;
-use std::io::{Read, Write};
 use std::path::{Path, PathBuf}; // 기존 episodes 폴더명 사용
 
-const SNAPSHOTS_SUBDIRNAME: &str = "snapshots"; // episodes 폴더 하위의 스냅샷 폴더명
-const EPISODE_SNAPSHOT_FILE_EXTENSION: &str = "mvles"; // Muvel Episode Snapshot
+pub const SNAPSHOTS_SUBDIRNAME: &str = "snapshots"; // episodes 폴더 하위의 스냅샷 폴더명
+pub const EPISODE_SNAPSHOT_FILE_EXTENSION: &str = "mvles"; // Muvel Episode Snapshot
+pub const WIKI_PAGE_SNAPSHOT_FILE_EXTENSION: &str = "mvlws"; // Muvel Wiki page Snapshot
+
+/// 이전 형식(.mvles에 블록 전체 저장) 파일을 변환할 때 블록 압축 여부
+const MIGRATION_COMPRESS_BLOCKS: bool = true;
+
+/// 스냅샷 파일(.mvles)에 실제로 저장되는 형태입니다.
+/// 블록 본문은 블록 저장소(block_store)에 두고 `blockRefs`로만 참조합니다.
+/// 이전 형식의 파일은 `blocks`에 블록 전체를 담고 있으며, 읽을 때 새 형식으로 변환됩니다.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct StoredEpisodeSnapshot {
+    id: String,
+    episode_id: String,
+    reason: SnapshotReason,
+    #[serde(rename = "createdAt")]
+    created_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+    #[serde(default)]
+    pinned: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    block_refs: Option<Vec<BlockRef>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    blocks: Option<Vec<Block>>,
+}
+
+/// 위키 페이지 스냅샷 파일(.mvlws)에 저장되는 형태입니다.
+/// 페이지의 블록은 비워서 저장하고 `blockRefs`로 블록 저장소를 참조합니다.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct StoredWikiPageSnapshot {
+    id: String,
+    wiki_page_id: String,
+    reason: SnapshotReason,
+    #[serde(rename = "createdAt")]
+    created_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+    #[serde(default)]
+    pinned: bool,
+    page: WikiPage,
+    block_refs: Vec<BlockRef>,
+}
+
+/// 스냅샷 파일에서 블록 참조 필드만 꺼내기 위한 구조체 (파일은 전체를 읽음)
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct PartialSnapshotBlockRefs {
+    #[serde(default)]
+    block_refs: Vec<BlockRef>,
+}
+
+/// 위키 페이지 스냅샷 파일에서 페이지 썸네일만 읽기 위한 구조체
+#[derive(Deserialize, Debug)]
+struct PartialWikiPageSnapshotThumbnail {
+    #[serde(default)]
+    page: PartialWikiPageThumbnail,
+}
+
+#[derive(Deserialize, Debug, Default)]
+struct PartialWikiPageThumbnail {
+    #[serde(default)]
+    thumbnail: Option<String>,
+}
 
 /// 특정 에피소드의 스냅샷들이 저장될 디렉토리 경로를 반환합니다.
 /// 예: {novel_root_path}/episodes/snapshots/{episode_id}/
@@ -29,79 +107,165 @@ fn get_snapshot_file_path(novel_root_path: &Path, episode_id: &str, snapshot_id:
 pub fn ensure_episode_snapshots_directory_exists(
     novel_root_path: &Path,
     episode_id: &str,
-) -> Result<PathBuf, String> {
+) -> MuvelResult<PathBuf> {
     let dir_path = get_episode_snapshots_dir_path(novel_root_path, episode_id);
     if !dir_path.exists() {
         fs::create_dir_all(&dir_path).map_err(|e| {
-            format!(
-                "에피소드 스냅샷 디렉토리 생성 실패 (경로: {:?}): {}",
-                dir_path, e
-            )
+            MuvelError::io(Message::DirCreateFailed(FileKind::EpisodeSnapshot), &e)
+                .with_path(&dir_path)
         })?;
     }
     Ok(dir_path)
 }
 
+/// 스냅샷 저장 형태를 파일에 원자적으로 기록합니다.
+fn write_stored_snapshot(
+    snapshot_file_path: &Path,
+    stored: &StoredEpisodeSnapshot,
+) -> MuvelResult<()> {
+    let json_string = format_migration::to_versioned_json(stored, FileKind::EpisodeSnapshot)?;
+    durable_io::write_file_atomically(snapshot_file_path, json_string.as_bytes())
+        .map_err(|e| e.context(Message::FileWriteFailed(FileKind::EpisodeSnapshot)))?;
+    Ok(())
+}
+
 /// EpisodeSnapshot 데이터를 파일에 저장합니다.
+/// 블록은 블록 저장소에 저장되고, 스냅샷 파일에는 블록 참조만 기록됩니다.
 pub fn write_snapshot_file(
     novel_root_path: &Path,
     episode_id: &str, // snapshot.episode_id와 동일해야 함
     snapshot: &EpisodeSnapshot,
-) -> Result<(), String> {
+    compress_blocks: bool,
+) -> MuvelResult<()> {
     ensure_episode_snapshots_directory_exists(novel_root_path, episode_id)?;
     let snapshot_file_path = get_snapshot_file_path(novel_root_path, episode_id, &snapshot.id);
 
-    let temp_file_path =
-        snapshot_file_path.with_extension(&format!("{}.tmp", EPISODE_SNAPSHOT_FILE_EXTENSION));
-
-    let mut temp_file = fs::File::create(&temp_file_path).map_err(|e| {
-        format!(
-            "임시 스냅샷 파일 생성 실패 (경로: {:?}): {}",
-            temp_file_path, e
-        )
-    })?;
-    let json_string = serde_json::to_string_pretty(snapshot)
-        .map_err(|e| format!("스냅샷 데이터 JSON 직렬화 실패: {}", e))?;
-    temp_file
-        .write_all(json_string.as_bytes())
-        .map_err(|e| format!("임시 스냅샷 파일 쓰기 실패: {}", e))?;
-    fs::rename(&temp_file_path, &snapshot_file_path)
-        .map_err(|e| format!("스냅샷 파일 원자적 교체 실패: {}", e))?;
-    Ok(())
+    let block_refs = block_store::put_blocks(novel_root_path, &snapshot.blocks, compress_blocks)?;
+    let stored = StoredEpisodeSnapshot {
+        id: snapshot.id.clone(),
+        episode_id: snapshot.episode_id.clone(),
+        reason: snapshot.reason.clone(),
+        created_at: snapshot.created_at.clone(),
+        label: snapshot.label.clone(),
+        note: snapshot.note.clone(),
+        pinned: snapshot.pinned,
+        block_refs: Some(block_refs),
+        blocks: None,
+    };
+    write_stored_snapshot(&snapshot_file_path, &stored)
 }
 
 /// 스냅샷 파일 내용을 읽어 EpisodeSnapshot 객체로 반환합니다.
-fn read_snapshot_file_content(snapshot_file_path: &Path) -> Result<EpisodeSnapshot, String> {
+/// 이전 형식(블록 전체 포함)의 파일이면 블록 저장소를 사용하는 형식으로 변환하여 다시 저장합니다.
+fn read_snapshot_file_content(
+    novel_root_path: &Path,
+    snapshot_file_path: &Path,
+) -> MuvelResult<EpisodeSnapshot> {
     if !snapshot_file_path.exists() {
-        return Err(format!(
-            "스냅샷 파일을 찾을 수 없습니다: {:?}",
-            snapshot_file_path
-        ));
+        return Err(
+            MuvelError::not_found(Message::FileNotFound(FileKind::EpisodeSnapshot))
+                .with_path(snapshot_file_path),
+        );
     }
-    let mut file_content = String::new();
-    fs::File::open(snapshot_file_path)
-        .map_err(|e| {
-            format!(
-                "스냅샷 파일 열기 실패 (경로: {:?}): {}",
-                snapshot_file_path, e
-            )
-        })?
-        .read_to_string(&mut file_content)
-        .map_err(|e| format!("스냅샷 파일 읽기 실패: {}", e))?;
-    serde_json::from_str(&file_content).map_err(|e| {
-        format!(
-            "스냅샷 파일 JSON 파싱 실패 (경로: {:?}): {}",
-            snapshot_file_path, e
-        )
+    let mut stored: StoredEpisodeSnapshot =
+        format_migration::read_versioned_file(snapshot_file_path, FileKind::EpisodeSnapshot)?;
+
+    let blocks = match (&stored.block_refs, stored.blocks.take()) {
+        (Some(block_refs), _) => block_store::get_blocks(novel_root_path, block_refs)?,
+        (None, legacy_blocks) => {
+            let legacy_blocks = legacy_blocks.unwrap_or_default();
+            // 이전 형식 → 블록 저장소 형식으로 변환 (실패해도 읽기는 성공으로 처리)
+            match block_store::put_blocks(
+                novel_root_path,
+                &legacy_blocks,
+                MIGRATION_COMPRESS_BLOCKS,
+            ) {
+                Ok(block_refs) => {
+                    stored.block_refs = Some(block_refs);
+                    if let Err(e) = write_stored_snapshot(snapshot_file_path, &stored) {
+                        eprintln!(
+                            "스냅샷 파일 변환 저장 실패 (경로: {:?}): {}",
+                            snapshot_file_path, e
+                        );
+                    }
+                }
+                Err(e) => eprintln!(
+                    "스냅샷 블록 저장소 변환 실패 (경로: {:?}): {}",
+                    snapshot_file_path, e
+                ),
+            }
+            legacy_blocks
+        }
+    };
+
+    Ok(EpisodeSnapshot {
+        id: stored.id,
+        episode_id: stored.episode_id,
+        reason: stored.reason,
+        blocks,
+        created_at: stored.created_at,
+        label: stored.label,
+        note: stored.note,
+        pinned: stored.pinned,
     })
 }
 
+/// 특정 스냅샷 파일(.mvles)을 읽어 EpisodeSnapshot 객체로 반환합니다.
+pub fn read_snapshot_file(
+    novel_root_path: &Path,
+    episode_id: &str,
+    snapshot_id: &str,
+) -> MuvelResult<EpisodeSnapshot> {
+    let snapshot_file_path = get_snapshot_file_path(novel_root_path, episode_id, snapshot_id);
+    read_snapshot_file_content(novel_root_path, &snapshot_file_path)
+}
+
+/// 특정 스냅샷 파일(.mvles)을 삭제합니다.
+pub fn delete_snapshot_file(
+    novel_root_path: &Path,
+    episode_id: &str,
+    snapshot_id: &str,
+) -> MuvelResult<()> {
+    let snapshot_file_path = get_snapshot_file_path(novel_root_path, episode_id, snapshot_id);
+
+    if snapshot_file_path.exists() && snapshot_file_path.is_file() {
+        fs::remove_file(&snapshot_file_path).map_err(|e| {
+            MuvelError::io(Message::FileDeleteFailed(FileKind::EpisodeSnapshot), &e)
+                .with_path(&snapshot_file_path)
+        })?;
+    } else {
+        // 파일이 없어도 오류는 아님 (이미 삭제되었을 수 있음)
+        eprintln!(
+            "삭제할 스냅샷 파일이 존재하지 않거나 파일이 아닙니다: {:?}",
+            snapshot_file_path
+        );
+    }
+    Ok(())
+}
+
+/// 스냅샷 폴더를 통째로 삭제합니다. (에피소드/위키 페이지를 삭제할 때 사용)
+fn delete_snapshots_dir(dir_path: &Path, kind: FileKind) -> MuvelResult<()> {
+    if !dir_path.is_dir() {
+        return Ok(());
+    }
+    fs::remove_dir_all(dir_path)
+        .map_err(|e| MuvelError::io(Message::DirDeleteFailed(kind), &e).with_path(dir_path))
+}
+
+/// 특정 에피소드의 스냅샷 폴더(`episodes/snapshots/{episode_id}`)를 삭제합니다.
+pub fn delete_episode_snapshots_dir(novel_root_path: &Path, episode_id: &str) -> MuvelResult<()> {
+    delete_snapshots_dir(
+        &get_episode_snapshots_dir_path(novel_root_path, episode_id),
+        FileKind::EpisodeSnapshot,
+    )
+}
+
 /// 특정 에피소드에 속한 모든 스냅샷 파일들을 읽어 Vec<EpisodeSnapshot>으로 반환합니다.
 /// 생성 시각(createdAt) 기준으로 정렬하여 반환할 수 있습니다 (여기서는 파일시스템 순서).
 pub fn list_snapshots_for_episode(
     novel_root_path: &Path,
     episode_id: &str,
-) -> Result<Vec<EpisodeSnapshot>, String> {
+) -> MuvelResult<Vec<EpisodeSnapshot>> {
     let snapshots_dir = get_episode_snapshots_dir_path(novel_root_path, episode_id);
     let mut snapshots = Vec::new();
 
@@ -110,12 +274,10 @@ pub fn list_snapshots_for_episode(
     }
 
     for entry in fs::read_dir(&snapshots_dir).map_err(|e| {
-        format!(
-            "스냅샷 디렉토리 읽기 실패 (경로: {:?}): {}",
-            snapshots_dir, e
-        )
+        MuvelError::io(Message::DirReadFailed(FileKind::EpisodeSnapshot), &e)
+            .with_path(&snapshots_dir)
     })? {
-        let entry = entry.map_err(|e| format!("디렉토리 항목 읽기 실패: {}", e))?;
+        let entry = entry.map_err(|e| MuvelError::io(Message::DirEntryReadFailed, &e))?;
         let path = entry.path();
 
         if path.is_file()
@@ -123,7 +285,7 @@ pub fn list_snapshots_for_episode(
                 .extension()
                 .map_or(false, |ext| ext == EPISODE_SNAPSHOT_FILE_EXTENSION)
         {
-            match read_snapshot_file_content(&path) {
+            match read_snapshot_file_content(novel_root_path, &path) {
                 Ok(snapshot) => snapshots.push(snapshot),
                 Err(e) => {
                     // 개별 파일 읽기/파싱 실패 시 로그만 남기고 계속 진행할 수 있음
@@ -136,3 +298,383 @@ pub fn list_snapshots_for_episode(
     snapshots.sort_by(|a, b| a.created_at.cmp(&b.created_at));
     Ok(snapshots)
 }
+
+/// 스냅샷 파일의 헤더를 읽습니다. 파일을 고쳐 쓰지 않으며 형식 버전을 확인합니다.
+/// 이 앱보다 새로운 형식의 파일은 오류를 반환하므로, 헤더 목록에서 빠져 정리(pruning) 대상이 되지 않습니다.
+fn read_snapshot_header(path: &Path, kind: FileKind) -> MuvelResult<EpisodeSnapshotHeader> {
+    let file_content = fs::read_to_string(path)
+        .map_err(|e| MuvelError::io(Message::FileReadFailed(kind), &e).with_path(path))?;
+    format_migration::from_versioned_str(&file_content, kind).map_err(|e| e.with_path(path))
+}
+
+/// 특정 에피소드에 속한 모든 스냅샷 파일에서 헤더 정보(id, reason, createdAt)만 읽어 반환합니다.
+/// 파일은 전체를 읽지만, 블록은 블록 저장소에 있으므로 스냅샷 파일 자체는 작습니다.
+/// (블록 전체를 담은 이전 형식 파일은 읽을 때 변환되기 전까지 큼)
+pub fn list_snapshot_headers_for_episode(
+    novel_root_path: &Path,
+    episode_id: &str,
+) -> MuvelResult<Vec<EpisodeSnapshotHeader>> {
+    let snapshots_dir = get_episode_snapshots_dir_path(novel_root_path, episode_id);
+    let mut headers = Vec::new();
+
+    if !snapshots_dir.exists() || !snapshots_dir.is_dir() {
+        return Ok(headers);
+    }
+
+    for entry in fs::read_dir(&snapshots_dir).map_err(|e| {
+        MuvelError::io(Message::DirReadFailed(FileKind::EpisodeSnapshot), &e)
+            .with_path(&snapshots_dir)
+    })? {
+        let entry = entry.map_err(|e| MuvelError::io(Message::DirEntryReadFailed, &e))?;
+        let path = entry.path();
+
+        if path.is_file()
+            && path
+                .extension()
+                .is_some_and(|ext| ext == EPISODE_SNAPSHOT_FILE_EXTENSION)
+        {
+            match read_snapshot_header(&path, FileKind::EpisodeSnapshot) {
+                Ok(header) => headers.push(header),
+                Err(e) => eprintln!("스냅샷 헤더 읽기 실패 (파일: {:?}): {}", path, e),
+            }
+        }
+    }
+    Ok(headers)
+}
+
+/// `{snapshots_root}/{item_id}/*.{extension}` 스냅샷 파일 경로 목록을 반환합니다.
+fn list_snapshot_file_paths_under(
+    snapshots_root: &Path,
+    extension: &str,
+) -> MuvelResult<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    if !snapshots_root.is_dir() {
+        return Ok(paths);
+    }
+
+    for item_dir_entry in fs::read_dir(snapshots_root).map_err(|e| {
+        MuvelError::io(Message::DirReadFailed(FileKind::EpisodeSnapshot), &e)
+            .with_path(snapshots_root)
+    })? {
+        let item_dir = item_dir_entry
+            .map_err(|e| MuvelError::io(Message::DirEntryReadFailed, &e))?
+            .path();
+        if !item_dir.is_dir() {
+            continue;
+        }
+
+        for entry in fs::read_dir(&item_dir).map_err(|e| {
+            MuvelError::io(Message::DirReadFailed(FileKind::EpisodeSnapshot), &e)
+                .with_path(&item_dir)
+        })? {
+            let path = entry
+                .map_err(|e| MuvelError::io(Message::DirEntryReadFailed, &e))?
+                .path();
+            if path.extension().is_some_and(|ext| ext == extension) {
+                paths.push(path);
+            }
+        }
+    }
+    Ok(paths)
+}
+
+/// 스냅샷 파일을 읽어 필요한 필드만 역직렬화합니다.
+/// 참조를 읽지 못한 파일이 있으면 블록이나 리소스가 잘못 삭제될 수 있으므로 오류로 처리합니다.
+fn read_snapshot_refs<T: DeserializeOwned>(path: &Path, kind: FileKind) -> MuvelResult<T> {
+    let file_content = fs::read_to_string(path)
+        .map_err(|e| MuvelError::io(Message::FileReadFailed(kind), &e).with_path(path))?;
+    serde_json::from_str(&file_content).map_err(|e| {
+        MuvelError::parse(Message::FileParseFailed(kind))
+            .caused_by(e)
+            .with_path(path)
+    })
+}
+
+/// `{snapshots_root}/{item_id}/*.{extension}` 스냅샷 파일들이 참조하는 블록 해시를 모읍니다.
+fn collect_block_hashes_under(
+    snapshots_root: &Path,
+    extension: &str,
+    kind: FileKind,
+    hashes: &mut HashSet<String>,
+) -> MuvelResult<()> {
+    for path in list_snapshot_file_paths_under(snapshots_root, extension)? {
+        let partial: PartialSnapshotBlockRefs = read_snapshot_refs(&path, kind)?;
+        hashes.extend(partial.block_refs.into_iter().map(|r| r.hash));
+    }
+    Ok(())
+}
+
+/// 소설의 모든 에피소드/위키 페이지 스냅샷 파일이 참조하는 블록 해시 목록을 반환합니다.
+pub fn collect_referenced_block_hashes(novel_root_path: &Path) -> MuvelResult<HashSet<String>> {
+    let mut hashes = HashSet::new();
+    collect_block_hashes_under(
+        &novel_root_path
+            .join(EPISODES_DIRNAME)
+            .join(SNAPSHOTS_SUBDIRNAME),
+        EPISODE_SNAPSHOT_FILE_EXTENSION,
+        FileKind::EpisodeSnapshot,
+        &mut hashes,
+    )?;
+    collect_block_hashes_under(
+        &novel_root_path
+            .join(WIKI_PAGES_DIRNAME)
+            .join(SNAPSHOTS_SUBDIRNAME),
+        WIKI_PAGE_SNAPSHOT_FILE_EXTENSION,
+        FileKind::WikiPageSnapshot,
+        &mut hashes,
+    )?;
+    Ok(hashes)
+}
+
+/// 소설의 모든 위키 페이지 스냅샷에 저장된 페이지 썸네일 참조 목록을 반환합니다.
+pub fn collect_referenced_wiki_page_thumbnails(novel_root_path: &Path) -> MuvelResult<Vec<String>> {
+    let snapshots_root = novel_root_path
+        .join(WIKI_PAGES_DIRNAME)
+        .join(SNAPSHOTS_SUBDIRNAME);
+    let mut thumbnails = Vec::new();
+    for path in list_snapshot_file_paths_under(&snapshots_root, WIKI_PAGE_SNAPSHOT_FILE_EXTENSION)?
+    {
+        let partial: PartialWikiPageSnapshotThumbnail =
+            read_snapshot_refs(&path, FileKind::WikiPageSnapshot)?;
+        thumbnails.extend(partial.page.thumbnail);
+    }
+    Ok(thumbnails)
+}
+
+/// 특정 위키 페이지의 스냅샷 디렉토리 경로를 구성합니다.
+/// 예: {novel_root_path}/wiki/snapshots/{wiki_page_id}/
+fn get_wiki_page_snapshots_dir_path(novel_root_path: &Path, wiki_page_id: &str) -> PathBuf {
+    novel_root_path
+        .join(WIKI_PAGES_DIRNAME)
+        .join(SNAPSHOTS_SUBDIRNAME)
+        .join(wiki_page_id)
+}
+
+fn get_wiki_page_snapshot_file_path(
+    novel_root_path: &Path,
+    wiki_page_id: &str,
+    snapshot_id: &str,
+) -> PathBuf {
+    get_wiki_page_snapshots_dir_path(novel_root_path, wiki_page_id).join(format!(
+        "{}.{}",
+        snapshot_id, WIKI_PAGE_SNAPSHOT_FILE_EXTENSION
+    ))
+}
+
+/// 위키 페이지 스냅샷을 파일에 저장합니다. 블록은 블록 저장소에 저장됩니다.
+pub fn write_wiki_page_snapshot_file(
+    novel_root_path: &Path,
+    snapshot: &WikiPageSnapshot,
+    compress_blocks: bool,
+) -> MuvelResult<()> {
+    let dir_path = get_wiki_page_snapshots_dir_path(novel_root_path, &snapshot.wiki_page_id);
+    fs::create_dir_all(&dir_path).map_err(|e| {
+        MuvelError::io(Message::DirCreateFailed(FileKind::WikiPageSnapshot), &e)
+            .with_path(&dir_path)
+    })?;
+
+    let block_refs =
+        block_store::put_blocks(novel_root_path, &snapshot.page.blocks, compress_blocks)?;
+    let mut page = snapshot.page.clone();
+    page.blocks = Vec::new();
+    let stored = StoredWikiPageSnapshot {
+        id: snapshot.id.clone(),
+        wiki_page_id: snapshot.wiki_page_id.clone(),
+        reason: snapshot.reason.clone(),
+        created_at: snapshot.created_at.clone(),
+        label: snapshot.label.clone(),
+        note: snapshot.note.clone(),
+        pinned: snapshot.pinned,
+        page,
+        block_refs,
+    };
+
+    let snapshot_file_path =
+        get_wiki_page_snapshot_file_path(novel_root_path, &snapshot.wiki_page_id, &snapshot.id);
+    let json_string = format_migration::to_versioned_json(&stored, FileKind::WikiPageSnapshot)?;
+    durable_io::write_file_atomically(&snapshot_file_path, json_string.as_bytes())
+        .map_err(|e| e.context(Message::FileWriteFailed(FileKind::WikiPageSnapshot)))?;
+    Ok(())
+}
+
+fn read_wiki_page_snapshot_file_content(
+    novel_root_path: &Path,
+    snapshot_file_path: &Path,
+) -> MuvelResult<WikiPageSnapshot> {
+    if !snapshot_file_path.exists() {
+        return Err(
+            MuvelError::not_found(Message::FileNotFound(FileKind::WikiPageSnapshot))
+                .with_path(snapshot_file_path),
+        );
+    }
+    let stored: StoredWikiPageSnapshot =
+        format_migration::read_versioned_file(snapshot_file_path, FileKind::WikiPageSnapshot)?;
+
+    let mut page = stored.page;
+    page.blocks = block_store::get_blocks(novel_root_path, &stored.block_refs)?;
+    Ok(WikiPageSnapshot {
+        id: stored.id,
+        wiki_page_id: stored.wiki_page_id,
+        reason: stored.reason,
+        created_at: stored.created_at,
+        label: stored.label,
+        note: stored.note,
+        pinned: stored.pinned,
+        page,
+    })
+}
+
+/// 특정 위키 페이지 스냅샷 파일(.mvlws)을 읽어 WikiPageSnapshot 객체로 반환합니다.
+pub fn read_wiki_page_snapshot_file(
+    novel_root_path: &Path,
+    wiki_page_id: &str,
+    snapshot_id: &str,
+) -> MuvelResult<WikiPageSnapshot> {
+    let snapshot_file_path =
+        get_wiki_page_snapshot_file_path(novel_root_path, wiki_page_id, snapshot_id);
+    read_wiki_page_snapshot_file_content(novel_root_path, &snapshot_file_path)
+}
+
+/// 특정 위키 페이지 스냅샷 파일을 삭제합니다.
+pub fn delete_wiki_page_snapshot_file(
+    novel_root_path: &Path,
+    wiki_page_id: &str,
+    snapshot_id: &str,
+) -> MuvelResult<()> {
+    let snapshot_file_path =
+        get_wiki_page_snapshot_file_path(novel_root_path, wiki_page_id, snapshot_id);
+    if snapshot_file_path.is_file() {
+        fs::remove_file(&snapshot_file_path).map_err(|e| {
+            MuvelError::io(Message::FileDeleteFailed(FileKind::WikiPageSnapshot), &e)
+                .with_path(&snapshot_file_path)
+        })?;
+    } else {
+        eprintln!(
+            "삭제할 위키 페이지 스냅샷 파일이 존재하지 않습니다: {:?}",
+            snapshot_file_path
+        );
+    }
+    Ok(())
+}
+
+/// 특정 위키 페이지의 스냅샷 폴더(`wiki/snapshots/{page_id}`)를 삭제합니다.
+pub fn delete_wiki_page_snapshots_dir(
+    novel_root_path: &Path,
+    wiki_page_id: &str,
+) -> MuvelResult<()> {
+    delete_snapshots_dir(
+        &get_wiki_page_snapshots_dir_path(novel_root_path, wiki_page_id),
+        FileKind::WikiPageSnapshot,
+    )
+}
+
+/// 특정 위키 페이지의 스냅샷 파일 경로 목록을 반환합니다.
+fn list_wiki_page_snapshot_file_paths(
+    novel_root_path: &Path,
+    wiki_page_id: &str,
+) -> MuvelResult<Vec<PathBuf>> {
+    let snapshots_dir = get_wiki_page_snapshots_dir_path(novel_root_path, wiki_page_id);
+    let mut paths = Vec::new();
+    if !snapshots_dir.is_dir() {
+        return Ok(paths);
+    }
+    for entry in fs::read_dir(&snapshots_dir).map_err(|e| {
+        MuvelError::io(Message::DirReadFailed(FileKind::WikiPageSnapshot), &e)
+            .with_path(&snapshots_dir)
+    })? {
+        let path = entry
+            .map_err(|e| MuvelError::io(Message::DirEntryReadFailed, &e))?
+            .path();
+        if path.is_file()
+            && path
+                .extension()
+                .is_some_and(|ext| ext == WIKI_PAGE_SNAPSHOT_FILE_EXTENSION)
+        {
+            paths.push(path);
+        }
+    }
+    Ok(paths)
+}
+
+/// 특정 위키 페이지의 모든 스냅샷을 생성 시각 순으로 반환합니다.
+pub fn list_snapshots_for_wiki_page(
+    novel_root_path: &Path,
+    wiki_page_id: &str,
+) -> MuvelResult<Vec<WikiPageSnapshot>> {
+    let mut snapshots = Vec::new();
+    for path in list_wiki_page_snapshot_file_paths(novel_root_path, wiki_page_id)? {
+        match read_wiki_page_snapshot_file_content(novel_root_path, &path) {
+            Ok(snapshot) => snapshots.push(snapshot),
+            Err(e) => eprintln!(
+                "위키 페이지 스냅샷 파일 처리 중 오류 (파일: {:?}): {}",
+                path, e
+            ),
+        }
+    }
+    snapshots.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    Ok(snapshots)
+}
+
+/// 특정 위키 페이지의 모든 스냅샷 헤더(id, reason, createdAt, pinned)만 읽어 반환합니다.
+pub fn list_snapshot_headers_for_wiki_page(
+    novel_root_path: &Path,
+    wiki_page_id: &str,
+) -> MuvelResult<Vec<EpisodeSnapshotHeader>> {
+    let mut headers = Vec::new();
+    for path in list_wiki_page_snapshot_file_paths(novel_root_path, wiki_page_id)? {
+        match read_snapshot_header(&path, FileKind::WikiPageSnapshot) {
+            Ok(header) => headers.push(header),
+            Err(e) => eprintln!(
+                "위키 페이지 스냅샷 헤더 읽기 실패 (파일: {:?}): {}",
+                path, e
+            ),
+        }
+    }
+    Ok(headers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// 테스트마다 따로 쓰는 임시 소설 폴더를 만듭니다.
+    fn temp_novel_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!(
+            "muvel-snapshot-test-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        root
+    }
+
+    #[test]
+    fn headers_skip_snapshots_from_newer_format_versions() {
+        let root = temp_novel_root("headers");
+        let dir = ensure_episode_snapshots_directory_exists(&root, "ep").unwrap();
+        let supported = format_migration::current_format_version(FileKind::EpisodeSnapshot);
+        for (id, version) in [("current", supported), ("newer", supported + 1)] {
+            let content = json!({
+                "formatVersion": version,
+                "id": id,
+                "episodeId": "ep",
+                "reason": "autosave",
+                "createdAt": "2024-01-01T00:00:00Z",
+            });
+            fs::write(
+                dir.join(format!("{}.{}", id, EPISODE_SNAPSHOT_FILE_EXTENSION)),
+                content.to_string(),
+            )
+            .unwrap();
+        }
+
+        let headers = list_snapshot_headers_for_episode(&root, "ep").unwrap();
+        assert_eq!(
+            headers.iter().map(|h| h.id.as_str()).collect::<Vec<_>>(),
+            ["current"]
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
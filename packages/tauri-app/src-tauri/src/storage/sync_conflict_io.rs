@@ -0,0 +1,223 @@
+use crate::i18n::{FileKind, Message};
+use crate::models::commons::{SyncConflictCopy, SyncConflictKind};
+use crate::models::error::{MuvelError, MuvelResult};
+use crate::storage::episode_io::{EPISODES_DIRNAME, EPISODE_FILE_EXTENSION};
+use crate::storage::novel_io::NOVEL_METADATA_EXTENSION;
+use crate::storage::wiki_page_io::{WIKI_PAGES_DIRNAME, WIKI_PAGE_FILE_EXTENSION};
+use crate::storage::{format_migration, novel_watcher};
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+// Syncthing: `abc.sync-conflict-20240101-120000-ABCDEFG.mvle`
+const SYNCTHING_CONFLICT_MARKER: &str = ".sync-conflict-";
+// Dropbox/Nextcloud 등: `abc (conflicted copy).mvle`, `abc (홍길동의 충돌된 사본 2024-01-01).mvle`
+const CONFLICT_COPY_KEYWORDS: [&str; 3] = ["conflict", "충돌", "競合"];
+
+/// 충돌 사본 파일 이름(확장자 제외)에서 원본 파일 이름을 구합니다. 충돌 사본이 아니면 None을 반환합니다.
+pub fn original_stem_of(stem: &str) -> Option<&str> {
+    let original = if let Some(index) = stem.find(SYNCTHING_CONFLICT_MARKER) {
+        &stem[..index]
+    } else {
+        let inner_start = stem.strip_suffix(')')?.rfind(" (")?;
+        let inner = stem[inner_start + 2..stem.len() - 1].to_lowercase();
+        if !CONFLICT_COPY_KEYWORDS
+            .iter()
+            .any(|keyword| inner.contains(keyword))
+        {
+            return None;
+        }
+        &stem[..inner_start]
+    };
+    (!original.is_empty()).then_some(original)
+}
+
+/// 파일이 동기화 서비스가 만든 충돌 사본인지 확인합니다.
+pub fn is_conflict_copy(path: &Path) -> bool {
+    path.file_stem()
+        .and_then(|stem| stem.to_str())
+        .and_then(original_stem_of)
+        .is_some()
+}
+
+/// 충돌 사본이 생길 수 있는 폴더와 확장자, 종류 (소설 루트 기준)
+fn conflict_locations(novel_root_path: &Path) -> [(PathBuf, &'static str, SyncConflictKind); 3] {
+    [
+        (
+            novel_root_path.to_path_buf(),
+            NOVEL_METADATA_EXTENSION,
+            SyncConflictKind::Novel,
+        ),
+        (
+            novel_root_path.join(EPISODES_DIRNAME),
+            EPISODE_FILE_EXTENSION,
+            SyncConflictKind::Episode,
+        ),
+        (
+            novel_root_path.join(WIKI_PAGES_DIRNAME),
+            WIKI_PAGE_FILE_EXTENSION,
+            SyncConflictKind::WikiPage,
+        ),
+    ]
+}
+
+fn modified_at(path: &Path) -> Option<String> {
+    fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .map(|time: SystemTime| DateTime::<Utc>::from(time).to_rfc3339())
+}
+
+/// 폴더에서 충돌 사본이 아닌 .muvl 파일을 찾습니다.
+fn find_original_metadata_file(novel_root_path: &Path) -> Option<PathBuf> {
+    fs::read_dir(novel_root_path)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .find(|path| {
+            path.is_file()
+                && path
+                    .extension()
+                    .is_some_and(|ext| ext == NOVEL_METADATA_EXTENSION)
+                && !is_conflict_copy(path)
+        })
+}
+
+/// 충돌 사본 파일 하나의 정보를 만듭니다.
+fn describe_conflict_copy(
+    conflict_path: &Path,
+    extension: &str,
+    kind: SyncConflictKind,
+) -> Option<SyncConflictCopy> {
+    let stem = conflict_path.file_stem()?.to_str()?;
+    let original_stem = original_stem_of(stem)?;
+    let mut original_path =
+        conflict_path.with_file_name(format!("{}.{}", original_stem, extension));
+    // .muvl 파일 이름은 폴더 이름과 다를 수 있으므로, 같은 이름이 없으면 폴더의 다른 .muvl을 원본으로 봄
+    if kind == SyncConflictKind::Novel && !original_path.is_file() {
+        if let Some(metadata_path) = conflict_path.parent().and_then(find_original_metadata_file) {
+            original_path = metadata_path;
+        }
+    }
+    let item_id = (kind != SyncConflictKind::Novel).then(|| original_stem.to_string());
+
+    Some(SyncConflictCopy {
+        kind,
+        item_id,
+        conflict_path: conflict_path.to_string_lossy().into_owned(),
+        original_exists: original_path.is_file(),
+        conflict_modified_at: modified_at(conflict_path),
+        original_modified_at: modified_at(&original_path),
+        original_path: original_path.to_string_lossy().into_owned(),
+    })
+}
+
+/// 소설 폴더의 모든 충돌 사본(.muvl, 에피소드, 위키 페이지)을 찾습니다.
+pub fn find_conflict_copies(novel_root_path: &Path) -> MuvelResult<Vec<SyncConflictCopy>> {
+    let mut found = Vec::new();
+    for (dir_path, extension, kind) in conflict_locations(novel_root_path) {
+        if !dir_path.is_dir() {
+            continue;
+        }
+        for entry in fs::read_dir(&dir_path).map_err(|e| {
+            MuvelError::io(Message::DirReadFailed(FileKind::ConflictCopy), &e).with_path(&dir_path)
+        })? {
+            let entry = entry.map_err(|e| MuvelError::io(Message::DirEntryReadFailed, &e))?;
+            let path = entry.path();
+            if !path.is_file() || path.extension().map_or(true, |ext| ext != extension) {
+                continue;
+            }
+            if let Some(conflict) = describe_conflict_copy(&path, extension, kind) {
+                found.push(conflict);
+            }
+        }
+    }
+    found.sort_by(|a, b| a.conflict_path.cmp(&b.conflict_path));
+    Ok(found)
+}
+
+/// 요청된 경로가 소설 폴더 안의 충돌 사본인지 확인하고 정보를 반환합니다.
+pub fn inspect_conflict_copy(
+    novel_root_path: &Path,
+    conflict_path: &Path,
+) -> MuvelResult<SyncConflictCopy> {
+    if !conflict_path.is_file() {
+        return Err(
+            MuvelError::not_found(Message::FileNotFound(FileKind::ConflictCopy))
+                .with_path(conflict_path),
+        );
+    }
+    // `..` 등으로 폴더 밖을 가리키지 않도록 실제 경로로 비교
+    let canonical_path = fs::canonicalize(conflict_path).map_err(|e| {
+        MuvelError::io(Message::FileOpenFailed(FileKind::ConflictCopy), &e).with_path(conflict_path)
+    })?;
+    let parent = canonical_path.parent();
+
+    conflict_locations(novel_root_path)
+        .into_iter()
+        .find(|(dir_path, extension, _)| {
+            fs::canonicalize(dir_path).is_ok_and(|dir_path| parent == Some(dir_path.as_path()))
+                && canonical_path
+                    .extension()
+                    .is_some_and(|ext| ext == *extension)
+        })
+        .and_then(|(_, extension, kind)| describe_conflict_copy(&canonical_path, extension, kind))
+        .ok_or_else(|| {
+            MuvelError::invalid_input(Message::NotAConflictCopy).with_path(conflict_path)
+        })
+}
+
+/// 충돌 사본 파일을 읽어 현재 형식으로 마이그레이션한 뒤 역직렬화합니다. (사본 파일은 다시 쓰지 않음)
+pub fn read_conflict_copy<T: DeserializeOwned>(
+    conflict_path: &Path,
+    kind: FileKind,
+) -> MuvelResult<T> {
+    let content = fs::read_to_string(conflict_path).map_err(|e| {
+        MuvelError::io(Message::FileReadFailed(FileKind::ConflictCopy), &e).with_path(conflict_path)
+    })?;
+    format_migration::from_versioned_str(&content, kind).map_err(|e| e.with_path(conflict_path))
+}
+
+/// 병합했거나 버리기로 한 충돌 사본 파일을 삭제합니다.
+pub fn delete_conflict_copy(conflict_path: &Path) -> MuvelResult<()> {
+    novel_watcher::mark_internal_change(conflict_path);
+    fs::remove_file(conflict_path).map_err(|e| {
+        MuvelError::io(Message::FileDeleteFailed(FileKind::ConflictCopy), &e)
+            .with_path(conflict_path)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn original_stem_of_recognizes_conflict_copies() {
+        for (stem, original) in [
+            ("abc.sync-conflict-20240101-123456-ABCDEFG", "abc"),
+            ("abc (conflicted copy 2024-01-01)", "abc"),
+            ("abc (Kim's conflicted copy)", "abc"),
+            ("abc (충돌 사본)", "abc"),
+            ("abc (競合コピー)", "abc"),
+            ("my novel (2) (conflict)", "my novel (2)"),
+        ] {
+            assert_eq!(original_stem_of(stem), Some(original), "{:?}", stem);
+        }
+    }
+
+    #[test]
+    fn original_stem_of_ignores_other_names() {
+        for stem in [
+            "abc",
+            "abc (1)",
+            "abc (copy)",
+            "abc (conflict",
+            "(conflicted copy)",
+            ".sync-conflict-20240101-123456-ABCDEFG",
+        ] {
+            assert_eq!(original_stem_of(stem), None, "{:?}", stem);
+        }
+    }
+}
@@ -0,0 +1,384 @@
+use crate::i18n::{FileKind, Message};
+use crate::models::commons::{LeftoverTempFiles, PendingRecovery, RecoverableTempFile};
+use crate::models::error::{MuvelError, MuvelResult};
+use crate::storage::attachment_io::{
+    ATTACHMENTS_SUBDIR_IN_RESOURCES, ATTACHMENT_METADATA_FILENAME,
+};
+use crate::storage::block_store::BLOCK_STORE_DIRNAME;
+use crate::storage::edit_history_io::EDIT_HISTORY_FILE_EXTENSION;
+use crate::storage::episode_io::{EPISODES_DIRNAME, EPISODE_FILE_EXTENSION};
+use crate::storage::episode_manifest_io::EPISODE_MANIFEST_FILENAME;
+use crate::storage::image_resource_io::THUMBNAILS_SUBDIR_IN_RESOURCES;
+use crate::storage::novel_io::{
+    IMAGES_SUBDIR_IN_RESOURCES, NOVEL_METADATA_EXTENSION, RESOURCES_DIRNAME,
+};
+use crate::storage::novel_snapshot_io::{NOVEL_SNAPSHOTS_DIRNAME, NOVEL_SNAPSHOT_FILE_EXTENSION};
+use crate::storage::snapshot_io::{
+    EPISODE_SNAPSHOT_FILE_EXTENSION, SNAPSHOTS_SUBDIRNAME, WIKI_PAGE_SNAPSHOT_FILE_EXTENSION,
+};
+use crate::storage::wiki_page_io::{WIKI_PAGES_DIRNAME, WIKI_PAGE_FILE_EXTENSION};
+use crate::storage::{durable_io, index_manager};
+use chrono::{DateTime, Utc};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::PoisonError;
+use std::time::SystemTime;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// 남은 임시 파일 검사가 끝났고 확인할 파일이 있을 때 발생하는 이벤트
+pub const LEFTOVER_TEMP_FILES_FOUND_EVENT: &str = "leftover-temp-files-found";
+
+/// 임시 파일의 원본이 될 수 있는 파일 이름
+#[derive(Clone, Copy)]
+enum OriginalName {
+    Extension(&'static str),
+    FileName(&'static str),
+    /// 이름과 상관없이 모든 파일 (앱만 쓰는 폴더의 이미지, 블록 등)
+    Any,
+}
+
+impl OriginalName {
+    fn matches(self, original_name: &str) -> bool {
+        match self {
+            OriginalName::Extension(extension) => original_name
+                .strip_suffix(extension)
+                .and_then(|stem| stem.strip_suffix('.'))
+                .is_some_and(|stem| !stem.is_empty()),
+            OriginalName::FileName(file_name) => original_name == file_name,
+            OriginalName::Any => !original_name.is_empty(),
+        }
+    }
+}
+
+/// 원자적 쓰기로 임시 파일이 생길 수 있는 소설 폴더 안의 위치.
+/// `nested`이면 한 단계 아래 폴더(에피소드별 스냅샷 폴더 등)까지 검사합니다.
+/// `discard_only`이면 JSON이 아니거나 다시 만들 수 있는 파일이므로 복구하지 않고 삭제만 할 수 있습니다.
+/// 같은 임시 파일이 여러 위치에 맞으면 목록에서 먼저 나온 위치를 따릅니다.
+struct TempFileLocation {
+    subdirs: &'static [&'static str],
+    nested: bool,
+    original_names: &'static [OriginalName],
+    discard_only: bool,
+}
+
+const NOVEL_TEMP_FILE_LOCATIONS: &[TempFileLocation] = &[
+    TempFileLocation {
+        subdirs: &[],
+        nested: false,
+        original_names: &[OriginalName::Extension(NOVEL_METADATA_EXTENSION)],
+        discard_only: false,
+    },
+    TempFileLocation {
+        subdirs: &[EPISODES_DIRNAME],
+        nested: false,
+        original_names: &[
+            OriginalName::Extension(EPISODE_FILE_EXTENSION),
+            OriginalName::Extension(EDIT_HISTORY_FILE_EXTENSION),
+            OriginalName::FileName(EPISODE_MANIFEST_FILENAME),
+        ],
+        discard_only: false,
+    },
+    TempFileLocation {
+        subdirs: &[EPISODES_DIRNAME, SNAPSHOTS_SUBDIRNAME],
+        nested: true,
+        original_names: &[OriginalName::Extension(EPISODE_SNAPSHOT_FILE_EXTENSION)],
+        discard_only: false,
+    },
+    TempFileLocation {
+        subdirs: &[WIKI_PAGES_DIRNAME],
+        nested: false,
+        original_names: &[OriginalName::Extension(WIKI_PAGE_FILE_EXTENSION)],
+        discard_only: false,
+    },
+    TempFileLocation {
+        subdirs: &[WIKI_PAGES_DIRNAME, SNAPSHOTS_SUBDIRNAME],
+        nested: true,
+        original_names: &[OriginalName::Extension(WIKI_PAGE_SNAPSHOT_FILE_EXTENSION)],
+        discard_only: false,
+    },
+    TempFileLocation {
+        subdirs: &[NOVEL_SNAPSHOTS_DIRNAME],
+        nested: false,
+        original_names: &[OriginalName::Extension(NOVEL_SNAPSHOT_FILE_EXTENSION)],
+        discard_only: false,
+    },
+    TempFileLocation {
+        subdirs: &[RESOURCES_DIRNAME, ATTACHMENTS_SUBDIR_IN_RESOURCES],
+        nested: true,
+        original_names: &[OriginalName::FileName(ATTACHMENT_METADATA_FILENAME)],
+        discard_only: false,
+    },
+    TempFileLocation {
+        subdirs: &[RESOURCES_DIRNAME, ATTACHMENTS_SUBDIR_IN_RESOURCES],
+        nested: true,
+        original_names: &[OriginalName::Any],
+        discard_only: true,
+    },
+    TempFileLocation {
+        subdirs: &[RESOURCES_DIRNAME, IMAGES_SUBDIR_IN_RESOURCES],
+        nested: false,
+        original_names: &[OriginalName::Any],
+        discard_only: true,
+    },
+    TempFileLocation {
+        subdirs: &[RESOURCES_DIRNAME, THUMBNAILS_SUBDIR_IN_RESOURCES],
+        nested: false,
+        original_names: &[OriginalName::Any],
+        discard_only: true,
+    },
+    TempFileLocation {
+        subdirs: &[BLOCK_STORE_DIRNAME],
+        nested: true,
+        original_names: &[OriginalName::Any],
+        discard_only: true,
+    },
+];
+
+/// 앱 데이터 폴더(설정 파일 등)에서 검사할 위치
+const APP_DATA_TEMP_FILE_LOCATION: TempFileLocation = TempFileLocation {
+    subdirs: &[],
+    nested: false,
+    original_names: &[OriginalName::Extension("json")],
+    discard_only: false,
+};
+
+/// 검사할 폴더와 위치 목록 (앱 데이터 폴더와 등록된 모든 소설 폴더)
+fn collect_scan_targets(app_handle: &AppHandle) -> Vec<(PathBuf, &'static TempFileLocation)> {
+    let mut targets = Vec::new();
+    match app_handle.path().app_local_data_dir() {
+        Ok(dir) => targets.push((dir, &APP_DATA_TEMP_FILE_LOCATION)),
+        Err(e) => eprintln!("앱 데이터 디렉토리를 찾을 수 없습니다: {:?}", e),
+    }
+    match index_manager::get_all_novel_entries(app_handle) {
+        Ok(entries) => {
+            for novel_root in entries
+                .into_iter()
+                .filter_map(|e| e.path.map(PathBuf::from))
+            {
+                for location in NOVEL_TEMP_FILE_LOCATIONS {
+                    let dir = location
+                        .subdirs
+                        .iter()
+                        .fold(novel_root.clone(), |dir, subdir| dir.join(subdir));
+                    targets.push((dir, location));
+                }
+            }
+        }
+        Err(e) => eprintln!("임시 파일 검사를 위한 소설 인덱스 읽기 실패: {}", e),
+    }
+    targets
+}
+
+/// 폴더 바로 아래에서 위치에 맞는 임시 파일 경로를 수집합니다.
+/// 심볼릭 링크는 따라가지 않으며, `nested`이면 한 단계 아래 폴더까지만 봅니다.
+fn collect_temp_files(
+    dir_path: &Path,
+    location: &'static TempFileLocation,
+    nested: bool,
+    found: &mut Vec<(PathBuf, &'static TempFileLocation)>,
+) {
+    let Ok(entries) = fs::read_dir(dir_path) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        let path = entry.path();
+        if file_type.is_symlink() {
+            continue;
+        }
+        if file_type.is_dir() {
+            if nested {
+                collect_temp_files(&path, location, false, found);
+            }
+        } else if file_type.is_file() && is_known_temp_file(&path, location.original_names) {
+            found.push((path, location));
+        }
+    }
+}
+
+/// `durable_io::temp_file_path_for`가 만드는 이름(원본 이름 + `.tmp`)이고, 원본 이름이 위치에 맞는지 확인합니다.
+fn is_known_temp_file(path: &Path, original_names: &[OriginalName]) -> bool {
+    let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    file_name
+        .strip_suffix(durable_io::TEMP_FILE_SUFFIX)
+        .is_some_and(|original_name| {
+            original_names
+                .iter()
+                .any(|pattern| pattern.matches(original_name))
+        })
+}
+
+/// 임시 파일 경로에서 원본 파일 경로를 구합니다. (`.tmp` 접미사 제거)
+fn original_path_for(temp_path: &Path) -> Option<PathBuf> {
+    let file_name = temp_path.file_name()?.to_string_lossy().into_owned();
+    let original_name = file_name.strip_suffix(durable_io::TEMP_FILE_SUFFIX)?;
+    if original_name.is_empty() {
+        return None;
+    }
+    Some(temp_path.with_file_name(original_name))
+}
+
+/// 파일이 온전한 JSON인지 확인합니다.
+fn is_valid_json_file(path: &Path) -> bool {
+    fs::read(path)
+        .ok()
+        .is_some_and(|bytes| serde_json::from_slice::<serde_json::Value>(&bytes).is_ok())
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+fn to_rfc3339(time: SystemTime) -> String {
+    DateTime::<Utc>::from(time).to_rfc3339()
+}
+
+/// 임시 파일이 복구할 가치가 있으면 복구 후보 정보를 반환합니다.
+/// 임시 파일이 온전한 JSON이고, 원본보다 새롭고, 원본이 없거나 손상된 경우에만 후보가 됩니다.
+fn inspect_temp_file(temp_path: &Path) -> Option<RecoverableTempFile> {
+    let original_path = original_path_for(temp_path)?;
+    if !is_valid_json_file(temp_path) {
+        return None;
+    }
+    let original_exists = original_path.is_file();
+    if original_exists && is_valid_json_file(&original_path) {
+        return None;
+    }
+    let temp_modified = modified_time(temp_path)?;
+    let original_modified = modified_time(&original_path);
+    if original_modified.is_some_and(|original| original >= temp_modified) {
+        return None;
+    }
+    Some(RecoverableTempFile {
+        temp_path: temp_path.to_string_lossy().into_owned(),
+        original_path: original_path.to_string_lossy().into_owned(),
+        temp_modified_at: to_rfc3339(temp_modified),
+        original_modified_at: original_modified.map(to_rfc3339),
+        original_exists,
+    })
+}
+
+/// 이전 실행에서 남은 임시 파일을 검사합니다.
+/// 복구할 수 있는 임시 파일은 복구 후보로, 나머지는 오래된 임시 파일로 분류하며 아무것도 삭제하지 않습니다.
+fn scan_leftover_temp_files(app_handle: &AppHandle) -> LeftoverTempFiles {
+    let mut temp_files = Vec::new();
+    for (dir, location) in collect_scan_targets(app_handle) {
+        // 검사할 폴더 자체가 심볼릭 링크이면 건너뜀 (소설 폴더 밖을 가리킬 수 있음)
+        if fs::symlink_metadata(&dir).is_ok_and(|metadata| metadata.file_type().is_symlink()) {
+            continue;
+        }
+        collect_temp_files(&dir, location, location.nested, &mut temp_files);
+    }
+    // 안정 정렬이므로 같은 파일은 먼저 나온 위치의 것이 남음
+    temp_files.sort_by(|(a, _), (b, _)| a.cmp(b));
+    temp_files.dedup_by(|(a, _), (b, _)| a == b);
+
+    let mut leftovers = LeftoverTempFiles::default();
+    for (temp_path, location) in temp_files {
+        let candidate = if location.discard_only {
+            None
+        } else {
+            inspect_temp_file(&temp_path)
+        };
+        match candidate {
+            Some(candidate) => {
+                eprintln!("복구할 수 있는 임시 파일 발견: {:?}", temp_path);
+                leftovers.recoverable.push(candidate);
+            }
+            None => {
+                eprintln!("복구할 필요가 없는 남은 임시 파일 발견: {:?}", temp_path);
+                leftovers
+                    .stale
+                    .push(temp_path.to_string_lossy().into_owned());
+            }
+        }
+    }
+    leftovers
+        .recoverable
+        .sort_by(|a, b| a.original_path.cmp(&b.original_path));
+    leftovers
+}
+
+/// 앱 시작을 늦추지 않도록 별도 스레드에서 남은 임시 파일을 검사합니다.
+/// 확인할 파일이 있으면 `PendingRecovery`에 담고 `LEFTOVER_TEMP_FILES_FOUND_EVENT`를 보냅니다.
+pub fn spawn_leftover_temp_file_scan(app_handle: AppHandle) {
+    std::thread::spawn(move || {
+        let leftovers = scan_leftover_temp_files(&app_handle);
+        if leftovers.is_empty() {
+            return;
+        }
+        {
+            let state = app_handle.state::<PendingRecovery>();
+            let mut pending = state.0.lock().unwrap_or_else(PoisonError::into_inner);
+            pending.recoverable.extend(leftovers.recoverable);
+            pending.stale.extend(leftovers.stale);
+        }
+        if let Err(e) = app_handle.emit(LEFTOVER_TEMP_FILES_FOUND_EVENT, ()) {
+            eprintln!("남은 임시 파일 이벤트 전송 실패: {}", e);
+        }
+    });
+}
+
+/// 요청된 임시 파일이 검사 대상 위치의 임시 파일인지 확인하고 원본 경로와 위치를 반환합니다.
+fn resolve_known_temp_file(
+    app_handle: &AppHandle,
+    temp_path: &Path,
+) -> MuvelResult<(PathBuf, &'static TempFileLocation)> {
+    if !temp_path.is_file() {
+        return Err(
+            MuvelError::not_found(Message::FileNotFound(FileKind::TempFile)).with_path(temp_path),
+        );
+    }
+    // `..` 등으로 폴더 밖을 가리키지 않도록 실제 경로로 비교
+    let canonical_temp_path = fs::canonicalize(temp_path)
+        .map_err(|e| MuvelError::io(Message::TempPathResolveFailed, &e).with_path(temp_path))?;
+    let locations: Vec<&'static TempFileLocation> = collect_scan_targets(app_handle)
+        .into_iter()
+        .filter_map(|(dir, location)| {
+            let canonical_dir = fs::canonicalize(dir).ok()?;
+            let parent = canonical_temp_path.parent()?;
+            let is_in_location = parent == canonical_dir
+                || (location.nested && parent.parent() == Some(canonical_dir.as_path()));
+            is_in_location.then_some(location)
+        })
+        .collect();
+    if locations.is_empty() {
+        return Err(MuvelError::invalid_input(Message::OutsideManagedRoots).with_path(temp_path));
+    }
+    // 같은 폴더에 위치가 여럿이면 이름이 맞는 첫 위치를 따름 (검사할 때와 같은 우선순위)
+    let Some(location) = locations
+        .into_iter()
+        .find(|location| is_known_temp_file(&canonical_temp_path, location.original_names))
+    else {
+        return Err(MuvelError::invalid_input(Message::NotATempFile).with_path(temp_path));
+    };
+    let original_path = original_path_for(&canonical_temp_path)
+        .ok_or_else(|| MuvelError::invalid_input(Message::NotATempFile).with_path(temp_path))?;
+    Ok((original_path, location))
+}
+
+/// 임시 파일로 원본 파일을 교체합니다.
+pub fn recover_temp_file(app_handle: &AppHandle, temp_path: &Path) -> MuvelResult<()> {
+    let (original_path, location) = resolve_known_temp_file(app_handle, temp_path)?;
+    if location.discard_only {
+        return Err(MuvelError::invalid_input(Message::TempFileNotRecoverable).with_path(temp_path));
+    }
+    if !is_valid_json_file(temp_path) {
+        return Err(MuvelError::invalid_input(Message::TempFileCorrupted).with_path(temp_path));
+    }
+    durable_io::rename_durably(temp_path, &original_path)?;
+    eprintln!("임시 파일로 복구했습니다: {:?}", original_path);
+    Ok(())
+}
+
+/// 복구하지 않기로 한 임시 파일을 삭제합니다.
+pub fn discard_temp_file(app_handle: &AppHandle, temp_path: &Path) -> MuvelResult<()> {
+    resolve_known_temp_file(app_handle, temp_path)?;
+    fs::remove_file(temp_path).map_err(|e| {
+        MuvelError::io(Message::FileDeleteFailed(FileKind::TempFile), &e).with_path(temp_path)
+    })
+}
@@ -1,14 +1,20 @@
+use crate::i18n::{FileKind, Message};
+use crate::models::error::{MuvelError, MuvelResult};
 use crate::models::novel::WikiPageSummaryData; // WikiPageSummaryData 사용
 use crate::models::wiki_page::{WikiPage, WikiPageCategory}; // WikiPage 모델 사용
+use crate::storage::novel_watcher;
+use crate::storage::sync_conflict_io;
+use crate::storage::{durable_io, format_migration};
+use crate::utils::block_order_utils;
 use serde::Deserialize; // 부분 역직렬화를 위해 필요
 use std::fs;
-use std::io::{Read, Write};
+use std::io::Read;
 use std::path::{Path, PathBuf};
 
 // 위키 페이지 파일들을 저장할 폴더 이름
 pub const WIKI_PAGES_DIRNAME: &str = "wiki"; // 예시 폴더명
                                              // 위키 페이지 파일의 확장자
-const WIKI_PAGE_FILE_EXTENSION: &str = "mvlw"; // Muvel Wiki Page
+pub const WIKI_PAGE_FILE_EXTENSION: &str = "mvlw"; // Muvel Wiki Page
 
 /// 주어진 소설 루트 경로와 위키 페이지 ID를 사용하여 위키 페이지 파일의 전체 경로를 구성합니다.
 fn get_wiki_page_file_path(novel_root_path: &Path, page_id: &str) -> PathBuf {
@@ -18,47 +24,35 @@ fn get_wiki_page_file_path(novel_root_path: &Path, page_id: &str) -> PathBuf {
 }
 
 /// 위키 페이지 저장 디렉토리를 생성합니다. (없을 경우)
-pub fn ensure_wiki_pages_directory_exists(novel_root_path: &Path) -> Result<PathBuf, String> {
+pub fn ensure_wiki_pages_directory_exists(novel_root_path: &Path) -> MuvelResult<PathBuf> {
     let wiki_pages_dir = novel_root_path.join(WIKI_PAGES_DIRNAME);
     if !wiki_pages_dir.exists() {
         fs::create_dir_all(&wiki_pages_dir).map_err(|e| {
-            format!(
-                "위키 페이지 디렉토리 생성 실패 (경로: {:?}): {}",
-                wiki_pages_dir, e
-            )
+            MuvelError::io(Message::DirCreateFailed(FileKind::WikiPage), &e)
+                .with_path(&wiki_pages_dir)
         })?;
     }
     Ok(wiki_pages_dir)
 }
 
 /// 특정 위키 페이지 파일(.mkwp)을 읽어 WikiPage 객체로 반환합니다.
-pub fn read_wiki_page_content(novel_root_path: &Path, page_id: &str) -> Result<WikiPage, String> {
+pub fn read_wiki_page_content(novel_root_path: &Path, page_id: &str) -> MuvelResult<WikiPage> {
     let page_file_path = get_wiki_page_file_path(novel_root_path, page_id);
 
     if !page_file_path.exists() {
-        return Err(format!(
-            "위키 페이지 파일을 찾을 수 없습니다: {:?}",
-            page_file_path
-        ));
+        return Err(
+            MuvelError::not_found(Message::FileNotFound(FileKind::WikiPage))
+                .with_path(&page_file_path),
+        );
     }
 
-    let mut file_content = String::new();
-    fs::File::open(&page_file_path)
-        .map_err(|e| {
-            format!(
-                "위키 페이지 파일을 열 수 없습니다 (경로: {:?}): {}",
-                page_file_path, e
-            )
-        })?
-        .read_to_string(&mut file_content)
-        .map_err(|e| format!("위키 페이지 파일 내용을 읽을 수 없습니다: {}", e))?;
-
-    serde_json::from_str(&file_content).map_err(|e| {
-        format!(
-            "위키 페이지 파일 JSON 파싱에 실패했습니다 (ID: {}): {}",
-            page_id, e
-        )
-    })
+    let mut data: WikiPage =
+        format_migration::read_versioned_file(&page_file_path, FileKind::WikiPage)
+            .map_err(|e| e.with_id(page_id))?;
+
+    // 이전 형식의 order 정리는 형식 마이그레이션에서 한 번만 하므로, 여기서는 메모리에서 정렬만 함
+    block_order_utils::sort_blocks_by_order(&mut data.blocks);
+    Ok(data)
 }
 
 /// WikiPage 객체를 위키 페이지 파일(.mkwp)에 저장(업데이트)합니다.
@@ -66,51 +60,27 @@ pub fn write_wiki_page_content(
     novel_root_path: &Path,
     page_id: &str, // page_id는 data.id와 동일해야 함
     data: &WikiPage,
-) -> Result<(), String> {
+) -> MuvelResult<()> {
     ensure_wiki_pages_directory_exists(novel_root_path)?; // 디렉토리 존재 확인 및 생성
     let page_file_path = get_wiki_page_file_path(novel_root_path, page_id);
 
-    let temp_file_path =
-        page_file_path.with_extension(&format!("{}.tmp", WIKI_PAGE_FILE_EXTENSION));
-
-    let mut temp_file = fs::File::create(&temp_file_path).map_err(|e| {
-        format!(
-            "임시 위키 페이지 파일을 생성할 수 없습니다 (경로: {:?}): {}",
-            temp_file_path, e
-        )
-    })?;
-
-    let json_string = serde_json::to_string_pretty(data).map_err(|e| {
-        format!(
-            "위키 페이지 데이터를 JSON으로 직렬화하는 데 실패했습니다: {}",
-            e
-        )
-    })?;
-
-    temp_file
-        .write_all(json_string.as_bytes())
-        .map_err(|e| format!("임시 위키 페이지 파일에 쓰는 데 실패했습니다: {}", e))?;
-
-    fs::rename(&temp_file_path, &page_file_path).map_err(|e| {
-        format!(
-            "위키 페이지 파일을 원자적으로 교체하는 데 실패했습니다 (원본: {:?}, 임시: {:?}): {}",
-            page_file_path, temp_file_path, e
-        )
-    })?;
+    let json_string = format_migration::to_versioned_json(data, FileKind::WikiPage)?;
+
+    durable_io::write_file_atomically(&page_file_path, json_string.as_bytes())
+        .map_err(|e| e.context(Message::FileWriteFailed(FileKind::WikiPage)))?;
 
     Ok(())
 }
 
 /// 특정 위키 페이지 파일(.mkwp)을 삭제합니다.
-pub fn delete_wiki_page_file(novel_root_path: &Path, page_id: &str) -> Result<(), String> {
+pub fn delete_wiki_page_file(novel_root_path: &Path, page_id: &str) -> MuvelResult<()> {
     let page_file_path = get_wiki_page_file_path(novel_root_path, page_id);
 
     if page_file_path.exists() && page_file_path.is_file() {
+        novel_watcher::mark_internal_change(&page_file_path);
         fs::remove_file(&page_file_path).map_err(|e| {
-            format!(
-                "위키 페이지 파일 삭제에 실패했습니다 (경로: {:?}): {}",
-                page_file_path, e
-            )
+            MuvelError::io(Message::FileDeleteFailed(FileKind::WikiPage), &e)
+                .with_path(&page_file_path)
         })?;
         Ok(())
     } else {
@@ -139,7 +109,7 @@ struct PartialWikiPageDataForSummary {
 /// 특정 소설의 `wiki` 디렉토리 내 모든 위키 페이지 파일에서 요약 정보만 읽어 목록으로 반환합니다.
 pub fn list_wiki_page_summaries_from_files(
     novel_root_path: &Path,
-) -> Result<Vec<WikiPageSummaryData>, String> {
+) -> MuvelResult<Vec<WikiPageSummaryData>> {
     let wiki_pages_dir_path = ensure_wiki_pages_directory_exists(novel_root_path)?;
     let mut page_summaries = Vec::new();
 
@@ -149,18 +119,18 @@ pub fn list_wiki_page_summaries_from_files(
     }
 
     for entry in fs::read_dir(&wiki_pages_dir_path).map_err(|e| {
-        format!(
-            "위키 페이지 디렉토리를 읽을 수 없습니다 (경로: {:?}): {}",
-            wiki_pages_dir_path, e
-        )
+        MuvelError::io(Message::DirReadFailed(FileKind::WikiPage), &e)
+            .with_path(&wiki_pages_dir_path)
     })? {
-        let entry = entry.map_err(|e| format!("디렉토리 항목 읽기 실패: {}", e))?;
+        let entry = entry.map_err(|e| MuvelError::io(Message::DirEntryReadFailed, &e))?;
         let path = entry.path();
 
         if path.is_file()
             && path
                 .extension()
                 .map_or(false, |ext| ext == WIKI_PAGE_FILE_EXTENSION)
+            && !sync_conflict_io::is_conflict_copy(&path)
+        // 충돌 사본은 원본과 ID가 같으므로 제외
         {
             let mut file_content = String::new();
             if fs::File::open(&path)
@@ -0,0 +1,332 @@
+use crate::models::block::Block;
+use crate::models::block_diff::{
+    BlockDiff, BlockDiffEntry, ModifiedBlockEntry, MovedBlockEntry, TextDiffOp, TextDiffSegment,
+};
+use std::collections::{HashMap, HashSet};
+
+/// LCS 테이블의 최대 칸 수. 이보다 크면 앞뒤 공통 부분을 제외한 나머지를 통째로 교체로 취급합니다.
+const MAX_LCS_CELLS: usize = 4_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum EditOp {
+    Equal,
+    Insert,
+    Delete,
+}
+
+/// 두 시퀀스 사이의 최소 편집 스크립트를 LCS로 계산합니다.
+/// 공통 접두/접미 부분은 테이블 계산에서 제외합니다.
+fn compute_edit_script<T: PartialEq>(a: &[T], b: &[T]) -> Vec<EditOp> {
+    let prefix_len = a.iter().zip(b.iter()).take_while(|(x, y)| x == y).count();
+    let suffix_len = a[prefix_len..]
+        .iter()
+        .rev()
+        .zip(b[prefix_len..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+
+    let a_mid = &a[prefix_len..a.len() - suffix_len];
+    let b_mid = &b[prefix_len..b.len() - suffix_len];
+
+    let mut ops = vec![EditOp::Equal; prefix_len];
+
+    let (n, m) = (a_mid.len(), b_mid.len());
+    if n.saturating_mul(m) > MAX_LCS_CELLS {
+        ops.extend(std::iter::repeat(EditOp::Delete).take(n));
+        ops.extend(std::iter::repeat(EditOp::Insert).take(m));
+    } else {
+        // lcs[i][j]: a_mid[i..]와 b_mid[j..]의 LCS 길이
+        let width = m + 1;
+        let mut lcs = vec![0u32; (n + 1) * width];
+        for i in (0..n).rev() {
+            for j in (0..m).rev() {
+                lcs[i * width + j] = if a_mid[i] == b_mid[j] {
+                    lcs[(i + 1) * width + j + 1] + 1
+                } else {
+                    lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+                };
+            }
+        }
+
+        let (mut i, mut j) = (0, 0);
+        while i < n && j < m {
+            if a_mid[i] == b_mid[j] {
+                ops.push(EditOp::Equal);
+                i += 1;
+                j += 1;
+            } else if lcs[(i + 1) * width + j] >= lcs[i * width + j + 1] {
+                ops.push(EditOp::Delete);
+                i += 1;
+            } else {
+                ops.push(EditOp::Insert);
+                j += 1;
+            }
+        }
+        ops.extend(std::iter::repeat(EditOp::Delete).take(n - i));
+        ops.extend(std::iter::repeat(EditOp::Insert).take(m - j));
+    }
+
+    ops.extend(std::iter::repeat(EditOp::Equal).take(suffix_len));
+    ops
+}
+
+/// 두 문자열의 글자 단위 diff를 계산합니다.
+/// 바이트가 아닌 `char` 단위로 비교하므로 한글 음절이 깨지지 않습니다.
+pub fn diff_text(before: &str, after: &str) -> Vec<TextDiffSegment> {
+    let before_chars: Vec<char> = before.chars().collect();
+    let after_chars: Vec<char> = after.chars().collect();
+    let ops = compute_edit_script(&before_chars, &after_chars);
+
+    let mut segments: Vec<TextDiffSegment> = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    for op in ops {
+        let (diff_op, ch) = match op {
+            EditOp::Equal => {
+                i += 1;
+                j += 1;
+                (TextDiffOp::Equal, before_chars[i - 1])
+            }
+            EditOp::Delete => {
+                i += 1;
+                (TextDiffOp::Delete, before_chars[i - 1])
+            }
+            EditOp::Insert => {
+                j += 1;
+                (TextDiffOp::Insert, after_chars[j - 1])
+            }
+        };
+        match segments.last_mut() {
+            Some(last) if last.op == diff_op => last.text.push(ch),
+            _ => segments.push(TextDiffSegment {
+                op: diff_op,
+                text: ch.to_string(),
+            }),
+        }
+    }
+    segments
+}
+
+/// 순서(order)와 수정 시각을 제외한 블록 내용이 같은지 확인합니다.
+fn is_block_content_equal(before: &Block, after: &Block) -> bool {
+    before.text == after.text
+        && before.content == after.content
+        && before.block_type == after.block_type
+        && before.attr == after.attr
+}
+
+fn sorted_by_order(blocks: &[Block]) -> Vec<&Block> {
+    let mut sorted: Vec<&Block> = blocks.iter().collect();
+    sorted.sort_by(|a, b| a.order.total_cmp(&b.order));
+    sorted
+}
+
+/// 두 블록 목록을 블록 ID 기준으로 비교합니다.
+///
+/// # Arguments
+/// * `before`: 기준이 되는(이전) 블록 목록입니다.
+/// * `after`: 비교 대상(이후) 블록 목록입니다.
+///
+/// # Returns
+/// 추가/삭제/이동/수정된 블록 목록. 이동 여부는 양쪽에 모두 있는 블록들의
+/// 상대 순서(LCS)에서 벗어난 블록으로 판단합니다.
+pub fn diff_blocks(before: &[Block], after: &[Block]) -> BlockDiff {
+    let before_sorted = sorted_by_order(before);
+    let after_sorted = sorted_by_order(after);
+
+    let before_index: HashMap<&str, usize> = before_sorted
+        .iter()
+        .enumerate()
+        .map(|(i, b)| (b.id.as_str(), i))
+        .collect();
+    let after_index: HashMap<&str, usize> = after_sorted
+        .iter()
+        .enumerate()
+        .map(|(i, b)| (b.id.as_str(), i))
+        .collect();
+
+    let mut diff = BlockDiff::default();
+
+    for (index, block) in before_sorted.iter().enumerate() {
+        if !after_index.contains_key(block.id.as_str()) {
+            diff.removed.push(BlockDiffEntry {
+                index,
+                block: (*block).clone(),
+            });
+        }
+    }
+    for (index, block) in after_sorted.iter().enumerate() {
+        if !before_index.contains_key(block.id.as_str()) {
+            diff.added.push(BlockDiffEntry {
+                index,
+                block: (*block).clone(),
+            });
+        }
+    }
+
+    // 양쪽에 모두 있는 블록들의 ID 순서를 비교하여 이동한 블록을 찾는다.
+    let common_before: Vec<&str> = before_sorted
+        .iter()
+        .map(|b| b.id.as_str())
+        .filter(|id| after_index.contains_key(id))
+        .collect();
+    let common_after: Vec<&str> = after_sorted
+        .iter()
+        .map(|b| b.id.as_str())
+        .filter(|id| before_index.contains_key(id))
+        .collect();
+
+    let mut stable_ids: HashSet<&str> = HashSet::new();
+    let mut before_pos = 0;
+    for op in compute_edit_script(&common_before, &common_after) {
+        match op {
+            EditOp::Equal => {
+                stable_ids.insert(common_before[before_pos]);
+                before_pos += 1;
+            }
+            EditOp::Delete => before_pos += 1,
+            EditOp::Insert => {}
+        }
+    }
+
+    for id in &common_after {
+        let from_index = before_index[id];
+        let to_index = after_index[id];
+        let before_block = before_sorted[from_index];
+        let after_block = after_sorted[to_index];
+
+        let moved = !stable_ids.contains(id);
+        if moved {
+            diff.moved.push(MovedBlockEntry {
+                id: id.to_string(),
+                from_index,
+                to_index,
+            });
+        }
+
+        if !is_block_content_equal(before_block, after_block) {
+            diff.modified.push(ModifiedBlockEntry {
+                id: id.to_string(),
+                before: before_block.clone(),
+                after: after_block.clone(),
+                text_diff: diff_text(&before_block.text, &after_block.text),
+            });
+        } else if !moved {
+            diff.unchanged_count += 1;
+        }
+    }
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(id: &str, text: &str, order: f64) -> Block {
+        Block {
+            id: id.to_string(),
+            text: text.to_string(),
+            content: vec![serde_json::json!({ "type": "text", "text": text })],
+            block_type: "paragraph".to_string(),
+            attr: None,
+            order,
+            updated_at: None,
+        }
+    }
+
+    fn segment(op: TextDiffOp, text: &str) -> TextDiffSegment {
+        TextDiffSegment {
+            op,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn diff_text_keeps_hangul_syllables_whole() {
+        assert_eq!(
+            diff_text("안녕하세요", "안녕히 가세요"),
+            vec![
+                segment(TextDiffOp::Equal, "안녕"),
+                segment(TextDiffOp::Delete, "하"),
+                segment(TextDiffOp::Insert, "히 가"),
+                segment(TextDiffOp::Equal, "세요"),
+            ]
+        );
+    }
+
+    #[test]
+    fn edit_script_falls_back_to_replace_over_lcs_cap() {
+        // 공통 접두/접미를 뺀 가운데가 MAX_LCS_CELLS를 넘으면 통째로 삭제 후 삽입
+        let a: Vec<u32> = std::iter::once(7).chain(0..2_001).chain([9]).collect();
+        let b: Vec<u32> = std::iter::once(7).chain(1..2_002).chain([9]).collect();
+
+        let ops = compute_edit_script(&a, &b);
+        let mut expected = vec![EditOp::Equal];
+        expected.extend(std::iter::repeat(EditOp::Delete).take(2_001));
+        expected.extend(std::iter::repeat(EditOp::Insert).take(2_001));
+        expected.push(EditOp::Equal);
+        assert_eq!(ops, expected);
+    }
+
+    #[test]
+    fn edit_script_uses_lcs_under_cap() {
+        let ops = compute_edit_script(&[1, 2, 3, 4], &[1, 3, 4, 5]);
+        assert_eq!(
+            ops,
+            vec![
+                EditOp::Equal,
+                EditOp::Delete,
+                EditOp::Equal,
+                EditOp::Equal,
+                EditOp::Insert,
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_blocks_detects_changes_by_id() {
+        let before = vec![
+            block("a", "하나", 0.0),
+            block("b", "둘", 1.0),
+            block("c", "셋", 2.0),
+            block("d", "넷", 3.0),
+        ];
+        let after = vec![
+            block("c", "셋", 0.0),
+            block("a", "하나", 1.0),
+            block("b", "두울", 2.0),
+            block("e", "다섯", 3.0),
+        ];
+
+        let diff = diff_blocks(&before, &after);
+        assert_eq!(
+            diff.removed
+                .iter()
+                .map(|e| e.block.id.as_str())
+                .collect::<Vec<_>>(),
+            ["d"]
+        );
+        assert_eq!(
+            diff.added
+                .iter()
+                .map(|e| (e.index, e.block.id.as_str()))
+                .collect::<Vec<_>>(),
+            [(3, "e")]
+        );
+        assert_eq!(
+            diff.moved
+                .iter()
+                .map(|e| (e.id.as_str(), e.from_index, e.to_index))
+                .collect::<Vec<_>>(),
+            [("c", 2, 0)]
+        );
+        assert_eq!(
+            diff.modified
+                .iter()
+                .map(|e| e.id.as_str())
+                .collect::<Vec<_>>(),
+            ["b"]
+        );
+        assert_eq!(diff.unchanged_count, 1);
+    }
+}
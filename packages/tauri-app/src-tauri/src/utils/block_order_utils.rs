@@ -0,0 +1,218 @@
+use crate::models::block::Block;
+use std::collections::{HashMap, HashSet};
+
+/// 인접한 두 블록 order 사이의 최소 간격. 이보다 좁아지면 전체 순서를 다시 매깁니다.
+/// (f64 정밀도 안에서 두 블록 사이에 새 블록을 충분히 여러 번 끼워 넣을 수 있도록 여유를 둠)
+pub const MIN_ORDER_GAP: f64 = 1e-6;
+
+/// 두 블록 사이에 들어갈 order를 계산합니다. `None`은 목록의 처음/끝을 뜻합니다.
+pub fn order_between(before: Option<f64>, after: Option<f64>) -> f64 {
+    match (before, after) {
+        (Some(before), Some(after)) => before + (after - before) / 2.0,
+        (Some(before), None) => before + 1.0,
+        (None, Some(after)) => after - 1.0,
+        (None, None) => 0.0,
+    }
+}
+
+/// order 기준으로 블록을 정렬합니다. order가 같으면 기존 순서를 유지합니다. (안정 정렬)
+pub fn sort_blocks_by_order(blocks: &mut [Block]) {
+    blocks.sort_by(|a, b| a.order.total_cmp(&b.order));
+}
+
+/// 정렬된 블록 목록에 같은 order, 너무 좁은 간격, 유한하지 않은 값이 있는지 확인합니다.
+pub fn needs_rebalance(sorted_blocks: &[Block]) -> bool {
+    sorted_blocks.iter().any(|b| !b.order.is_finite())
+        || sorted_blocks
+            .windows(2)
+            .any(|pair| pair[1].order - pair[0].order < MIN_ORDER_GAP)
+}
+
+/// 현재 순서를 유지한 채 order를 0, 1, 2, ... 으로 다시 매깁니다. (클라이언트의 인덱스 기반 order와 동일)
+pub fn rebalance_block_orders(sorted_blocks: &mut [Block]) {
+    for (index, block) in sorted_blocks.iter_mut().enumerate() {
+        block.order = index as f64;
+    }
+}
+
+/// 블록을 정렬하고, 필요하면 순서를 다시 매깁니다.
+/// 다시 매겼으면 true를 반환합니다. (이전 형식 파일의 중복 order 변환에도 사용)
+pub fn normalize_block_orders(blocks: &mut [Block]) -> bool {
+    sort_blocks_by_order(blocks);
+    if needs_rebalance(blocks) {
+        rebalance_block_orders(blocks);
+        true
+    } else {
+        false
+    }
+}
+
+/// order를 다시 매기기 전의 블록 목록(`before_blocks`, 정렬됨)을 다시 매긴 뒤(`after_blocks`)의 order 체계로 옮깁니다.
+/// 변경분이 적용되지 않은 블록은 다시 매겨도 서로의 순서가 그대로이므로 `after_blocks`의 order를 쓰고,
+/// `changed_ids`의 블록(또는 사라진 블록)은 앞뒤의 그대로인 블록 사이에 원래 순서대로 고르게 배치합니다.
+pub fn rebase_block_orders(
+    before_blocks: &[Block],
+    after_blocks: &[Block],
+    changed_ids: &HashSet<&str>,
+) -> Vec<Block> {
+    let after_orders: HashMap<&str, f64> = after_blocks
+        .iter()
+        .map(|b| (b.id.as_str(), b.order))
+        .collect();
+    let mut rebased = Vec::with_capacity(before_blocks.len());
+    let mut pending: Vec<Block> = Vec::new();
+    let mut previous_anchor = None;
+
+    for block in before_blocks {
+        let anchor = (!changed_ids.contains(block.id.as_str()))
+            .then(|| after_orders.get(block.id.as_str()).copied())
+            .flatten();
+        match anchor {
+            Some(order) => {
+                place_between(&mut pending, previous_anchor, Some(order), &mut rebased);
+                rebased.push(Block {
+                    order,
+                    ..block.clone()
+                });
+                previous_anchor = Some(order);
+            }
+            None => pending.push(block.clone()),
+        }
+    }
+    place_between(&mut pending, previous_anchor, None, &mut rebased);
+    rebased
+}
+
+/// `blocks`를 `before`와 `after` 사이에 고르게 배치해 `output`으로 옮깁니다. (`None`은 목록의 처음/끝)
+fn place_between(
+    blocks: &mut Vec<Block>,
+    before: Option<f64>,
+    after: Option<f64>,
+    output: &mut Vec<Block>,
+) {
+    let count = blocks.len() as f64;
+    for (index, mut block) in blocks.drain(..).enumerate() {
+        let step = index as f64 + 1.0;
+        block.order = match (before, after) {
+            (Some(before), Some(after)) => before + (after - before) * step / (count + 1.0),
+            (Some(before), None) => before + step,
+            (None, Some(after)) => after - (count + 1.0 - step),
+            (None, None) => step - 1.0,
+        };
+        output.push(block);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(id: &str, order: f64) -> Block {
+        Block {
+            id: id.to_string(),
+            text: String::new(),
+            content: Vec::new(),
+            block_type: "paragraph".to_string(),
+            attr: None,
+            order,
+            updated_at: None,
+        }
+    }
+
+    fn ids_and_orders(blocks: &[Block]) -> Vec<(&str, f64)> {
+        blocks.iter().map(|b| (b.id.as_str(), b.order)).collect()
+    }
+
+    #[test]
+    fn normalize_keeps_orders_with_enough_gap() {
+        let mut blocks = vec![block("b", 0.5), block("a", 0.25), block("c", 0.75)];
+        assert!(!normalize_block_orders(&mut blocks));
+        assert_eq!(
+            ids_and_orders(&blocks),
+            [("a", 0.25), ("b", 0.5), ("c", 0.75)]
+        );
+    }
+
+    #[test]
+    fn normalize_rebalances_below_min_gap() {
+        // 같은 두 블록 사이에 계속 끼워 넣으면 간격이 MIN_ORDER_GAP 아래로 좁아짐
+        let mut after = 1.0;
+        let mut blocks = vec![block("first", 0.0), block("last", after)];
+        for index in 0..30 {
+            let order = order_between(Some(0.0), Some(after));
+            blocks.push(block(&format!("n{}", index), order));
+            after = order;
+        }
+        assert!(after < MIN_ORDER_GAP);
+
+        assert!(normalize_block_orders(&mut blocks));
+        assert_eq!(blocks.first().unwrap().id, "first");
+        assert_eq!(blocks[1].id, "n29");
+        assert_eq!(blocks.last().unwrap().id, "last");
+        for (index, block) in blocks.iter().enumerate() {
+            assert_eq!(block.order, index as f64);
+        }
+        assert!(!needs_rebalance(&blocks));
+    }
+
+    #[test]
+    fn normalize_rebalances_duplicate_and_non_finite_orders() {
+        let mut blocks = vec![block("a", 1.0), block("b", 1.0)];
+        assert!(normalize_block_orders(&mut blocks));
+        assert_eq!(ids_and_orders(&blocks), [("a", 0.0), ("b", 1.0)]);
+
+        let mut blocks = vec![block("a", 0.0), block("b", f64::NAN)];
+        assert!(normalize_block_orders(&mut blocks));
+        assert_eq!(ids_and_orders(&blocks), [("a", 0.0), ("b", 1.0)]);
+    }
+
+    #[test]
+    fn rebase_places_changed_blocks_between_rebalanced_neighbours() {
+        let before = vec![
+            block("head", 0.0),
+            block("a", 0.5),
+            block("b", 0.5000001),
+            block("c", 0.6),
+            block("tail", 1.0),
+        ];
+        // "b"를 지우고 "x"를 "a"와 "c" 사이에 넣은 뒤 0, 1, 2, ...로 다시 매긴 결과
+        let after = vec![
+            block("head", 0.0),
+            block("a", 1.0),
+            block("x", 2.0),
+            block("c", 3.0),
+            block("tail", 4.0),
+        ];
+        let changed_ids = HashSet::from(["b", "x"]);
+
+        let rebased = rebase_block_orders(&before, &after, &changed_ids);
+        assert_eq!(
+            ids_and_orders(&rebased),
+            [
+                ("head", 0.0),
+                ("a", 1.0),
+                ("b", 2.0),
+                ("c", 3.0),
+                ("tail", 4.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn rebase_places_changed_blocks_at_the_ends() {
+        let before = vec![
+            block("x", 0.0),
+            block("y", 0.1),
+            block("a", 0.2),
+            block("z", 0.3),
+        ];
+        let after = vec![block("a", 0.0)];
+        let changed_ids = HashSet::from(["x", "y", "z"]);
+
+        let rebased = rebase_block_orders(&before, &after, &changed_ids);
+        assert_eq!(
+            ids_and_orders(&rebased),
+            [("x", -2.0), ("y", -1.0), ("a", 0.0), ("z", 1.0)]
+        );
+    }
+}
@@ -1,5 +1,9 @@
-use crate::models::block::{Block, DeltaBlock, DeltaBlockAction};
-use std::collections::HashMap;
+use crate::i18n::Message;
+use crate::models::block::{Block, DeltaBlock, DeltaBlockAction, DeltaRejectReason, RejectedDelta};
+use crate::models::error::{MuvelError, MuvelResult};
+use crate::utils::block_order_utils;
+use chrono::DateTime;
+use std::collections::{HashMap, HashSet};
 
 /// ProseMirror의 content JSON 배열에서 순수 텍스트를 추출합니다.
 fn calculate_block_text_from_content(content: &Vec<serde_json::Value>) -> String {
@@ -20,41 +24,205 @@ fn calculate_block_text_from_content(content: &Vec<serde_json::Value>) -> String
         .join("")
 }
 
+/// 블록 목록의 공백을 제외한 글자 수 합계를 계산합니다. (에피소드의 contentLength)
+pub fn calculate_content_length(blocks: &[Block]) -> i32 {
+    let content_length: usize = blocks
+        .iter()
+        .map(|block| block.text.chars().filter(|c| !c.is_whitespace()).count())
+        .sum();
+    content_length as i32
+}
+
+/// 블록을 그대로 다시 만드는 생성 변경분으로 바꿉니다. (같은 ID의 블록이 있으면 덮어씀)
+fn block_to_create_delta(block: &Block, date: &str) -> DeltaBlock {
+    DeltaBlock {
+        id: block.id.clone(),
+        action: DeltaBlockAction::Create,
+        date: date.to_string(),
+        content: Some(block.content.clone()),
+        block_type: Some(block.block_type.clone()),
+        attr: block.attr.clone(),
+        order: Some(block.order),
+    }
+}
+
+/// `applied_deltas`를 적용하기 전의 블록 목록을 기준으로, 적용 결과를 되돌리는 변경분을 만듭니다.
+/// 적용 전에 있던 블록은 이전 내용 그대로 다시 생성하고, 없던 블록은 삭제합니다.
+pub fn build_inverse_deltas(
+    before_blocks: &[Block],
+    applied_deltas: &[DeltaBlock],
+    date: &str,
+) -> Vec<DeltaBlock> {
+    let before_map: HashMap<&str, &Block> =
+        before_blocks.iter().map(|b| (b.id.as_str(), b)).collect();
+    let mut seen_ids = HashSet::new();
+    applied_deltas
+        .iter()
+        .filter(|delta| seen_ids.insert(delta.id.as_str()))
+        .map(|delta| match before_map.get(delta.id.as_str()) {
+            Some(block) => block_to_create_delta(block, date),
+            None => DeltaBlock {
+                id: delta.id.clone(),
+                action: DeltaBlockAction::Delete,
+                date: date.to_string(),
+                content: None,
+                block_type: None,
+                attr: None,
+                order: None,
+            },
+        })
+        .collect()
+}
+
+/// order를 다시 매긴 뒤의 블록 목록(`after_blocks`)을 기준으로, 변경분의 order를 블록의 현재 order로 바꿉니다.
+/// (다시 매기기 전의 order로 기록된 변경분을 나중에 다시 적용해도 같은 자리에 놓이도록 함)
+pub fn rebase_delta_orders(deltas: &mut [DeltaBlock], after_blocks: &[Block]) {
+    let after_orders: HashMap<&str, f64> = after_blocks
+        .iter()
+        .map(|b| (b.id.as_str(), b.order))
+        .collect();
+    for delta in deltas {
+        if let (Some(order), Some(after_order)) =
+            (delta.order.as_mut(), after_orders.get(delta.id.as_str()))
+        {
+            *order = *after_order;
+        }
+    }
+}
+
+/// 같은 문서의 다른 사본(동기화 충돌 사본 등)에서 `base_blocks`에 없거나 내용이 다른 블록을 생성 변경분으로 만듭니다.
+/// 변경분 시각은 사본 블록의 수정 시각(없으면 `fallback_date`)이므로, `reject_stale`을 켜고 적용하면
+/// 기준 쪽에서 더 나중에 수정된 블록은 그대로 남습니다. 이미 있는 블록은 기준 쪽의 order를 유지합니다.
+/// 사본에만 있는 블록이 기준 문서의 수정 시각(`base_updated_at`)보다 먼저 수정되었다면
+/// 기준 쪽에서 삭제한 블록으로 보고 되살리지 않습니다.
+///
+/// # Returns
+/// 생성 변경분과, 삭제된 블록으로 보고 건너뛴 블록 수
+pub fn build_copy_merge_deltas(
+    base_blocks: &[Block],
+    base_updated_at: &str,
+    copy_blocks: &[Block],
+    fallback_date: &str,
+) -> (Vec<DeltaBlock>, usize) {
+    let base_map: HashMap<&str, &Block> = base_blocks.iter().map(|b| (b.id.as_str(), b)).collect();
+    let mut skipped_count = 0;
+    let deltas = copy_blocks
+        .iter()
+        .filter_map(|block| {
+            let date = block.updated_at.as_deref().unwrap_or(fallback_date);
+            match base_map.get(block.id.as_str()) {
+                None if is_before(date, base_updated_at) => {
+                    skipped_count += 1;
+                    None
+                }
+                None => Some(block_to_create_delta(block, date)),
+                Some(base)
+                    if base.content != block.content
+                        || base.block_type != block.block_type
+                        || base.attr != block.attr =>
+                {
+                    Some(DeltaBlock {
+                        order: Some(base.order),
+                        ..block_to_create_delta(block, date)
+                    })
+                }
+                Some(_) => None,
+            }
+        })
+        .collect();
+    (deltas, skipped_count)
+}
+
+/// `merge_delta_blocks`의 결과
+#[derive(Debug)]
+pub struct MergeOutcome {
+    pub blocks: Vec<Block>,
+    pub applied_count: usize,
+    pub rejected: Vec<RejectedDelta>,
+    /// order 충돌로 전체 블록 순서를 다시 매겼는지 여부 (클라이언트는 블록을 다시 불러와야 함)
+    pub rebalanced: bool,
+}
+
+/// 블록이 변경분 생성 시각(`delta_date`) 이후에 수정되었는지 확인합니다.
+/// 어느 한쪽의 시각을 해석할 수 없으면 수정되지 않은 것으로 봅니다.
+fn is_modified_after(block: &Block, delta_date: &str) -> bool {
+    let parse = |value: &str| DateTime::parse_from_rfc3339(value).ok();
+    match (
+        block.updated_at.as_deref().and_then(parse),
+        parse(delta_date),
+    ) {
+        (Some(block_date), Some(delta_date)) => block_date > delta_date,
+        _ => false,
+    }
+}
+
+/// `a` 시각이 `b`보다 먼저인지 확인합니다. 어느 한쪽을 해석할 수 없으면 false를 반환합니다.
+fn is_before(a: &str, b: &str) -> bool {
+    match (
+        DateTime::parse_from_rfc3339(a),
+        DateTime::parse_from_rfc3339(b),
+    ) {
+        (Ok(a), Ok(b)) => a < b,
+        _ => false,
+    }
+}
+
 /// 주어진 `DeltaBlock` 목록을 기존 `Block` 목록에 병합합니다.
 ///
 /// # Arguments
-/// * `current_blocks`: 현재 `Block` 객체의 벡터입니다. 이 벡터는 변경될 수 있습니다.
+/// * `initial_blocks`: 현재 `Block` 객체의 벡터입니다.
 /// * `delta_blocks`: 적용할 `DeltaBlock` 객체의 벡터입니다.
-/// * `server_update_time`: 변경 사항이 적용된 서버 시간 (ISO 8601 문자열)입니다.
+/// * `reject_stale`: true이면 변경분의 `date` 이후에 이미 수정된 블록에 대한 변경분을 거부합니다.
+///   클라이언트가 최신 리비전을 기준으로 변경분을 만든 경우에는 false로 호출합니다.
 ///
 /// # Returns
-/// * `Result<Vec<Block>, String>`: 성공 시 병합된 `Block` 객체의 벡터, 실패 시 에러 메시지.
+/// * `MuvelResult<MergeOutcome>`: 성공 시 병합된 블록과 적용/거부된 변경분 정보,
+///   변경분 자체가 잘못된 경우(생성 시 content 누락 등) `MuvelError::InvalidInput`.
+///   존재하지 않는 블록에 대한 수정은 항상 `MissingBlock`으로 거부됩니다.
 pub fn merge_delta_blocks(
     initial_blocks: Vec<Block>,
     delta_blocks: Vec<DeltaBlock>,
-    // server_update_time: &str, // DeltaBlock의 date 필드를 사용하므로 이 파라미터는 불필요할 수 있습니다.
-) -> Result<Vec<Block>, String> {
+    reject_stale: bool,
+) -> MuvelResult<MergeOutcome> {
     let mut current_blocks_map: HashMap<String, Block> = initial_blocks
         .into_iter()
         .map(|b| (b.id.clone(), b))
         .collect();
+    let mut applied_count = 0;
+    let mut rejected = Vec::new();
 
     for delta in delta_blocks {
+        let is_stale = reject_stale
+            && current_blocks_map
+                .get(&delta.id)
+                .is_some_and(|block| is_modified_after(block, &delta.date));
+        if is_stale {
+            rejected.push(RejectedDelta {
+                id: delta.id,
+                action: delta.action,
+                reason: DeltaRejectReason::Stale,
+            });
+            continue;
+        }
+
         match delta.action {
             DeltaBlockAction::Create => {
                 let block_content = delta.content.ok_or_else(|| {
-                    format!("생성 액션 시 블록 ID {}에 content가 없습니다.", delta.id)
+                    MuvelError::invalid_input(Message::MissingBlockField {
+                        block_id: delta.id.clone(),
+                        field: "content",
+                    })
                 })?;
 
                 let block_text = calculate_block_text_from_content(&block_content);
 
-                // order는 f32로 받지만 Block에는 i32로 저장
-                let new_block_f_order = delta.order.unwrap_or_else(|| {
-                    current_blocks_map
+                // order가 없으면 맨 뒤에 추가
+                let new_block_order = delta.order.unwrap_or_else(|| {
+                    let last_order = current_blocks_map
                         .values()
-                        .map(|b| b.order as f32) // i32를 f32로 변환하여 계산
-                        .fold(0.0_f32, |max_ord, current_ord| if current_ord > max_ord { current_ord } else { max_ord }) // 수정: f32::max 대신 직접 비교
-                        + 1.0
+                        .map(|b| b.order)
+                        .max_by(|a, b| a.total_cmp(b));
+                    block_order_utils::order_between(last_order, None)
                 });
 
                 let new_block = Block {
@@ -62,13 +230,17 @@ pub fn merge_delta_blocks(
                     text: block_text,
                     content: block_content,
                     block_type: delta.block_type.ok_or_else(|| {
-                        format!("생성 액션 시 블록 ID {}에 blockType이 없습니다.", delta.id)
+                        MuvelError::invalid_input(Message::MissingBlockField {
+                            block_id: delta.id.clone(),
+                            field: "blockType",
+                        })
                     })?,
                     attr: delta.attr,
-                    order: new_block_f_order.round() as i32,
+                    order: new_block_order,
                     updated_at: Some(delta.date.clone()), // Delta의 date를 사용
                 };
                 current_blocks_map.insert(delta.id, new_block);
+                applied_count += 1;
             }
             DeltaBlockAction::Update => {
                 if let Some(block_to_update) = current_blocks_map.get_mut(&delta.id) {
@@ -88,32 +260,178 @@ pub fn merge_delta_blocks(
                         block_to_update.attr = delta.attr;
                         changed = true;
                     }
-                    if let Some(f_order_val) = delta.order {
-                        block_to_update.order = f_order_val.round() as i32;
+                    if let Some(order_val) = delta.order {
+                        block_to_update.order = order_val;
                         changed = true;
                     }
 
                     if changed {
                         block_to_update.updated_at = Some(delta.date); // Delta의 date를 사용
                     }
+                    applied_count += 1;
                 } else {
-                    // 업데이트하려는 블록이 없는 경우, 생성으로 처리할 수도 있으나 여기서는 경고만 출력
-                    eprintln!(
-                        "Warning: 업데이트하려는 블록 ID {}를 찾을 수 없습니다. Delta: {:?}",
-                        delta.id, delta
-                    );
-                    // 또는 여기서 에러를 반환할 수도 있습니다.
-                    // return Err(format!("업데이트하려는 블록 ID {}를 찾을 수 없습니다.", delta.id));
+                    // 다른 창에서 이미 삭제된 블록 등: 호출자에게 알리기 위해 거부 목록에 추가
+                    rejected.push(RejectedDelta {
+                        id: delta.id,
+                        action: DeltaBlockAction::Update,
+                        reason: DeltaRejectReason::MissingBlock,
+                    });
                 }
             }
             DeltaBlockAction::Delete => {
+                // 이미 없는 블록의 삭제는 결과가 같으므로 적용된 것으로 취급
                 current_blocks_map.remove(&delta.id);
+                applied_count += 1;
             }
         }
     }
 
+    // HashMap 순회 순서에 좌우되지 않도록 ID로 먼저 정렬한 뒤 order로 안정 정렬
     let mut updated_blocks_vec: Vec<Block> = current_blocks_map.into_values().collect();
-    updated_blocks_vec.sort_by(|a, b| a.order.cmp(&b.order));
+    updated_blocks_vec.sort_by(|a, b| a.id.cmp(&b.id));
+    let rebalanced = block_order_utils::normalize_block_orders(&mut updated_blocks_vec);
+
+    Ok(MergeOutcome {
+        blocks: updated_blocks_vec,
+        applied_count,
+        rejected,
+        rebalanced,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn content(text: &str) -> Vec<serde_json::Value> {
+        vec![serde_json::json!({ "type": "text", "text": text })]
+    }
+
+    fn block(id: &str, text: &str, order: f64, updated_at: &str) -> Block {
+        Block {
+            id: id.to_string(),
+            text: text.to_string(),
+            content: content(text),
+            block_type: "paragraph".to_string(),
+            attr: None,
+            order,
+            updated_at: Some(updated_at.to_string()),
+        }
+    }
+
+    fn delta(id: &str, action: DeltaBlockAction, date: &str) -> DeltaBlock {
+        DeltaBlock {
+            id: id.to_string(),
+            action,
+            date: date.to_string(),
+            content: None,
+            block_type: None,
+            attr: None,
+            order: None,
+        }
+    }
 
-    Ok(updated_blocks_vec)
+    fn update_text(id: &str, text: &str, date: &str) -> DeltaBlock {
+        DeltaBlock {
+            content: Some(content(text)),
+            ..delta(id, DeltaBlockAction::Update, date)
+        }
+    }
+
+    fn texts(blocks: &[Block]) -> Vec<(&str, &str)> {
+        blocks
+            .iter()
+            .map(|b| (b.id.as_str(), b.text.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn merge_rejects_stale_deltas_by_date() {
+        let blocks = vec![
+            block("a", "하나", 0.0, "2024-01-01T00:00:10Z"),
+            block("b", "둘", 1.0, "2024-01-01T00:00:00Z"),
+        ];
+        let deltas = vec![
+            update_text("a", "오래된 수정", "2024-01-01T00:00:05Z"),
+            update_text("b", "새 수정", "2024-01-01T00:00:05Z"),
+            update_text("missing", "없음", "2024-01-01T00:00:05Z"),
+        ];
+
+        let outcome = merge_delta_blocks(blocks.clone(), deltas.clone(), true).unwrap();
+        assert_eq!(outcome.applied_count, 1);
+        assert_eq!(
+            outcome
+                .rejected
+                .iter()
+                .map(|r| (r.id.as_str(), r.reason.clone()))
+                .collect::<Vec<_>>(),
+            [
+                ("a", DeltaRejectReason::Stale),
+                ("missing", DeltaRejectReason::MissingBlock),
+            ]
+        );
+        assert_eq!(texts(&outcome.blocks), [("a", "하나"), ("b", "새 수정")]);
+
+        // reject_stale이 꺼져 있으면 시각과 상관없이 적용
+        let outcome = merge_delta_blocks(blocks, deltas, false).unwrap();
+        assert_eq!(outcome.applied_count, 2);
+        assert_eq!(
+            texts(&outcome.blocks),
+            [("a", "오래된 수정"), ("b", "새 수정")]
+        );
+    }
+
+    #[test]
+    fn inverse_deltas_round_trip_undo_and_redo() {
+        let before = vec![
+            block("a", "하나", 0.0, "2024-01-01T00:00:00Z"),
+            block("b", "둘", 1.0, "2024-01-01T00:00:00Z"),
+        ];
+        let date = "2024-01-02T00:00:00Z";
+        let deltas = vec![
+            update_text("a", "하나!", date),
+            delta("b", DeltaBlockAction::Delete, date),
+            DeltaBlock {
+                content: Some(content("셋")),
+                block_type: Some("paragraph".to_string()),
+                order: Some(0.5),
+                ..delta("c", DeltaBlockAction::Create, date)
+            },
+        ];
+
+        let edited = merge_delta_blocks(before.clone(), deltas.clone(), false).unwrap();
+        assert_eq!(texts(&edited.blocks), [("a", "하나!"), ("c", "셋")]);
+        let inverse = build_inverse_deltas(&before, &deltas, date);
+
+        let undone = merge_delta_blocks(edited.blocks, inverse, false).unwrap();
+        assert_eq!(texts(&undone.blocks), texts(&before));
+        assert_eq!(
+            undone.blocks.iter().map(|b| b.order).collect::<Vec<_>>(),
+            [0.0, 1.0]
+        );
+
+        let redone = merge_delta_blocks(undone.blocks, deltas, false).unwrap();
+        assert_eq!(texts(&redone.blocks), [("a", "하나!"), ("c", "셋")]);
+    }
+
+    #[test]
+    fn copy_merge_skips_blocks_deleted_in_base() {
+        let base = vec![block("a", "하나", 0.0, "2024-01-01T00:00:00Z")];
+        let copy = vec![
+            block("a", "하나 (사본)", 5.0, "2024-01-03T00:00:00Z"),
+            block("deleted", "지운 블록", 6.0, "2024-01-01T00:00:00Z"),
+            block("added", "새 블록", 7.0, "2024-01-03T00:00:00Z"),
+        ];
+
+        let (deltas, skipped) =
+            build_copy_merge_deltas(&base, "2024-01-02T00:00:00Z", &copy, "2024-01-03T00:00:00Z");
+        assert_eq!(skipped, 1);
+        assert_eq!(
+            deltas
+                .iter()
+                .map(|d| (d.id.as_str(), d.order))
+                .collect::<Vec<_>>(),
+            [("a", Some(0.0)), ("added", Some(7.0))]
+        );
+    }
 }
@@ -1,2 +1,5 @@
+pub mod block_diff_utils;
+pub mod block_order_utils;
 pub mod delta_block_utils;
+pub mod snapshot_retention_utils;
 pub mod text_search_utils;
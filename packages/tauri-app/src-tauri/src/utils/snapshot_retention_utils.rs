@@ -0,0 +1,226 @@
+use crate::models::enums::snapshot_reason::SnapshotReason;
+use crate::models::settings::SnapshotRetentionPolicy;
+use crate::models::snapshot::EpisodeSnapshotHeader;
+use chrono::{DateTime, NaiveDate, Utc};
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+/// 보관 정책에 따라 삭제해야 할 스냅샷 ID 목록을 계산합니다.
+///
+/// 스냅샷은 SnapshotReason별로 나누어 최신순으로 평가합니다.
+/// 1. `max_age_days`보다 오래된 스냅샷은 삭제합니다.
+/// 2. `keep_daily_after_days`보다 오래된 스냅샷은 날짜(UTC)별로 가장 최신 것 하나만 남깁니다.
+/// 3. 그 외의 스냅샷은 최신 `keep_last`개 안에 들면 남깁니다.
+///
+/// 고정(pinned)된 스냅샷과 생성 시각을 해석할 수 없는 스냅샷은 삭제하지 않으며,
+/// `keep_last` 개수에도 포함하지 않습니다.
+pub fn select_snapshots_to_prune(
+    snapshots: &[EpisodeSnapshotHeader],
+    policy: &SnapshotRetentionPolicy,
+    now: DateTime<Utc>,
+) -> Vec<String> {
+    let mut dated: Vec<(&EpisodeSnapshotHeader, DateTime<Utc>)> = snapshots
+        .iter()
+        .filter(|s| !s.pinned)
+        .filter_map(|s| {
+            DateTime::parse_from_rfc3339(&s.created_at)
+                .ok()
+                .map(|created_at| (s, created_at.with_timezone(&Utc)))
+        })
+        .collect();
+    // 최신순 정렬
+    dated.sort_by_key(|(_, created_at)| Reverse(*created_at));
+
+    let mut rank_by_reason: HashMap<&SnapshotReason, usize> = HashMap::new();
+    let mut kept_days_by_reason: HashMap<&SnapshotReason, HashSet<NaiveDate>> = HashMap::new();
+    let mut to_prune = Vec::new();
+
+    for (snapshot, created_at) in dated {
+        let rule = policy.rule_for(&snapshot.reason);
+        let rank = rank_by_reason.entry(&snapshot.reason).or_insert(0);
+        let age_days = (now - created_at).num_days();
+
+        let keep = if rule.max_age_days.is_some_and(|max_age| age_days > max_age) {
+            false
+        } else if rule
+            .keep_daily_after_days
+            .is_some_and(|after_days| age_days >= after_days)
+        {
+            kept_days_by_reason
+                .entry(&snapshot.reason)
+                .or_default()
+                .insert(created_at.date_naive())
+        } else {
+            rule.keep_last.map_or(true, |keep_last| *rank < keep_last)
+        };
+
+        *rank += 1;
+        if !keep {
+            to_prune.push(snapshot.id.clone());
+        }
+    }
+    to_prune
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::settings::SnapshotRetentionRule;
+
+    fn header(
+        id: &str,
+        reason: SnapshotReason,
+        created_at: &str,
+        pinned: bool,
+    ) -> EpisodeSnapshotHeader {
+        EpisodeSnapshotHeader {
+            id: id.to_string(),
+            reason,
+            created_at: created_at.to_string(),
+            pinned,
+        }
+    }
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-06-30T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn policy_with_autosave(rule: SnapshotRetentionRule) -> SnapshotRetentionPolicy {
+        SnapshotRetentionPolicy {
+            autosave: rule,
+            ..SnapshotRetentionPolicy::default()
+        }
+    }
+
+    #[test]
+    fn keep_last_skips_pinned_and_unparsable_snapshots() {
+        let snapshots = vec![
+            header(
+                "new",
+                SnapshotReason::Autosave,
+                "2024-06-30T11:00:00Z",
+                false,
+            ),
+            header(
+                "pinned",
+                SnapshotReason::Autosave,
+                "2024-06-30T10:00:00Z",
+                true,
+            ),
+            header(
+                "mid",
+                SnapshotReason::Autosave,
+                "2024-06-30T09:00:00Z",
+                false,
+            ),
+            header(
+                "old",
+                SnapshotReason::Autosave,
+                "2024-06-30T08:00:00Z",
+                false,
+            ),
+            header("broken", SnapshotReason::Autosave, "어제", false),
+            header(
+                "manual",
+                SnapshotReason::Manual,
+                "2024-01-01T00:00:00Z",
+                false,
+            ),
+        ];
+        let policy = policy_with_autosave(SnapshotRetentionRule {
+            keep_last: Some(2),
+            keep_daily_after_days: None,
+            max_age_days: None,
+        });
+
+        assert_eq!(
+            select_snapshots_to_prune(&snapshots, &policy, now()),
+            ["old"]
+        );
+    }
+
+    #[test]
+    fn keep_daily_keeps_latest_snapshot_per_day() {
+        let snapshots = vec![
+            header(
+                "recent",
+                SnapshotReason::Autosave,
+                "2024-06-29T10:00:00Z",
+                false,
+            ),
+            header(
+                "day1-late",
+                SnapshotReason::Autosave,
+                "2024-06-20T18:00:00Z",
+                false,
+            ),
+            header(
+                "day1-early",
+                SnapshotReason::Autosave,
+                "2024-06-20T08:00:00Z",
+                false,
+            ),
+            header(
+                "day1-pinned",
+                SnapshotReason::Autosave,
+                "2024-06-20T07:00:00Z",
+                true,
+            ),
+            header(
+                "day2",
+                SnapshotReason::Autosave,
+                "2024-06-19T08:00:00Z",
+                false,
+            ),
+        ];
+        let policy = policy_with_autosave(SnapshotRetentionRule {
+            keep_last: Some(1),
+            keep_daily_after_days: Some(7),
+            max_age_days: None,
+        });
+
+        assert_eq!(
+            select_snapshots_to_prune(&snapshots, &policy, now()),
+            ["day1-early"]
+        );
+    }
+
+    #[test]
+    fn max_age_prunes_old_snapshots_but_not_pinned_ones() {
+        let snapshots = vec![
+            header(
+                "recent",
+                SnapshotReason::Restore,
+                "2024-06-25T00:00:00Z",
+                false,
+            ),
+            header(
+                "expired",
+                SnapshotReason::Restore,
+                "2024-05-01T00:00:00Z",
+                false,
+            ),
+            header(
+                "pinned",
+                SnapshotReason::Restore,
+                "2024-05-01T00:00:00Z",
+                true,
+            ),
+        ];
+        let policy = SnapshotRetentionPolicy {
+            restore: SnapshotRetentionRule {
+                keep_last: None,
+                keep_daily_after_days: Some(7),
+                max_age_days: Some(30),
+            },
+            ..SnapshotRetentionPolicy::default()
+        };
+
+        assert_eq!(
+            select_snapshots_to_prune(&snapshots, &policy, now()),
+            ["expired"]
+        );
+    }
+}